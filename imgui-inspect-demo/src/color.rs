@@ -12,7 +12,9 @@ pub struct Color4f {
 }
 
 // This struct demonstrates how to wrap an existing type that you might be using from another crate
-// and manually implement your own inspect handler
+// and manually implement your own inspect handler. If the default widgets for each field are good
+// enough, a mirror struct with #[inspect(remote = "Color4f")] avoids the wrapper entirely (see
+// Color4fDef in main.rs)
 #[derive(Clone)]
 pub struct Color(pub Color4f);
 
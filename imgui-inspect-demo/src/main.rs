@@ -1,6 +1,29 @@
 use imgui_inspect_derive::Inspect;
 
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::net::{IpAddr, Ipv4Addr};
+use std::num::NonZeroU32;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+use imgui_inspect::ImguiInspectVisitor;
+use imgui_inspect::InspectArgsDefault;
+use imgui_inspect::InspectArgsSlider;
 use imgui_inspect::InspectArgsStruct;
+use imgui_inspect::InspectFilter;
+use imgui_inspect::InspectIdCheck;
+use imgui_inspect::InspectLayoutState;
+use imgui_inspect::InspectReflect;
+use imgui_inspect::InspectRenderDefault;
+use imgui_inspect::InspectSerde;
+use imgui_inspect::InspectVisit;
+use imgui_inspect::InspectVisitor;
+
+use serde::{Deserialize, Serialize};
 
 mod color;
 use color::Color;
@@ -48,9 +71,856 @@ impl Default for ExampleInspectTarget {
     }
 }
 
+//
+// Each struct below shows off one feature of imgui-inspect. They're drawn as sections of the "Feature Showcase" window
+//
+
+// on_set calls a function after a field is changed through the UI
+#[derive(Inspect, Default)]
+pub struct OnSetExample {
+    #[inspect_slider(
+        min_value = 0.0,
+        max_value = 10.0,
+        on_set = "Self::speed_changed",
+        on_set_old_value
+    )]
+    speed: f32,
+
+    // Written by speed_changed
+    last_change: String,
+}
+
+impl OnSetExample {
+    fn speed_changed(
+        &mut self,
+        old_value: &f32,
+    ) {
+        self.last_change = format!("speed changed from {:.2} to {:.2}", old_value, self.speed);
+    }
+}
+
+// Generic structs get bounds inferred from the fields that use their type parameters
+#[derive(Inspect, Default)]
+pub struct GenericExample<T> {
+    first: T,
+    second: T,
+}
+
+// Types from other crates (pretend Color4f is one) can be drawn without a wrapper by mirroring their fields with
+// remote. The mirror is then used as the proxy_type of the fields that hold the real type
+#[derive(Inspect)]
+#[inspect(remote = "Color4f")]
+pub struct Color4fDef {
+    #[inspect_slider(min_value = 0.0, max_value = 1.0)]
+    r: f32,
+
+    #[inspect_slider(min_value = 0.0, max_value = 1.0)]
+    g: f32,
+
+    #[inspect_slider(min_value = 0.0, max_value = 1.0)]
+    b: f32,
+
+    #[inspect_slider(min_value = 0.0, max_value = 1.0)]
+    a: f32,
+}
+
+#[derive(Inspect)]
+pub struct RemoteExample {
+    #[inspect(proxy_type = "Color4fDef")]
+    color: Color4f,
+}
+
+impl Default for RemoteExample {
+    fn default() -> Self {
+        RemoteExample {
+            color: Color4f {
+                r: 1.0,
+                g: 0.5,
+                b: 0.0,
+                a: 1.0,
+            },
+        }
+    }
+}
+
+// Widget args can be expressions, including ones that read other fields through self
+#[derive(Inspect)]
+pub struct ExpressionArgsExample {
+    #[inspect_slider(
+        min_value = "-std::f32::consts::PI",
+        max_value = "std::f32::consts::PI"
+    )]
+    angle: f32,
+
+    #[inspect_slider(min_value = 1.0, max_value = 100.0)]
+    max_speed: f32,
+
+    // Lowering max_speed shrinks the range of this slider
+    #[inspect_slider(min_value = 0.0, max_value = "self.max_speed")]
+    speed: f32,
+}
+
+impl Default for ExpressionArgsExample {
+    fn default() -> Self {
+        ExpressionArgsExample {
+            angle: 0.0,
+            max_speed: 20.0,
+            speed: 5.0,
+        }
+    }
+}
+
+// Fields that differ from their default are highlighted, and right-clicking them offers "Reset to Default". The
+// struct's Default impl provides the default for every field, unless the field gives its own
+#[derive(Inspect)]
+#[inspect(default)]
+pub struct ResetToDefaultExample {
+    name: String,
+    lives: u32,
+
+    #[inspect(default = 0.5)]
+    volume: f32,
+}
+
+impl Default for ResetToDefaultExample {
+    fn default() -> Self {
+        ResetToDefaultExample {
+            name: "player".to_string(),
+            lives: 3,
+            volume: 0.5,
+        }
+    }
+}
+
+// Values in maps and sets can be edited, and their keys can be added, removed, and renamed
+#[derive(Inspect)]
+pub struct MapExample {
+    high_scores: BTreeMap<String, u32>,
+    tags: BTreeSet<String>,
+}
+
+impl Default for MapExample {
+    fn default() -> Self {
+        MapExample {
+            high_scores: vec![("alice".to_string(), 1200), ("bob".to_string(), 950)]
+                .into_iter()
+                .collect(),
+            tags: vec!["enemy".to_string(), "flying".to_string()]
+                .into_iter()
+                .collect(),
+        }
+    }
+}
+
+// Smart pointers and interior mutability are drawn like the value inside them
+#[derive(Inspect)]
+pub struct SmartPointerExample {
+    boxed: Box<f32>,
+    ref_cell: RefCell<String>,
+    cell: Cell<bool>,
+    mutex: Mutex<u32>,
+
+    // Edited in place unless the Arc is shared, in which case the edit is written to a new copy
+    arc: Arc<String>,
+
+    // Rc can't be edited, since other owners might be reading it
+    rc: Rc<String>,
+}
+
+impl Default for SmartPointerExample {
+    fn default() -> Self {
+        SmartPointerExample {
+            boxed: Box::new(1.0),
+            ref_cell: RefCell::new("borrowed while drawing".to_string()),
+            cell: Cell::new(true),
+            mutex: Mutex::new(3),
+            arc: Arc::new("edited in place".to_string()),
+            rc: Rc::new("read only".to_string()),
+        }
+    }
+}
+
+// Other std types. Values edited as text (char, PathBuf, IpAddr, NonZeroU32) aren't changed until the text parses
+#[derive(Inspect)]
+pub struct StdTypesExample {
+    hotkey: char,
+    save_path: PathBuf,
+    server_address: IpAddr,
+    max_players: NonZeroU32,
+    respawn_delay: Duration,
+    spawn_height: Range<f32>,
+
+    // Shown read-only as a UTC date and time
+    started_at: SystemTime,
+}
+
+impl Default for StdTypesExample {
+    fn default() -> Self {
+        StdTypesExample {
+            hotkey: 'F',
+            save_path: PathBuf::from("saves/slot1.sav"),
+            server_address: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            max_players: NonZeroU32::new(8).unwrap(),
+            respawn_delay: Duration::from_millis(2500),
+            spawn_height: 10.0..20.0,
+            started_at: SystemTime::now(),
+        }
+    }
+}
+
+// Math types from glam, nalgebra, cgmath, and mint are supported with cargo features. Rotations are edited as Euler
+// angles in degrees, and matrices are edited as translation, rotation, and scale
+#[derive(Inspect)]
+pub struct MathExample {
+    position: mint::Point3<f32>,
+    velocity: mint::Vector3<f32>,
+    rotation: mint::Quaternion<f32>,
+    transform: mint::ColumnMatrix4<f32>,
+}
+
+impl Default for MathExample {
+    fn default() -> Self {
+        MathExample {
+            position: [0.0, 1.0, 0.0].into(),
+            velocity: [2.0, 0.0, 0.0].into(),
+            rotation: mint::Quaternion {
+                v: [0.0, 0.0, 0.0].into(),
+                s: 1.0,
+            },
+            transform: [
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+                [5.0, 0.0, -3.0, 1.0],
+            ]
+            .into(),
+        }
+    }
+}
+
+// For example purposes, pretend these types are in some upstream crate that implements serde's traits but doesn't
+// know about imgui-inspect
+#[derive(Serialize, Deserialize)]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct GameSettings {
+    difficulty: Difficulty,
+    field_of_view: f32,
+    subtitles: bool,
+}
+
+// InspectSerde draws any Serialize + Deserialize type by converting it to a tree of values and back. Enum variants are
+// edited as text
+#[derive(Inspect)]
+pub struct SerdeExample {
+    #[inspect(proxy_type = "InspectSerde<GameSettings>")]
+    settings: GameSettings,
+}
+
+impl Default for SerdeExample {
+    fn default() -> Self {
+        SerdeExample {
+            settings: GameSettings {
+                difficulty: Difficulty::Normal,
+                field_of_view: 90.0,
+                subtitles: true,
+            },
+        }
+    }
+}
+
+// serde_json::Value (and toml::Value with the toml feature) is drawn as a tree. Each node has a selector for changing its
+// type, and objects and arrays can have entries added and removed
+#[derive(Inspect)]
+pub struct DocumentExample {
+    document: serde_json::Value,
+}
+
+impl Default for DocumentExample {
+    fn default() -> Self {
+        DocumentExample {
+            document: serde_json::json!({
+                "name": "level_1",
+                "gravity": -9.8,
+                "spawn_points": [[0, 0], [10, 5]],
+                "fog": { "enabled": true, "color": "gray" }
+            }),
+        }
+    }
+}
+
+// Right-clicking a field offers "Copy" and "Paste", and right-clicking a struct's header copies or pastes the whole
+// struct. Types that implement Display and FromStr are copied as text, and with the serde feature, types that
+// implement Serialize and Deserialize are copied as JSON
+#[derive(Inspect, Serialize, Deserialize)]
+pub struct Waypoint {
+    name: String,
+    x: f32,
+    y: f32,
+}
+
+#[derive(Inspect)]
+pub struct ClipboardExample {
+    // Try copying one waypoint and pasting it onto the other
+    start: Waypoint,
+    end: Waypoint,
+}
+
+impl Default for ClipboardExample {
+    fn default() -> Self {
+        ClipboardExample {
+            start: Waypoint {
+                name: "start".to_string(),
+                x: 0.0,
+                y: 0.0,
+            },
+            end: Waypoint {
+                name: "end".to_string(),
+                x: 100.0,
+                y: 50.0,
+            },
+        }
+    }
+}
+
+// Lists longer than a page are split into pages, and rows that are scrolled out of view aren't drawn, so this costs
+// about the same to draw as a short list
+#[derive(Inspect)]
+pub struct LargeListExample {
+    samples: Vec<f32>,
+}
+
+impl Default for LargeListExample {
+    fn default() -> Self {
+        LargeListExample {
+            samples: (0..100_000).map(|x| (x as f32 * 0.01).sin()).collect(),
+        }
+    }
+}
+
+// #[inspect(visitor)] also implements InspectVisit, which passes each field (with the args from its attributes) to an
+// InspectVisitor instead of drawing it with imgui. This lets the same struct drive other UI libraries
+#[derive(Inspect)]
+#[inspect(visitor)]
+pub struct VisitorExample {
+    #[inspect_slider(min_value = 0.0, max_value = 1.0)]
+    opacity: f32,
+    visible: bool,
+    tags: Vec<String>,
+}
+
+impl Default for VisitorExample {
+    fn default() -> Self {
+        VisitorExample {
+            opacity: 0.75,
+            visible: true,
+            tags: vec!["ui".to_string(), "overlay".to_string()],
+        }
+    }
+}
+
+// A visitor that describes each field as a line of text. Other backends work the same way, but draw widgets and return
+// true when a value is edited
+#[derive(Default)]
+struct TextVisitor {
+    lines: Vec<String>,
+    indent: usize,
+
+    // List elements are visited with an empty label, so they're labeled by their index instead
+    list_index: Option<usize>,
+}
+
+impl TextVisitor {
+    fn add_line(
+        &mut self,
+        label: &str,
+        value: &dyn std::fmt::Display,
+    ) {
+        let line = match self.list_index.take() {
+            Some(index) => format!(
+                "{:indent$}[{}] {}",
+                "",
+                index,
+                value,
+                indent = self.indent * 2
+            ),
+            None => format!(
+                "{:indent$}{}: {}",
+                "",
+                label,
+                value,
+                indent = self.indent * 2
+            ),
+        };
+        self.lines.push(line);
+    }
+}
+
+impl InspectVisitor for TextVisitor {
+    fn visit_bool(
+        &mut self,
+        label: &'static str,
+        values: &mut [&mut bool],
+        _args: &InspectArgsDefault,
+    ) -> bool {
+        if let Some(value) = values.first() {
+            self.add_line(label, value);
+        }
+        false
+    }
+
+    fn visit_f32(
+        &mut self,
+        label: &'static str,
+        values: &mut [&mut f32],
+        _args: &InspectArgsDefault,
+    ) -> bool {
+        if let Some(value) = values.first() {
+            self.add_line(label, value);
+        }
+        false
+    }
+
+    fn visit_u32(
+        &mut self,
+        label: &'static str,
+        values: &mut [&mut u32],
+        _args: &InspectArgsDefault,
+    ) -> bool {
+        if let Some(value) = values.first() {
+            self.add_line(label, value);
+        }
+        false
+    }
+
+    fn visit_usize(
+        &mut self,
+        label: &'static str,
+        values: &mut [&mut usize],
+        _args: &InspectArgsDefault,
+    ) -> bool {
+        if let Some(value) = values.first() {
+            self.add_line(label, value);
+        }
+        false
+    }
+
+    fn visit_string(
+        &mut self,
+        label: &'static str,
+        values: &mut [&mut String],
+        _args: &InspectArgsDefault,
+    ) -> bool {
+        if let Some(value) = values.first() {
+            self.add_line(label, value);
+        }
+        false
+    }
+
+    fn visit_slider_f32(
+        &mut self,
+        label: &'static str,
+        values: &mut [&mut f32],
+        args: &InspectArgsSlider,
+    ) -> bool {
+        if let Some(value) = values.first() {
+            let text = format!(
+                "{} (slider from {:?} to {:?})",
+                value, args.min_value, args.max_value
+            );
+            self.add_line(label, &text);
+        }
+        false
+    }
+
+    fn visit_struct(
+        &mut self,
+        label: &'static str,
+        type_name: &'static str,
+        _args: &InspectArgsStruct,
+        visit_fields: &mut dyn FnMut(&mut dyn InspectVisitor) -> bool,
+    ) -> bool {
+        self.add_line(label, &type_name);
+        self.indent += 1;
+        visit_fields(self);
+        self.indent -= 1;
+        false
+    }
+
+    fn visit_option(
+        &mut self,
+        label: &'static str,
+        is_some: &[bool],
+        visit_some: &mut dyn FnMut(&mut dyn InspectVisitor) -> bool,
+    ) -> bool {
+        if is_some.first() == Some(&true) {
+            visit_some(self);
+        } else {
+            self.add_line(label, &"None");
+        }
+        false
+    }
+
+    fn visit_list(
+        &mut self,
+        label: &'static str,
+        lens: &[usize],
+        visit_item: &mut dyn FnMut(&mut dyn InspectVisitor, usize) -> bool,
+    ) -> bool {
+        let len = lens.first().copied().unwrap_or(0);
+        self.add_line(label, &format!("{} items", len));
+        self.indent += 1;
+        for index in 0..len {
+            self.list_index = Some(index);
+            visit_item(self, index);
+        }
+        self.indent -= 1;
+        false
+    }
+
+    fn visit_unsupported(
+        &mut self,
+        label: &'static str,
+        type_name: &'static str,
+    ) -> bool {
+        self.add_line(label, &format!("<{}>", type_name));
+        false
+    }
+}
+
+fn draw_visitor_example(
+    ui: &imgui::Ui,
+    value: &mut VisitorExample,
+) {
+    // ImguiInspectVisitor draws visited fields with the same widgets as InspectRenderDefault
+    let mut imgui_visitor = ImguiInspectVisitor::new(ui);
+    <VisitorExample as InspectVisit<VisitorExample>>::visit(
+        &mut [&mut *value],
+        "visited_with_imgui",
+        &mut imgui_visitor,
+        &InspectArgsDefault::default(),
+    );
+
+    let mut text_visitor = TextVisitor::default();
+    <VisitorExample as InspectVisit<VisitorExample>>::visit(
+        &mut [value],
+        "visited_as_text",
+        &mut text_visitor,
+        &InspectArgsDefault::default(),
+    );
+    for line in &text_visitor.lines {
+        ui.text(line);
+    }
+}
+
+// imgui-inspect-remote inspects a value in another process. The inspected process sends a property tree (each field's
+// path, type, value, and args) to a tools app, which draws it and sends edits back. InspectServer and InspectClient do
+// this over a socket. To keep the demo in a single process, the same steps are done here without one
+fn draw_remote_example(
+    ui: &imgui::Ui,
+    value: &mut VisitorExample,
+) {
+    // In the inspected process, InspectServer::poll builds the tree when a client asks for it
+    let mut tree = match imgui_inspect_remote::property_tree(value, "remote_value") {
+        Some(tree) => tree,
+        None => return,
+    };
+
+    // In the tools app, InspectClient::fetch_tree receives the tree, and each edit is sent back with send_edit
+    let edits =
+        imgui_inspect_remote::visit_property_tree(&mut tree, &mut ImguiInspectVisitor::new(ui));
+
+    // Back in the inspected process, InspectServer::poll applies the edits it receives
+    for edit in &edits {
+        if let Err(e) = imgui_inspect_remote::apply_edit(value, "remote_value", edit) {
+            log::warn!("Failed to apply edit: {}", e);
+        }
+    }
+}
+
+// imgui tells widgets apart by their label, so objects drawn with the same label share state (i.e. whether their header
+// is open). An id gives each object its own scope
+#[derive(Inspect)]
+#[inspect(id = "self.id")]
+pub struct IdScopedEntity {
+    #[inspect(skip)]
+    id: u64,
+
+    name: String,
+    health: f32,
+}
+
+fn draw_widget_id_example(
+    ui: &imgui::Ui,
+    entities: &mut [IdScopedEntity],
+    id_check: &mut InspectIdCheck,
+) {
+    // While the check is active, a warning is drawn above anything whose ID was already used
+    id_check.scope(|| {
+        for entity in entities {
+            <IdScopedEntity as InspectRenderDefault<IdScopedEntity>>::render_mut(
+                &mut [entity],
+                "entity",
+                ui,
+                &InspectArgsDefault::default(),
+            );
+        }
+    });
+
+    ui.text(format!("Duplicate IDs: {}", id_check.duplicates().len()));
+}
+
+// Buffers of f32 can be drawn read-only as a plot, followed by the min, average, and max of the values
+#[derive(Inspect)]
+pub struct PlotExample {
+    #[inspect_plot(
+        kind = "histogram",
+        min = 0.0,
+        max = 33.3,
+        height = 80.0,
+        overlay_text = "frame time (ms)"
+    )]
+    frame_times: VecDeque<f32>,
+
+    // Without min and max, the plot is scaled to fit the values
+    #[inspect_plot(height = 60.0)]
+    wave: Vec<f32>,
+}
+
+impl PlotExample {
+    const MAX_FRAME_TIMES: usize = 120;
+
+    fn record_frame_time(
+        &mut self,
+        milliseconds: f32,
+    ) {
+        if self.frame_times.len() >= Self::MAX_FRAME_TIMES {
+            self.frame_times.pop_front();
+        }
+        self.frame_times.push_back(milliseconds);
+    }
+}
+
+impl Default for PlotExample {
+    fn default() -> Self {
+        PlotExample {
+            frame_times: VecDeque::with_capacity(Self::MAX_FRAME_TIMES),
+            wave: (0..64).map(|x| (x as f32 * 0.2).sin()).collect(),
+        }
+    }
+}
+
+// Keyframes and color stops can be edited with the curve and gradient editors. Drag a point to move it, double-click an
+// empty spot to add one, and right-click a point to remove it. Clicking a color stop selects it for editing its color
+#[derive(Inspect)]
+pub struct CurveExample {
+    #[inspect_curve(
+        interpolation = "cubic",
+        max_x = "self.duration",
+        min_y = 0.0,
+        max_y = 2.0
+    )]
+    intensity: Vec<(f32, f32)>,
+
+    #[inspect_gradient(height = 32.0)]
+    color_over_time: Vec<(f32, [f32; 4])>,
+
+    #[inspect_slider(min_value = 0.1, max_value = 10.0)]
+    duration: f32,
+}
+
+impl Default for CurveExample {
+    fn default() -> Self {
+        CurveExample {
+            intensity: vec![(0.0, 0.0), (0.5, 1.8), (1.5, 0.6), (2.0, 1.0)],
+            color_over_time: vec![
+                (0.0, [1.0, 0.9, 0.2, 1.0]),
+                (0.6, [1.0, 0.3, 0.0, 1.0]),
+                (1.0, [0.2, 0.2, 0.2, 0.0]),
+            ],
+            duration: 2.0,
+        }
+    }
+}
+
+#[derive(Inspect, Default)]
+pub struct ShowcaseValues {
+    on_set: OnSetExample,
+    generic_f32: GenericExample<f32>,
+    generic_string: GenericExample<String>,
+    remote: RemoteExample,
+    expression_args: ExpressionArgsExample,
+    reset_to_default: ResetToDefaultExample,
+    maps: MapExample,
+    plots: PlotExample,
+    curves: CurveExample,
+
+    // The larger sections start closed
+    #[inspect(default_open = false)]
+    smart_pointers: SmartPointerExample,
+
+    #[inspect(default_open = false)]
+    std_types: StdTypesExample,
+
+    #[inspect(default_open = false)]
+    math: MathExample,
+
+    #[inspect(default_open = false)]
+    serde: SerdeExample,
+
+    #[inspect(default_open = false)]
+    document: DocumentExample,
+
+    #[inspect(default_open = false)]
+    clipboard: ClipboardExample,
+
+    #[inspect(default_open = false)]
+    large_list: LargeListExample,
+}
+
+// The values drawn in the "Feature Showcase" window, along with any inspector state that's kept between frames
+pub struct FeatureShowcase {
+    values: ShowcaseValues,
+
+    // Hides fields whose path (i.e. "maps.high_scores") doesn't contain the text typed into the search bar
+    filter: InspectFilter,
+
+    // Remembers which headers are open and the window's scroll position. With the serde feature, it can be saved with
+    // the rest of an editor's settings so the inspector looks the same after a restart
+    layout: InspectLayoutState,
+    saved_layout: Option<String>,
+
+    // Drawn with visitors instead of InspectRenderDefault
+    visitor: VisitorExample,
+
+    // Drawn with the same label
+    entities: Vec<IdScopedEntity>,
+    id_check: InspectIdCheck,
+}
+
+impl Default for FeatureShowcase {
+    fn default() -> Self {
+        FeatureShowcase {
+            values: Default::default(),
+            filter: Default::default(),
+            layout: Default::default(),
+            saved_layout: None,
+            visitor: Default::default(),
+            entities: (0..3)
+                .map(|id| IdScopedEntity {
+                    id,
+                    name: format!("Entity {}", id),
+                    health: 100.0,
+                })
+                .collect(),
+            id_check: Default::default(),
+        }
+    }
+}
+
+// derive(Inspect) also implements InspectReflect, which lists a struct's fields along with their attributes, and gets or
+// sets fields by name without drawing them
+fn draw_reflection_example(
+    ui: &imgui::Ui,
+    value: &mut ExpressionArgsExample,
+) {
+    for field in value.inspect_fields() {
+        if let Some(current) = value
+            .get_field(field.name)
+            .and_then(|x| x.downcast_ref::<f32>())
+        {
+            ui.text(format!(
+                "{}: {} = {:.3}",
+                field.name, field.type_name, current
+            ));
+        }
+
+        for (arg, arg_value) in field.args {
+            ui.text(format!("    {} = {}", arg, arg_value));
+        }
+    }
+
+    if ui.button(imgui::im_str!("Set speed to max_speed"), [0.0, 0.0]) {
+        let max_speed = value
+            .get_field("max_speed")
+            .and_then(|x| x.downcast_ref::<f32>())
+            .copied();
+        if let Some(max_speed) = max_speed {
+            // Setting a field this way doesn't run on_set callbacks
+            value.set_field("speed", Box::new(max_speed)).unwrap();
+        }
+    }
+}
+
+fn draw_feature_showcase(
+    ui: &imgui::Ui,
+    showcase: &mut FeatureShowcase,
+) {
+    imgui::Window::new(imgui::im_str!("Feature Showcase"))
+        .position([20.0, 20.0], imgui::Condition::Once)
+        .size([520.0, 560.0], imgui::Condition::Once)
+        .build(ui, || {
+            showcase
+                .values
+                .plots
+                .record_frame_time(ui.io().delta_time * 1000.0);
+
+            showcase.filter.render_search_bar(ui);
+
+            if ui.small_button(imgui::im_str!("Save Layout")) {
+                showcase.saved_layout = serde_json::to_string(&showcase.layout).ok();
+            }
+            ui.same_line(0.0);
+            if ui.small_button(imgui::im_str!("Restore Layout")) {
+                if let Some(layout) = showcase
+                    .saved_layout
+                    .as_ref()
+                    .and_then(|x| serde_json::from_str(x).ok())
+                {
+                    showcase.layout = layout;
+                }
+            }
+
+            let values = &mut showcase.values;
+            let filter = &showcase.filter;
+            showcase.layout.scope(ui, || {
+                filter.scope(|| {
+                    <ShowcaseValues as InspectRenderDefault<ShowcaseValues>>::render_mut(
+                        &mut [values],
+                        "Features",
+                        ui,
+                        &InspectArgsDefault::default(),
+                    );
+                });
+            });
+
+            if imgui::CollapsingHeader::new(imgui::im_str!("Reflection")).build(ui) {
+                draw_reflection_example(ui, &mut showcase.values.expression_args);
+            }
+
+            if imgui::CollapsingHeader::new(imgui::im_str!("Visitors")).build(ui) {
+                draw_visitor_example(ui, &mut showcase.visitor);
+            }
+
+            if imgui::CollapsingHeader::new(imgui::im_str!("Remote Inspection")).build(ui) {
+                draw_remote_example(ui, &mut showcase.visitor);
+            }
+
+            if imgui::CollapsingHeader::new(imgui::im_str!("Widget IDs")).build(ui) {
+                draw_widget_id_example(ui, &mut showcase.entities, &mut showcase.id_check);
+            }
+        });
+}
+
 fn draw_imgui(
     imgui_manager: &ImguiManager,
     example_inspect_target: &mut ExampleInspectTarget,
+    feature_showcase: &mut FeatureShowcase,
 ) {
     //
     //Draw an inspect window for the example struct
@@ -87,6 +957,13 @@ fn draw_imgui(
                 });
         });
     }
+
+    //
+    //Draw a window with an example of each feature
+    //
+    imgui_manager.with_ui(|ui: &mut imgui::Ui| {
+        draw_feature_showcase(ui, feature_showcase);
+    });
 }
 
 // Creates a window and runs the event loop.
@@ -127,6 +1004,7 @@ fn main() {
 
     // This is the thing we will inspect
     let mut example_inspect_target = ExampleInspectTarget::default();
+    let mut feature_showcase = FeatureShowcase::default();
 
     // Start the window event loop. Winit will not return once run is called. We will get notified
     // when important events happen.
@@ -171,7 +1049,11 @@ fn main() {
             //
             winit::event::Event::RedrawRequested(_window_id) => {
                 imgui_manager.begin_frame(&window);
-                draw_imgui(&imgui_manager, &mut example_inspect_target);
+                draw_imgui(
+                    &imgui_manager,
+                    &mut example_inspect_target,
+                    &mut feature_showcase,
+                );
                 imgui_manager.render(&window);
                 if let Err(e) =
                     renderer.draw(&window, imgui_manager.draw_data(), &example_inspect_target)
@@ -0,0 +1,187 @@
+use darling::FromField;
+use quote::quote;
+use super::*;
+
+//
+// Curve arg handling
+//
+#[derive(Debug, FromField, Clone)]
+#[darling(attributes(inspect_curve))]
+pub struct InspectFieldArgsCurve {
+    ident: Option<syn::Ident>,
+    ty: syn::Type,
+
+    #[darling(default)]
+    render_trait: Option<syn::Path>,
+
+    #[darling(default)]
+    proxy_type: Option<syn::Path>,
+
+    #[darling(default)]
+    with: Option<syn::Path>,
+
+    #[darling(default)]
+    on_set: Option<OnSetCallback>,
+
+    #[darling(default)]
+    on_set_old_value: bool,
+
+    #[darling(default)]
+    on_set_field_name: bool,
+
+    #[darling(default)]
+    skip: bool,
+
+    #[darling(default)]
+    default: Option<InspectExpr>,
+
+    #[darling(default)]
+    id: Option<InspectExpr>,
+
+    #[darling(default)]
+    interpolation: Option<CurveInterpolation>,
+
+    #[darling(default)]
+    min_x: Option<InspectArgValue>,
+
+    #[darling(default)]
+    max_x: Option<InspectArgValue>,
+
+    #[darling(default)]
+    min_y: Option<InspectArgValue>,
+
+    #[darling(default)]
+    max_y: Option<InspectArgValue>,
+
+    #[darling(default)]
+    height: Option<InspectArgValue>,
+}
+
+impl InspectFieldArgs for InspectFieldArgsCurve {
+    fn ident(&self) -> &Option<syn::Ident> {
+        &self.ident
+    }
+    fn ty(&self) -> &syn::Type {
+        &self.ty
+    }
+    fn render_trait(&self) -> &Option<syn::Path> {
+        &self.render_trait
+    }
+    fn proxy_type(&self) -> &Option<syn::Path> {
+        &self.proxy_type
+    }
+    fn with(&self) -> &Option<syn::Path> {
+        &self.with
+    }
+    fn on_set(&self) -> &Option<OnSetCallback> {
+        &self.on_set
+    }
+    fn on_set_old_value(&self) -> bool {
+        self.on_set_old_value
+    }
+    fn on_set_field_name(&self) -> bool {
+        self.on_set_field_name
+    }
+    fn skip(&self) -> bool {
+        self.skip
+    }
+    fn default_value(&self) -> &Option<InspectExpr> {
+        &self.default
+    }
+    fn id(&self) -> &Option<InspectExpr> {
+        &self.id
+    }
+    fn arg_values(&self) -> Vec<&InspectArgValue> {
+        [
+            &self.min_x,
+            &self.max_x,
+            &self.min_y,
+            &self.max_y,
+            &self.height,
+        ]
+        .iter()
+        .filter_map(|x| x.as_ref())
+        .collect()
+    }
+}
+
+// interpolation = "linear", "step", or "cubic"
+#[derive(Debug, Clone, Copy)]
+pub enum CurveInterpolation {
+    Linear,
+    Step,
+    Cubic,
+}
+
+impl darling::FromMeta for CurveInterpolation {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        match value {
+            "linear" => Ok(CurveInterpolation::Linear),
+            "step" => Ok(CurveInterpolation::Step),
+            "cubic" => Ok(CurveInterpolation::Cubic),
+            _ => Err(darling::Error::unknown_value(value)),
+        }
+    }
+}
+
+impl quote::ToTokens for CurveInterpolation {
+    fn to_tokens(
+        &self,
+        tokens: &mut proc_macro2::TokenStream,
+    ) {
+        tokens.extend(match self {
+            CurveInterpolation::Linear => quote!(imgui_inspect::InspectCurveInterpolation::Linear),
+            CurveInterpolation::Step => quote!(imgui_inspect::InspectCurveInterpolation::Step),
+            CurveInterpolation::Cubic => quote!(imgui_inspect::InspectCurveInterpolation::Cubic),
+        });
+    }
+}
+
+#[derive(Debug)]
+pub struct InspectArgsCurve {
+    interpolation: Option<CurveInterpolation>,
+    min_x: Option<InspectArgValue>,
+    max_x: Option<InspectArgValue>,
+    min_y: Option<InspectArgValue>,
+    max_y: Option<InspectArgValue>,
+    height: Option<InspectArgValue>,
+}
+
+impl From<InspectFieldArgsCurve> for InspectArgsCurve {
+    fn from(field_args: InspectFieldArgsCurve) -> Self {
+        Self {
+            interpolation: field_args.interpolation,
+            min_x: field_args.min_x,
+            max_x: field_args.max_x,
+            min_y: field_args.min_y,
+            max_y: field_args.max_y,
+            height: field_args.height,
+        }
+    }
+}
+
+impl quote::ToTokens for InspectArgsCurve {
+    fn to_tokens(
+        &self,
+        tokens: &mut proc_macro2::TokenStream,
+    ) {
+        let interpolation = expand_to_tokens(&self.interpolation);
+        let min_x = expand_to_tokens(&self.min_x);
+        let max_x = expand_to_tokens(&self.max_x);
+        let min_y = expand_to_tokens(&self.min_y);
+        let max_y = expand_to_tokens(&self.max_y);
+        let height = expand_to_tokens(&self.height);
+
+        use quote::TokenStreamExt;
+        tokens.append_all(quote!(
+            imgui_inspect::InspectArgsCurve {
+                interpolation: #interpolation,
+                min_x: #min_x,
+                max_x: #max_x,
+                min_y: #min_y,
+                max_y: #max_y,
+                height: #height,
+            }
+        ));
+    }
+}
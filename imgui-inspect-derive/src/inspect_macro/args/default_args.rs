@@ -18,19 +18,34 @@ pub struct InspectFieldArgsDefault {
     proxy_type: Option<syn::Path>,
 
     #[darling(default)]
-    on_set: Option<syn::Ident>,
+    with: Option<syn::Path>,
+
+    #[darling(default)]
+    on_set: Option<OnSetCallback>,
+
+    #[darling(default)]
+    on_set_old_value: bool,
+
+    #[darling(default)]
+    on_set_field_name: bool,
 
     #[darling(default)]
     skip: bool,
 
     #[darling(default)]
-    min_value: Option<f32>,
+    default: Option<InspectExpr>,
+
+    #[darling(default)]
+    id: Option<InspectExpr>,
+
+    #[darling(default)]
+    min_value: Option<InspectArgValue>,
 
     #[darling(default)]
-    max_value: Option<f32>,
+    max_value: Option<InspectArgValue>,
 
     #[darling(default)]
-    step: Option<f32>,
+    step: Option<InspectArgValue>,
 
     //TODO: Default to true
     #[darling(default)]
@@ -39,6 +54,9 @@ pub struct InspectFieldArgsDefault {
     //TODO: Default to true
     #[darling(default)]
     indent_children: Option<bool>,
+
+    #[darling(default)]
+    default_open: Option<bool>,
 }
 
 impl InspectFieldArgs for InspectFieldArgsDefault {
@@ -54,21 +72,43 @@ impl InspectFieldArgs for InspectFieldArgsDefault {
     fn proxy_type(&self) -> &Option<syn::Path> {
         &self.proxy_type
     }
-    fn on_set(&self) -> &Option<syn::Ident> {
+    fn with(&self) -> &Option<syn::Path> {
+        &self.with
+    }
+    fn on_set(&self) -> &Option<OnSetCallback> {
         &self.on_set
     }
+    fn on_set_old_value(&self) -> bool {
+        self.on_set_old_value
+    }
+    fn on_set_field_name(&self) -> bool {
+        self.on_set_field_name
+    }
     fn skip(&self) -> bool {
         self.skip
     }
+    fn default_value(&self) -> &Option<InspectExpr> {
+        &self.default
+    }
+    fn id(&self) -> &Option<InspectExpr> {
+        &self.id
+    }
+    fn arg_values(&self) -> Vec<&InspectArgValue> {
+        [&self.min_value, &self.max_value, &self.step]
+            .iter()
+            .filter_map(|x| x.as_ref())
+            .collect()
+    }
 }
 
 #[derive(Debug)]
 pub struct InspectArgsDefault {
-    min_value: Option<f32>,
-    max_value: Option<f32>,
-    step: Option<f32>,
+    min_value: Option<InspectArgValue>,
+    max_value: Option<InspectArgValue>,
+    step: Option<InspectArgValue>,
     header: Option<bool>,
     indent_children: Option<bool>,
+    default_open: Option<bool>,
 }
 
 impl From<InspectFieldArgsDefault> for InspectArgsDefault {
@@ -79,6 +119,7 @@ impl From<InspectFieldArgsDefault> for InspectArgsDefault {
             step: field_args.step,
             header: field_args.header,
             indent_children: field_args.indent_children,
+            default_open: field_args.default_open,
         }
     }
 }
@@ -93,6 +134,7 @@ impl quote::ToTokens for InspectArgsDefault {
         let step = expand_to_tokens(&self.step);
         let header = expand_to_tokens(&self.header);
         let indent_children = expand_to_tokens(&self.indent_children);
+        let default_open = expand_to_tokens(&self.default_open);
 
         use quote::TokenStreamExt;
         tokens.append_all(quote!(
@@ -102,6 +144,7 @@ impl quote::ToTokens for InspectArgsDefault {
                 step: #step,
                 header: #header,
                 indent_children: #indent_children,
+                default_open: #default_open,
             }
         ));
     }
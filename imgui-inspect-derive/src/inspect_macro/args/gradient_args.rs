@@ -0,0 +1,111 @@
+use darling::FromField;
+use quote::quote;
+use super::*;
+
+//
+// Gradient arg handling
+//
+#[derive(Debug, FromField, Clone)]
+#[darling(attributes(inspect_gradient))]
+pub struct InspectFieldArgsGradient {
+    ident: Option<syn::Ident>,
+    ty: syn::Type,
+
+    #[darling(default)]
+    render_trait: Option<syn::Path>,
+
+    #[darling(default)]
+    proxy_type: Option<syn::Path>,
+
+    #[darling(default)]
+    with: Option<syn::Path>,
+
+    #[darling(default)]
+    on_set: Option<OnSetCallback>,
+
+    #[darling(default)]
+    on_set_old_value: bool,
+
+    #[darling(default)]
+    on_set_field_name: bool,
+
+    #[darling(default)]
+    skip: bool,
+
+    #[darling(default)]
+    default: Option<InspectExpr>,
+
+    #[darling(default)]
+    id: Option<InspectExpr>,
+
+    #[darling(default)]
+    height: Option<InspectArgValue>,
+}
+
+impl InspectFieldArgs for InspectFieldArgsGradient {
+    fn ident(&self) -> &Option<syn::Ident> {
+        &self.ident
+    }
+    fn ty(&self) -> &syn::Type {
+        &self.ty
+    }
+    fn render_trait(&self) -> &Option<syn::Path> {
+        &self.render_trait
+    }
+    fn proxy_type(&self) -> &Option<syn::Path> {
+        &self.proxy_type
+    }
+    fn with(&self) -> &Option<syn::Path> {
+        &self.with
+    }
+    fn on_set(&self) -> &Option<OnSetCallback> {
+        &self.on_set
+    }
+    fn on_set_old_value(&self) -> bool {
+        self.on_set_old_value
+    }
+    fn on_set_field_name(&self) -> bool {
+        self.on_set_field_name
+    }
+    fn skip(&self) -> bool {
+        self.skip
+    }
+    fn default_value(&self) -> &Option<InspectExpr> {
+        &self.default
+    }
+    fn id(&self) -> &Option<InspectExpr> {
+        &self.id
+    }
+    fn arg_values(&self) -> Vec<&InspectArgValue> {
+        [&self.height].iter().filter_map(|x| x.as_ref()).collect()
+    }
+}
+
+#[derive(Debug)]
+pub struct InspectArgsGradient {
+    height: Option<InspectArgValue>,
+}
+
+impl From<InspectFieldArgsGradient> for InspectArgsGradient {
+    fn from(field_args: InspectFieldArgsGradient) -> Self {
+        Self {
+            height: field_args.height,
+        }
+    }
+}
+
+impl quote::ToTokens for InspectArgsGradient {
+    fn to_tokens(
+        &self,
+        tokens: &mut proc_macro2::TokenStream,
+    ) {
+        let height = expand_to_tokens(&self.height);
+
+        use quote::TokenStreamExt;
+        tokens.append_all(quote!(
+            imgui_inspect::InspectArgsGradient {
+                height: #height,
+            }
+        ));
+    }
+}
@@ -1,9 +1,12 @@
 mod default_args;
 mod struct_args;
 mod slider_args;
+mod plot_args;
+mod curve_args;
+mod gradient_args;
 
 use darling::FromDeriveInput;
-use quote::quote;
+use quote::{quote, ToTokens};
 
 pub use default_args::InspectFieldArgsDefault;
 pub use default_args::InspectArgsDefault;
@@ -11,6 +14,12 @@ pub use struct_args::InspectFieldArgsStruct;
 pub use struct_args::InspectArgsStruct;
 pub use slider_args::InspectFieldArgsSlider;
 pub use slider_args::InspectArgsSlider;
+pub use plot_args::InspectFieldArgsPlot;
+pub use plot_args::InspectArgsPlot;
+pub use curve_args::InspectFieldArgsCurve;
+pub use curve_args::InspectArgsCurve;
+pub use gradient_args::InspectFieldArgsGradient;
+pub use gradient_args::InspectArgsGradient;
 
 // Utility function to convert an Option<T> to tokens
 pub fn expand_to_tokens<T: quote::ToTokens>(input: &Option<T>) -> proc_macro2::TokenStream {
@@ -20,11 +29,206 @@ pub fn expand_to_tokens<T: quote::ToTokens>(input: &Option<T>) -> proc_macro2::T
     }
 }
 
+// The callback named by `on_set`. A bare identifier (i.e. `on_set = "recalc"`) is called as a
+// method on each changed object. Anything else, like a path (`on_set = "Self::recalc"`) or a
+// closure (`on_set = "|x: &mut MyStruct| x.recalc()"`), is called with the object as the first
+// parameter.
+#[derive(Debug, Clone)]
+pub enum OnSetCallback {
+    Method(syn::Ident),
+    Function(Box<syn::Expr>),
+}
+
+impl darling::FromMeta for OnSetCallback {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        let expr =
+            syn::parse_str::<syn::Expr>(value).map_err(|_| darling::Error::unknown_value(value))?;
+
+        match expr {
+            syn::Expr::Path(ref expr_path) if expr_path.qself.is_none() => {
+                match expr_path.path.get_ident() {
+                    Some(ident) => Ok(OnSetCallback::Method(ident.clone())),
+                    None => Ok(OnSetCallback::Function(Box::new(expr))),
+                }
+            }
+            syn::Expr::Path(_) | syn::Expr::Closure(_) => {
+                Ok(OnSetCallback::Function(Box::new(expr)))
+            }
+            _ => Err(darling::Error::custom(
+                "on_set must be a method name, a path to a function, or a closure",
+            )),
+        }
+    }
+}
+
+// `self` isn't available in the generated render functions, so it is replaced by a binding to the first
+// selected object. self::some_module::CONST style paths are left alone.
+fn replace_self(tokens: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let mut replaced = vec![];
+    let mut iter = tokens.into_iter().peekable();
+    while let Some(token) = iter.next() {
+        let token = match token {
+            proc_macro2::TokenTree::Ident(ident) if ident == "self" => {
+                let is_path = match iter.peek() {
+                    Some(proc_macro2::TokenTree::Punct(p)) => p.as_char() == ':',
+                    _ => false,
+                };
+
+                if is_path {
+                    proc_macro2::TokenTree::Ident(ident)
+                } else {
+                    proc_macro2::TokenTree::Ident(syn::Ident::new("_inspect_self", ident.span()))
+                }
+            }
+            proc_macro2::TokenTree::Group(group) => {
+                let mut new_group =
+                    proc_macro2::Group::new(group.delimiter(), replace_self(group.stream()));
+                new_group.set_span(group.span());
+                proc_macro2::TokenTree::Group(new_group)
+            }
+            token => token,
+        };
+        replaced.push(token);
+    }
+
+    replaced.into_iter().collect()
+}
+
+// A value for a widget arg like min_value. This can be a literal (min_value = 5.0) or a string containing an
+// expression. The expression can be anything that evaluates to the arg's type, like a const
+// (max_value = "std::f32::consts::TAU") or a per-instance value (max_value = "self.max_radius"). When multiple objects
+// are selected, `self` refers to the first one.
+#[derive(Debug, Clone)]
+pub struct InspectArgValue(syn::Expr);
+
+impl InspectArgValue {
+    // Literals can be emitted into a const. Anything else might refer to `self`, Self, generic parameters, or call
+    // non-const functions, so it must be evaluated with a let
+    pub fn is_literal(&self) -> bool {
+        matches!(self.0, syn::Expr::Lit(_))
+    }
+
+    pub fn uses_self(&self) -> bool {
+        fn tokens_use_self(tokens: proc_macro2::TokenStream) -> bool {
+            tokens.into_iter().any(|token| match token {
+                proc_macro2::TokenTree::Ident(ident) => ident == "self",
+                proc_macro2::TokenTree::Group(group) => tokens_use_self(group.stream()),
+                _ => false,
+            })
+        }
+
+        tokens_use_self(self.0.to_token_stream())
+    }
+}
+
+impl darling::FromMeta for InspectArgValue {
+    fn from_value(value: &syn::Lit) -> darling::Result<Self> {
+        let expr = match value {
+            syn::Lit::Str(s) => s
+                .parse::<syn::Expr>()
+                .map_err(|_| darling::Error::unknown_value(&s.value()))?,
+            syn::Lit::Int(_) | syn::Lit::Float(_) => syn::parse_quote!(#value),
+            _ => return Err(darling::Error::unexpected_lit_type(value)),
+        };
+
+        Ok(InspectArgValue(int_to_float(expr)))
+    }
+}
+
+// Integer literals are allowed for convenience (i.e. max_value = 100 or max_value = "-100"), but the args are floats
+fn int_to_float(expr: syn::Expr) -> syn::Expr {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(i),
+            ..
+        }) => {
+            let f = syn::LitFloat::new(&format!("{}.0", i.base10_digits()), i.span());
+            syn::parse_quote!(#f)
+        }
+        syn::Expr::Unary(mut unary) if matches!(unary.op, syn::UnOp::Neg(_)) => {
+            *unary.expr = int_to_float(*unary.expr);
+            syn::Expr::Unary(unary)
+        }
+        expr => expr,
+    }
+}
+
+impl quote::ToTokens for InspectArgValue {
+    fn to_tokens(
+        &self,
+        tokens: &mut proc_macro2::TokenStream,
+    ) {
+        let expr = &self.0;
+        tokens.extend(replace_self(quote!(#expr)));
+    }
+}
+
+// An arbitrary expression, given as a string (i.e. default = "Vec::new()"). Other literals are used as-is, so
+// default = 5.0 and default = true work too.
+#[derive(Debug, Clone)]
+pub struct InspectExpr(syn::Expr);
+
+impl darling::FromMeta for InspectExpr {
+    fn from_value(value: &syn::Lit) -> darling::Result<Self> {
+        let expr = match value {
+            syn::Lit::Str(s) => s
+                .parse::<syn::Expr>()
+                .map_err(|_| darling::Error::unknown_value(&s.value()))?,
+            _ => syn::parse_quote!(#value),
+        };
+
+        Ok(InspectExpr(expr))
+    }
+}
+
+impl InspectExpr {
+    // The expression with `self` replaced by a binding to the first selected object, for expressions that are
+    // evaluated per-instance like #[inspect(id = "self.guid")]
+    pub fn with_self_replaced(&self) -> proc_macro2::TokenStream {
+        replace_self(self.0.to_token_stream())
+    }
+}
+
+impl quote::ToTokens for InspectExpr {
+    fn to_tokens(
+        &self,
+        tokens: &mut proc_macro2::TokenStream,
+    ) {
+        self.0.to_tokens(tokens);
+    }
+}
+
 // Metadata from the struct's type annotation
 #[derive(Debug, FromDeriveInput)]
 #[darling(attributes(inspect))]
 pub struct InspectStructArgs {
     pub ident: syn::Ident,
+
+    // Overrides the where predicates that would otherwise be inferred from the fields' types (i.e.
+    // #[inspect(bound = "T: MyTrait")])
+    #[darling(default)]
+    pub bound: Option<String>,
+
+    // Generate impls that render a type from another crate instead of this struct. This struct must mirror the
+    // fields of the remote type (i.e. #[inspect(remote = "other_crate::Color4f")]) and is used as a proxy_type
+    #[darling(default)]
+    pub remote: Option<syn::Path>,
+
+    // Like #[serde(default)], fields that aren't given their own default use the value from the struct's Default impl.
+    // Fields with a default can be reset from a context menu and are highlighted when changed.
+    #[darling(default)]
+    pub default: bool,
+
+    // Also implement InspectVisit, which passes the fields and their parsed args to an InspectVisitor instead of
+    // drawing them with imgui. Only bool, f32, u32, usize, String, sliders, structs with #[inspect(visitor)], Option,
+    // Vec, and VecDeque are visited. Other fields are passed to visit_unsupported.
+    #[darling(default)]
+    pub visitor: bool,
+
+    // An ID pushed for each object in addition to the label, so that objects drawn with the same label don't share
+    // widget state (i.e. #[inspect(id = "self.guid")])
+    #[darling(default)]
+    pub id: Option<InspectExpr>,
 }
 
 // We support multiple distinct inspect annotations (i.e. inspect_slider, inspect_text)
@@ -35,6 +239,14 @@ pub trait InspectFieldArgs {
     fn ty(&self) -> &syn::Type;
     fn render_trait(&self) -> &Option<syn::Path>;
     fn proxy_type(&self) -> &Option<syn::Path>;
-    fn on_set(&self) -> &Option<syn::Ident>;
+    fn with(&self) -> &Option<syn::Path>;
+    fn on_set(&self) -> &Option<OnSetCallback>;
+    fn on_set_old_value(&self) -> bool;
+    fn on_set_field_name(&self) -> bool;
     fn skip(&self) -> bool;
+    fn default_value(&self) -> &Option<InspectExpr>;
+    fn id(&self) -> &Option<InspectExpr>;
+
+    // All widget arg values given as expressions, used to decide how the args are emitted
+    fn arg_values(&self) -> Vec<&InspectArgValue>;
 }
@@ -0,0 +1,171 @@
+use darling::FromField;
+use quote::quote;
+use super::*;
+
+//
+// Plot arg handling
+//
+#[derive(Debug, FromField, Clone)]
+#[darling(attributes(inspect_plot))]
+pub struct InspectFieldArgsPlot {
+    ident: Option<syn::Ident>,
+    ty: syn::Type,
+
+    #[darling(default)]
+    render_trait: Option<syn::Path>,
+
+    #[darling(default)]
+    proxy_type: Option<syn::Path>,
+
+    #[darling(default)]
+    with: Option<syn::Path>,
+
+    #[darling(default)]
+    on_set: Option<OnSetCallback>,
+
+    #[darling(default)]
+    on_set_old_value: bool,
+
+    #[darling(default)]
+    on_set_field_name: bool,
+
+    #[darling(default)]
+    skip: bool,
+
+    #[darling(default)]
+    default: Option<InspectExpr>,
+
+    #[darling(default)]
+    id: Option<InspectExpr>,
+
+    #[darling(default)]
+    kind: Option<PlotKind>,
+
+    #[darling(default)]
+    min: Option<InspectArgValue>,
+
+    #[darling(default)]
+    max: Option<InspectArgValue>,
+
+    #[darling(default)]
+    height: Option<InspectArgValue>,
+
+    #[darling(default)]
+    overlay_text: Option<String>,
+}
+
+impl InspectFieldArgs for InspectFieldArgsPlot {
+    fn ident(&self) -> &Option<syn::Ident> {
+        &self.ident
+    }
+    fn ty(&self) -> &syn::Type {
+        &self.ty
+    }
+    fn render_trait(&self) -> &Option<syn::Path> {
+        &self.render_trait
+    }
+    fn proxy_type(&self) -> &Option<syn::Path> {
+        &self.proxy_type
+    }
+    fn with(&self) -> &Option<syn::Path> {
+        &self.with
+    }
+    fn on_set(&self) -> &Option<OnSetCallback> {
+        &self.on_set
+    }
+    fn on_set_old_value(&self) -> bool {
+        self.on_set_old_value
+    }
+    fn on_set_field_name(&self) -> bool {
+        self.on_set_field_name
+    }
+    fn skip(&self) -> bool {
+        self.skip
+    }
+    fn default_value(&self) -> &Option<InspectExpr> {
+        &self.default
+    }
+    fn id(&self) -> &Option<InspectExpr> {
+        &self.id
+    }
+    fn arg_values(&self) -> Vec<&InspectArgValue> {
+        [&self.min, &self.max, &self.height]
+            .iter()
+            .filter_map(|x| x.as_ref())
+            .collect()
+    }
+}
+
+// kind = "lines" or kind = "histogram"
+#[derive(Debug, Clone, Copy)]
+pub enum PlotKind {
+    Lines,
+    Histogram,
+}
+
+impl darling::FromMeta for PlotKind {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        match value {
+            "lines" => Ok(PlotKind::Lines),
+            "histogram" => Ok(PlotKind::Histogram),
+            _ => Err(darling::Error::unknown_value(value)),
+        }
+    }
+}
+
+impl quote::ToTokens for PlotKind {
+    fn to_tokens(
+        &self,
+        tokens: &mut proc_macro2::TokenStream,
+    ) {
+        tokens.extend(match self {
+            PlotKind::Lines => quote!(imgui_inspect::InspectPlotKind::Lines),
+            PlotKind::Histogram => quote!(imgui_inspect::InspectPlotKind::Histogram),
+        });
+    }
+}
+
+#[derive(Debug)]
+pub struct InspectArgsPlot {
+    kind: Option<PlotKind>,
+    min: Option<InspectArgValue>,
+    max: Option<InspectArgValue>,
+    height: Option<InspectArgValue>,
+    overlay_text: Option<String>,
+}
+
+impl From<InspectFieldArgsPlot> for InspectArgsPlot {
+    fn from(field_args: InspectFieldArgsPlot) -> Self {
+        Self {
+            kind: field_args.kind,
+            min: field_args.min,
+            max: field_args.max,
+            height: field_args.height,
+            overlay_text: field_args.overlay_text,
+        }
+    }
+}
+
+impl quote::ToTokens for InspectArgsPlot {
+    fn to_tokens(
+        &self,
+        tokens: &mut proc_macro2::TokenStream,
+    ) {
+        let kind = expand_to_tokens(&self.kind);
+        let min = expand_to_tokens(&self.min);
+        let max = expand_to_tokens(&self.max);
+        let height = expand_to_tokens(&self.height);
+        let overlay_text = expand_to_tokens(&self.overlay_text);
+
+        use quote::TokenStreamExt;
+        tokens.append_all(quote!(
+            imgui_inspect::InspectArgsPlot {
+                kind: #kind,
+                min: #min,
+                max: #max,
+                height: #height,
+                overlay_text: #overlay_text,
+            }
+        ));
+    }
+}
@@ -18,16 +18,31 @@ pub struct InspectFieldArgsSlider {
     proxy_type: Option<syn::Path>,
 
     #[darling(default)]
-    on_set: Option<syn::Ident>,
+    with: Option<syn::Path>,
+
+    #[darling(default)]
+    on_set: Option<OnSetCallback>,
+
+    #[darling(default)]
+    on_set_old_value: bool,
+
+    #[darling(default)]
+    on_set_field_name: bool,
 
     #[darling(default)]
     skip: bool,
 
     #[darling(default)]
-    min_value: Option<f32>,
+    default: Option<InspectExpr>,
+
+    #[darling(default)]
+    id: Option<InspectExpr>,
 
     #[darling(default)]
-    max_value: Option<f32>,
+    min_value: Option<InspectArgValue>,
+
+    #[darling(default)]
+    max_value: Option<InspectArgValue>,
 }
 
 impl InspectFieldArgs for InspectFieldArgsSlider {
@@ -43,18 +58,39 @@ impl InspectFieldArgs for InspectFieldArgsSlider {
     fn proxy_type(&self) -> &Option<syn::Path> {
         &self.proxy_type
     }
-    fn on_set(&self) -> &Option<syn::Ident> {
+    fn with(&self) -> &Option<syn::Path> {
+        &self.with
+    }
+    fn on_set(&self) -> &Option<OnSetCallback> {
         &self.on_set
     }
+    fn on_set_old_value(&self) -> bool {
+        self.on_set_old_value
+    }
+    fn on_set_field_name(&self) -> bool {
+        self.on_set_field_name
+    }
     fn skip(&self) -> bool {
         self.skip
     }
+    fn default_value(&self) -> &Option<InspectExpr> {
+        &self.default
+    }
+    fn id(&self) -> &Option<InspectExpr> {
+        &self.id
+    }
+    fn arg_values(&self) -> Vec<&InspectArgValue> {
+        [&self.min_value, &self.max_value]
+            .iter()
+            .filter_map(|x| x.as_ref())
+            .collect()
+    }
 }
 
 #[derive(Debug)]
 pub struct InspectArgsSlider {
-    min_value: Option<f32>,
-    max_value: Option<f32>,
+    min_value: Option<InspectArgValue>,
+    max_value: Option<InspectArgValue>,
 }
 
 impl From<InspectFieldArgsSlider> for InspectArgsSlider {
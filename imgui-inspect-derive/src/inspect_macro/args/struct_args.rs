@@ -18,11 +18,26 @@ pub struct InspectFieldArgsStruct {
     proxy_type: Option<syn::Path>,
 
     #[darling(default)]
-    on_set: Option<syn::Ident>,
+    with: Option<syn::Path>,
+
+    #[darling(default)]
+    on_set: Option<OnSetCallback>,
+
+    #[darling(default)]
+    on_set_old_value: bool,
+
+    #[darling(default)]
+    on_set_field_name: bool,
 
     #[darling(default)]
     skip: bool,
 
+    #[darling(default)]
+    default: Option<InspectExpr>,
+
+    #[darling(default)]
+    id: Option<InspectExpr>,
+
     #[darling(default)]
     min_value: Option<f32>,
 
@@ -36,6 +51,9 @@ pub struct InspectFieldArgsStruct {
     //TODO: Default to true
     #[darling(default)]
     indent_children: Option<bool>,
+
+    #[darling(default)]
+    default_open: Option<bool>,
 }
 
 impl InspectFieldArgs for InspectFieldArgsStruct {
@@ -51,15 +69,33 @@ impl InspectFieldArgs for InspectFieldArgsStruct {
     fn proxy_type(&self) -> &Option<syn::Path> {
         &self.proxy_type
     }
-    fn on_set(&self) -> &Option<syn::Ident> {
+    fn with(&self) -> &Option<syn::Path> {
+        &self.with
+    }
+    fn on_set(&self) -> &Option<OnSetCallback> {
         &self.on_set
     }
+    fn on_set_old_value(&self) -> bool {
+        self.on_set_old_value
+    }
+    fn on_set_field_name(&self) -> bool {
+        self.on_set_field_name
+    }
     fn skip(&self) -> bool {
         self.skip
     }
+    fn default_value(&self) -> &Option<InspectExpr> {
+        &self.default
+    }
+    fn id(&self) -> &Option<InspectExpr> {
+        &self.id
+    }
+    // None of the struct args are expressions, so they can always be emitted into a const
+    fn arg_values(&self) -> Vec<&InspectArgValue> {
+        vec![]
+    }
 }
 
-#[allow(dead_code)]
 #[derive(Debug)]
 pub struct InspectArgsStruct {
     //TODO: Default to true
@@ -67,6 +103,8 @@ pub struct InspectArgsStruct {
 
     //TODO: Default to true
     pub indent_children: Option<bool>,
+
+    pub default_open: Option<bool>,
 }
 
 impl From<InspectFieldArgsStruct> for InspectArgsStruct {
@@ -74,6 +112,7 @@ impl From<InspectFieldArgsStruct> for InspectArgsStruct {
         Self {
             header: field_args.header,
             indent_children: field_args.indent_children,
+            default_open: field_args.default_open,
         }
     }
 }
@@ -85,12 +124,14 @@ impl quote::ToTokens for InspectArgsStruct {
     ) {
         let header = expand_to_tokens(&self.header);
         let indent_children = expand_to_tokens(&self.indent_children);
+        let default_open = expand_to_tokens(&self.default_open);
 
         use quote::TokenStreamExt;
         tokens.append_all(quote!(
             imgui_inspect::InspectArgsStruct {
                 header: #header,
                 indent_children: #indent_children,
+                default_open: #default_open,
             }
         ));
     }
@@ -8,13 +8,25 @@ use args::*;
 pub fn impl_inspect_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let struct_args = InspectStructArgs::from_derive_input(&input).unwrap();
-    let field_args = parse_field_args(&input);
+    let field_args = parse_field_args(&input, &struct_args);
     generate(&input, struct_args, field_args)
 }
 
 struct ParsedField {
     render: proc_macro2::TokenStream,
     render_mut: proc_macro2::TokenStream,
+    // The type of the field and the where predicates the generated code needs for it. These are only added to the
+    // impl if the field's type mentions one of the struct's type parameters
+    ty: syn::Type,
+    bounds: Vec<syn::WherePredicate>,
+    // Used to implement InspectReflect. field_info is an imgui_inspect::InspectFieldInfo literal
+    ident: syn::Ident,
+    field_info: proc_macro2::TokenStream,
+    // An expression that's true if the field matches _inspect_filter at _inspect_path. None for skipped fields
+    filter_check: Option<proc_macro2::TokenStream>,
+    // Passes the field to an InspectVisitor, and the bounds that needs. Only used with #[inspect(visitor)]
+    visit: proc_macro2::TokenStream,
+    visit_bounds: Vec<syn::WherePredicate>,
     //skip: bool
 }
 
@@ -22,32 +34,82 @@ struct ParsedField {
 fn handle_inspect_types(
     parsed_field: &mut Option<ParsedField>,
     f: &syn::Field,
+    struct_args: &InspectStructArgs,
 ) {
     // These are effectively constants
     #[allow(non_snake_case)]
     let INSPECT_DEFAULT_PATH = syn::parse2::<syn::Path>(quote!(inspect)).unwrap();
     #[allow(non_snake_case)]
     let INSPECT_SLIDER_PATH = syn::parse2::<syn::Path>(quote!(inspect_slider)).unwrap();
+    #[allow(non_snake_case)]
+    let INSPECT_STRUCT_PATH = syn::parse2::<syn::Path>(quote!(inspect_struct)).unwrap();
+    #[allow(non_snake_case)]
+    let INSPECT_PLOT_PATH = syn::parse2::<syn::Path>(quote!(inspect_plot)).unwrap();
+    #[allow(non_snake_case)]
+    let INSPECT_CURVE_PATH = syn::parse2::<syn::Path>(quote!(inspect_curve)).unwrap();
+    #[allow(non_snake_case)]
+    let INSPECT_GRADIENT_PATH = syn::parse2::<syn::Path>(quote!(inspect_gradient)).unwrap();
 
     // We must check every trait
     try_handle_inspect_type::<InspectFieldArgsSlider, InspectArgsSlider>(
         parsed_field,
         f,
+        struct_args,
         &INSPECT_SLIDER_PATH,
         quote!(imgui_inspect::InspectRenderSlider),
         quote!(imgui_inspect::InspectArgsSlider),
     );
 
+    try_handle_inspect_type::<InspectFieldArgsStruct, InspectArgsStruct>(
+        parsed_field,
+        f,
+        struct_args,
+        &INSPECT_STRUCT_PATH,
+        quote!(imgui_inspect::InspectRenderStruct),
+        quote!(imgui_inspect::InspectArgsStruct),
+    );
+
+    try_handle_inspect_type::<InspectFieldArgsPlot, InspectArgsPlot>(
+        parsed_field,
+        f,
+        struct_args,
+        &INSPECT_PLOT_PATH,
+        quote!(imgui_inspect::InspectRenderPlot),
+        quote!(imgui_inspect::InspectArgsPlot),
+    );
+
+    try_handle_inspect_type::<InspectFieldArgsCurve, InspectArgsCurve>(
+        parsed_field,
+        f,
+        struct_args,
+        &INSPECT_CURVE_PATH,
+        quote!(imgui_inspect::InspectRenderCurve),
+        quote!(imgui_inspect::InspectArgsCurve),
+    );
+
+    try_handle_inspect_type::<InspectFieldArgsGradient, InspectArgsGradient>(
+        parsed_field,
+        f,
+        struct_args,
+        &INSPECT_GRADIENT_PATH,
+        quote!(imgui_inspect::InspectRenderGradient),
+        quote!(imgui_inspect::InspectArgsGradient),
+    );
+
     try_handle_inspect_type::<InspectFieldArgsDefault, InspectArgsDefault>(
         parsed_field,
         f,
+        struct_args,
         &INSPECT_DEFAULT_PATH,
         quote!(imgui_inspect::InspectRenderDefault),
         quote!(imgui_inspect::InspectArgsDefault),
     );
 }
 
-fn parse_field_args(input: &syn::DeriveInput) -> Vec<ParsedField> {
+fn parse_field_args(
+    input: &syn::DeriveInput,
+    struct_args: &InspectStructArgs,
+) -> Vec<ParsedField> {
     match input.data {
         Data::Struct(ref data) => {
             match data.fields {
@@ -59,12 +121,13 @@ fn parse_field_args(input: &syn::DeriveInput) -> Vec<ParsedField> {
                         .map(|f| {
                             let mut parsed_field: Option<ParsedField> = None;
 
-                            handle_inspect_types(&mut parsed_field, &f);
+                            handle_inspect_types(&mut parsed_field, &f, struct_args);
 
                             if parsed_field.is_none() {
                                 handle_inspect_type::<InspectFieldArgsDefault, InspectArgsDefault>(
                                     &mut parsed_field,
                                     &f,
+                                    struct_args,
                                     quote!(imgui_inspect::InspectRenderDefault),
                                     quote!(imgui_inspect::InspectArgsDefault),
                                 );
@@ -92,12 +155,19 @@ fn try_handle_inspect_type<
 >(
     parsed_field: &mut Option<ParsedField>,
     f: &syn::Field,
+    struct_args: &InspectStructArgs,
     path: &syn::Path,
     default_render_trait: proc_macro2::TokenStream,
     arg_type: proc_macro2::TokenStream,
 ) {
     if f.attrs.iter().any(|x| x.path == *path) {
-        handle_inspect_type::<FieldArgsT, ArgsT>(parsed_field, &f, default_render_trait, arg_type);
+        handle_inspect_type::<FieldArgsT, ArgsT>(
+            parsed_field,
+            &f,
+            struct_args,
+            default_render_trait,
+            arg_type,
+        );
     }
 }
 
@@ -109,6 +179,7 @@ fn handle_inspect_type<
 >(
     parsed_field: &mut Option<ParsedField>,
     f: &syn::Field,
+    struct_args: &InspectStructArgs,
     default_render_trait: proc_macro2::TokenStream,
     arg_type: proc_macro2::TokenStream,
 ) {
@@ -126,6 +197,13 @@ fn handle_inspect_type<
         *parsed_field = Some(ParsedField {
             render: quote!(),
             render_mut: quote!(),
+            ty: field_args.ty().clone(),
+            bounds: vec![],
+            ident: f.ident.clone().unwrap(),
+            field_info: create_field_info(f, true),
+            filter_check: None,
+            visit: quote!(),
+            visit_bounds: vec![],
             //skip: true
         });
 
@@ -140,106 +218,663 @@ fn handle_inspect_type<
     let arg_type = syn::parse2::<syn::Type>(arg_type).unwrap();
     let args: ArgsT = field_args.clone().into();
 
-    let render = create_render_call(
-        field_args.ident().as_ref().unwrap(),
-        field_args.ty(),
-        &render_trait,
-        field_args.proxy_type(),
-        &arg_type,
-        &args,
+    let filter_children = create_filter_children_check(&field_args, &render_trait);
+
+    let render = create_filtered_call(
+        &field_args,
+        &filter_children,
+        create_render_call(&field_args, &render_trait, &arg_type, &args),
     );
 
-    let render_mut = create_render_mut_call(
-        field_args.ident().as_ref().unwrap(),
-        field_args.ty(),
-        field_args.on_set(),
-        &render_trait,
-        field_args.proxy_type(),
-        &arg_type,
-        &args,
+    let render_mut = create_filtered_call(
+        &field_args,
+        &filter_children,
+        create_render_mut_call(&field_args, struct_args, &render_trait, &arg_type, &args),
     );
 
+    let bounds = create_bounds(&field_args, struct_args, &render_trait);
+
+    let visit = create_visit_call(&field_args, &render_trait, &arg_type, &args);
+    let visit_bounds = create_visit_bounds(&field_args, &render_trait);
+
     *parsed_field = Some(ParsedField {
         render,
         render_mut,
+        ty: field_args.ty().clone(),
+        bounds,
+        ident: f.ident.clone().unwrap(),
+        field_info: create_field_info(f, false),
+        filter_check: Some(quote!(_inspect_filter.matches(_inspect_path) || #filter_children)),
+        visit,
+        visit_bounds,
         //skip: false
     });
 }
 
-fn create_render_call<T: ToTokens>(
-    field_name: &syn::Ident,
-    field_type: &syn::Type,
+// Returns true if the render trait is the built-in trait with the given name
+fn is_render_trait(
+    render_trait: &syn::Path,
+    name: &str,
+) -> bool {
+    render_trait
+        .segments
+        .last()
+        .map(|x| x.ident == name)
+        .unwrap_or(false)
+}
+
+// An expression that's true if anything nested inside the field matches _inspect_filter at _inspect_path. Only the
+// default trait can be asked about its children. Fields drawn with other traits or free functions are treated as
+// having no children.
+fn create_filter_children_check<FieldArgsT: InspectFieldArgs>(
+    field_args: &FieldArgsT,
+    render_trait: &syn::Path,
+) -> proc_macro2::TokenStream {
+    if field_args.with().is_some() || !is_render_trait(render_trait, "InspectRenderDefault") {
+        return quote!(false);
+    }
+
+    let field_type = field_args.ty();
+    let source_type = if let Some(w) = field_args.proxy_type() {
+        quote!(#w)
+    } else {
+        quote!(#field_type)
+    };
+
+    quote!(imgui_inspect::filter_matches_children_of::<#field_type, #source_type>(_inspect_filter, _inspect_path))
+}
+
+// Wraps a field's render call so that it's skipped if it doesn't match the active filter. Fields with
+// #[inspect(id = "...")] are drawn inside an extra ID scope, evaluated against the first selected object.
+fn create_filtered_call<FieldArgsT: InspectFieldArgs>(
+    field_args: &FieldArgsT,
+    filter_children: &proc_macro2::TokenStream,
+    render_call: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let field_name = field_args.ident().as_ref().unwrap();
+
+    let (push_field_id, pop_field_id) = match field_args.id() {
+        Some(id) => {
+            let id = id.with_self_replaced();
+            (
+                quote! {
+                    let _inspect_field_id_token = data.first().map(|_inspect_self| imgui_inspect::push_inspect_id(&(#id), ui));
+                },
+                quote! {
+                    if let Some(id_token) = _inspect_field_id_token {
+                        id_token.pop(ui);
+                    }
+                },
+            )
+        }
+        None => (quote!(), quote!()),
+    };
+
+    quote! {
+        if let Some(_inspect_filter_token) = imgui_inspect::begin_filtered_field(
+            stringify!(#field_name),
+            |_inspect_filter, _inspect_path| #filter_children,
+            ui
+        ) {
+            #push_field_id
+            imgui_inspect::check_inspect_id(stringify!(#field_name), ui);
+            #render_call
+            #pop_field_id
+            _inspect_filter_token.end(ui);
+        }
+    }
+}
+
+// Emits the imgui_inspect::InspectFieldInfo for a field. The attribute args are recorded as written rather than as
+// parsed so that every kind of attribute can be described the same way.
+fn create_field_info(
+    f: &syn::Field,
+    skip: bool,
+) -> proc_macro2::TokenStream {
+    let name = f.ident.as_ref().unwrap().to_string();
+    let type_name = type_to_string(&f.ty);
+
+    let mut attribute = "inspect".to_string();
+    let mut args = vec![];
+    for attr in &f.attrs {
+        let is_inspect_attr = [
+            "inspect",
+            "inspect_slider",
+            "inspect_struct",
+            "inspect_plot",
+            "inspect_curve",
+            "inspect_gradient",
+        ]
+        .iter()
+        .any(|x| attr.path.is_ident(x));
+        if !is_inspect_attr {
+            continue;
+        }
+
+        attribute = attr.path.to_token_stream().to_string();
+        if let Ok(syn::Meta::List(list)) = attr.parse_meta() {
+            for nested in list.nested {
+                match nested {
+                    syn::NestedMeta::Meta(syn::Meta::NameValue(nv)) => {
+                        let value = match &nv.lit {
+                            syn::Lit::Str(s) => s.value(),
+                            lit => lit.to_token_stream().to_string(),
+                        };
+                        args.push((nv.path.to_token_stream().to_string(), value));
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) => {
+                        args.push((path.to_token_stream().to_string(), "true".to_string()));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    let arg_names = args.iter().map(|(k, _)| k);
+    let arg_values = args.iter().map(|(_, v)| v);
+
+    quote! {
+        imgui_inspect::InspectFieldInfo {
+            name: #name,
+            type_name: #type_name,
+            attribute: #attribute,
+            args: &[#((#arg_names, #arg_values)),*],
+            skip: #skip,
+        }
+    }
+}
+
+// Formats a type roughly the way it would be written by hand. to_string() on tokens puts spaces between everything
+// (i.e. "Vec < f32 >")
+fn type_to_string(ty: &syn::Type) -> String {
+    let mut s = ty.to_token_stream().to_string();
+    for (from, to) in &[
+        (" <", "<"),
+        ("< ", "<"),
+        (" >", ">"),
+        (" ,", ","),
+        (" :: ", "::"),
+        (":: ", "::"),
+        ("& ", "&"),
+        ("[ ", "["),
+        (" ]", "]"),
+        (" ;", ";"),
+        ("( ", "("),
+        (" )", ")"),
+    ] {
+        s = s.replace(from, to);
+    }
+    s
+}
+
+// The bounds that the generated code relies on for a single field. For a field `points: Vec<T>` this would be
+// `Vec<T>: imgui_inspect::InspectRenderDefault<Vec<T>>`
+fn create_bounds<FieldArgsT: InspectFieldArgs>(
+    field_args: &FieldArgsT,
+    struct_args: &InspectStructArgs,
+    render_trait: &syn::Path,
+) -> Vec<syn::WherePredicate> {
+    let field_type = field_args.ty();
+    let source_type = if let Some(w) = field_args.proxy_type() {
+        quote!(#w)
+    } else {
+        quote!(#field_type)
+    };
+
+    let mut bounds = vec![];
+
+    // Free functions provided via #[inspect(with = "...")] don't go through a trait
+    if field_args.with().is_none() {
+        bounds.push(syn::parse_quote!(#source_type: #render_trait<#field_type>));
+    }
+
+    if field_args.on_set_old_value() {
+        bounds.push(syn::parse_quote!(#field_type: Clone));
+    }
+
+    if has_default(field_args, struct_args) {
+        bounds.push(syn::parse_quote!(#field_type: PartialEq + Clone));
+    }
+
+    bounds
+}
+
+// Fields have a default if they specify one, or if the struct uses its Default impl for all fields
+fn has_default<FieldArgsT: InspectFieldArgs>(
+    field_args: &FieldArgsT,
+    struct_args: &InspectStructArgs,
+) -> bool {
+    field_args.default_value().is_some() || struct_args.default
+}
+
+// Returns true if any of the given type parameters appear anywhere in the type
+fn type_uses_params(
+    ty: &syn::Type,
+    type_params: &[&syn::Ident],
+) -> bool {
+    fn tokens_use_params(
+        tokens: proc_macro2::TokenStream,
+        type_params: &[&syn::Ident],
+    ) -> bool {
+        tokens.into_iter().any(|token| match token {
+            proc_macro2::TokenTree::Ident(ident) => type_params.iter().any(|p| **p == ident),
+            proc_macro2::TokenTree::Group(group) => tokens_use_params(group.stream(), type_params),
+            _ => false,
+        })
+    }
+
+    tokens_use_params(ty.to_token_stream(), type_params)
+}
+
+// Returns the path that render/render_mut are called on. This is either a module given by #[inspect(with = "...")]
+// or the render trait implemented by the field's type (or its proxy_type)
+fn create_render_impl_path<FieldArgsT: InspectFieldArgs>(
+    field_args: &FieldArgsT,
+    render_trait: &syn::Path,
+) -> proc_macro2::TokenStream {
+    if let Some(with) = field_args.with() {
+        return quote!(#with);
+    }
+
+    let field_type = field_args.ty();
+    let source_type = if let Some(w) = field_args.proxy_type() {
+        quote!(#w)
+    } else {
+        quote!(#field_type)
+    };
+
+    quote!(<#source_type as #render_trait<#field_type>>)
+}
+
+// Emits the args for a field. If every value is a literal, the args are a const like they've always been. Otherwise
+// they're evaluated every frame, and if any of them refer to `self`, against the first selected object.
+fn create_args_binding<FieldArgsT: InspectFieldArgs, T: ToTokens>(
+    field_args: &FieldArgsT,
+    args_name: &syn::Ident,
+    arg_type: &syn::Type,
+    args: &T,
+) -> proc_macro2::TokenStream {
+    let arg_values = field_args.arg_values();
+    if arg_values.iter().all(|x| x.is_literal()) {
+        quote! {
+            #[allow(non_upper_case_globals)]
+            const #args_name : #arg_type = #args;
+        }
+    } else if arg_values.iter().any(|x| x.uses_self()) {
+        quote! {
+            let #args_name : #arg_type = match data.first() {
+                Some(_inspect_self) => #args,
+                None => Default::default(),
+            };
+        }
+    } else {
+        quote! {
+            let #args_name : #arg_type = #args;
+        }
+    }
+}
+
+fn create_render_call<FieldArgsT: InspectFieldArgs, T: ToTokens>(
+    field_args: &FieldArgsT,
     render_trait: &syn::Path,
-    proxy_type: &Option<syn::Path>,
     arg_type: &syn::Type,
     args: &T,
 ) -> proc_macro2::TokenStream {
+    let field_name = field_args.ident().as_ref().unwrap();
+
     use quote::format_ident;
     let args_name1 = format_ident!("_inspect_args_{}", field_name);
     let args_name2 = args_name1.clone();
+    let args_binding = create_args_binding(field_args, &args_name1, arg_type, args);
 
     let field_name1 = field_name.clone();
     let field_name2 = field_name.clone();
 
-    let source_type = if let Some(w) = proxy_type {
-        quote!(#w)
-    } else {
-        quote!(#field_type)
-    };
+    let render_impl = create_render_impl_path(field_args, render_trait);
 
+    let field_type = field_args.ty();
+    let clipboard_ops = create_clipboard_ops(&quote!(#field_type));
+
+    // A single selected object is by far the most common case, so the field's references are collected into an array
+    // on the stack rather than a Vec
     quote! {{
-        #[allow(non_upper_case_globals)]
-        const #args_name1 : #arg_type = #args;
-        let values : Vec<_> = data.iter().map(|x| &x.#field_name1).collect();
+        #args_binding
+        let _inspect_single;
+        let _inspect_multiple : Vec<_>;
+        let values : &[&#field_type] = if data.len() == 1 {
+            _inspect_single = [&data[0].#field_name1];
+            &_inspect_single
+        } else {
+            _inspect_multiple = data.iter().map(|x| &x.#field_name1).collect();
+            &_inspect_multiple
+        };
+
         if data.len() != 0 {
-            <#source_type as #render_trait<#field_type>>::render(values.as_slice(), stringify!(#field_name2), ui, &#args_name2);
+            let _inspect_menu_scope = imgui_inspect::begin_field_context_menu();
+            #render_impl::render(values, stringify!(#field_name2), ui, &#args_name2);
+            let _inspect_menu_clicked = imgui_inspect::end_field_context_menu(_inspect_menu_scope, ui);
+
+            let _inspect_clipboard = #clipboard_ops;
+            imgui_inspect::render_field_context_menu(stringify!(#field_name2), _inspect_menu_clicked, values.first().copied(), _inspect_clipboard.as_ref(), None, true, ui);
         }
     }}
 }
 
-fn create_render_mut_call<T: ToTokens>(
-    field_name: &syn::Ident,
-    field_type: &syn::Type,
-    on_set: &Option<syn::Ident>,
+fn create_render_mut_call<FieldArgsT: InspectFieldArgs, T: ToTokens>(
+    field_args: &FieldArgsT,
+    struct_args: &InspectStructArgs,
     render_trait: &syn::Path,
-    proxy_type: &Option<syn::Path>,
     arg_type: &syn::Type,
     args: &T,
 ) -> proc_macro2::TokenStream {
+    let field_name = field_args.ident().as_ref().unwrap();
+    let field_type = field_args.ty();
+
     use quote::format_ident;
     let args_name1 = format_ident!("_inspect_args_{}", field_name);
     let args_name2 = args_name1.clone();
+    let args_binding = create_args_binding(field_args, &args_name1, arg_type, args);
 
     let field_name1 = field_name.clone();
     let field_name2 = field_name.clone();
 
-    let source_type = if let Some(w) = proxy_type {
+    let render_impl = create_render_impl_path(field_args, render_trait);
+
+    let OnSetCall {
+        capture_old_values,
+        capture_old_values_for_menu,
+        on_set_callback_impl,
+    } = create_on_set_call(field_args, true);
+
+    // Fields with a default are highlighted when any selected value differs from it, and get a context menu item to
+    // reset them. Resetting and pasting go through the normal changed path so on_set callbacks still fire.
+    let mut begin_default = quote!();
+    let mut end_default = quote!();
+    let mut is_modified = quote!(None);
+    let mut reset_default = quote!();
+    if has_default(field_args, struct_args) {
+        let default_value = match field_args.default_value() {
+            Some(default_value) => quote!(&(#default_value)),
+            None => quote!(&_inspect_struct_default.#field_name1),
+        };
+
+        begin_default = quote! {
+            let _inspect_default_value : &#field_type = #default_value;
+            let _inspect_is_modified = data.iter().any(|x| x.#field_name1 != *_inspect_default_value);
+            let _inspect_modified_style_token = imgui_inspect::push_modified_style(_inspect_is_modified, ui);
+        };
+
+        end_default = quote! {
+            if let Some(style_token) = _inspect_modified_style_token {
+                style_token.pop(ui);
+            }
+        };
+
+        is_modified = quote!(Some(_inspect_is_modified));
+
+        reset_default = quote! {
+            if _inspect_menu_action.reset {
+                for d in data.iter_mut() {
+                    d.#field_name1 = _inspect_default_value.clone();
+                }
+                changed = true;
+            }
+        };
+    }
+
+    let clipboard_ops = create_clipboard_ops(&quote!(#field_type));
+
+    quote! {{
+        #args_binding
+        #capture_old_values
+        #begin_default
+        let mut _inspect_single;
+        let mut _inspect_multiple : Vec<_>;
+        let values : &mut [&mut #field_type] = if data.len() == 1 {
+            _inspect_single = [&mut data[0].#field_name1];
+            &mut _inspect_single
+        } else {
+            _inspect_multiple = data.iter_mut().map(|x| &mut x.#field_name1).collect();
+            &mut _inspect_multiple
+        };
+        let _inspect_menu_scope = imgui_inspect::begin_field_context_menu();
+        let mut changed = #render_impl::render_mut(values, stringify!(#field_name2), ui, &#args_name2);
+        let _inspect_menu_clicked = imgui_inspect::end_field_context_menu(_inspect_menu_scope, ui);
+        #end_default
+
+        let _inspect_clipboard = #clipboard_ops;
+        let _inspect_menu_action = imgui_inspect::render_field_context_menu(
+            stringify!(#field_name2),
+            _inspect_menu_clicked,
+            data.first().map(|x| &x.#field_name1),
+            _inspect_clipboard.as_ref(),
+            #is_modified,
+            false,
+            ui
+        );
+
+        #capture_old_values_for_menu
+        #reset_default
+
+        if let (Some(text), Some(clipboard)) = (&_inspect_menu_action.paste, &_inspect_clipboard) {
+            for d in data.iter_mut() {
+                if let Ok(value) = (clipboard.from_text)(text) {
+                    d.#field_name1 = value;
+                    changed = true;
+                }
+            }
+        }
+
+        #on_set_callback_impl
+
+        _has_any_field_changed |= changed;
+    }}
+}
+
+struct OnSetCall {
+    capture_old_values: proc_macro2::TokenStream,
+    capture_old_values_for_menu: proc_macro2::TokenStream,
+    on_set_callback_impl: proc_macro2::TokenStream,
+}
+
+// Emits the code that runs a field's on_set callback for each selected object if `changed` is true. If the callback
+// wants the old value, the first block has to run before the field is written to.
+//
+// Cloning the old values every frame would be expensive for large fields, so when drawing with imgui they're only
+// captured on frames where input could edit the field (see imgui_inspect::is_edit_possible). Visitors don't have a way
+// to tell, so they always capture them.
+fn create_on_set_call<FieldArgsT: InspectFieldArgs>(
+    field_args: &FieldArgsT,
+    has_ui: bool,
+) -> OnSetCall {
+    let field_name = field_args.ident().as_ref().unwrap();
+    let field_type = field_args.ty();
+
+    let mut capture_old_values = quote!();
+    let mut capture_old_values_for_menu = quote!();
+    let mut take_old_values = quote!();
+    let mut on_set_args = vec![];
+    if field_args.on_set_old_value() {
+        let clone_old_values =
+            quote!(data.iter().map(|x| x.#field_name.clone()).collect::<Vec<#field_type>>());
+        capture_old_values = if has_ui {
+            quote! {
+                let mut _inspect_old_values : Option<Vec<#field_type>> = if imgui_inspect::is_edit_possible(ui) {
+                    Some(#clone_old_values)
+                } else {
+                    None
+                };
+            }
+        } else {
+            quote! {
+                let mut _inspect_old_values : Option<Vec<#field_type>> = Some(#clone_old_values);
+            }
+        };
+
+        // Resetting or pasting from the context menu is input too, but it's handled after the widget is drawn
+        capture_old_values_for_menu = quote! {
+            if _inspect_old_values.is_none() && (_inspect_menu_action.reset || _inspect_menu_action.paste.is_some()) {
+                _inspect_old_values = Some(#clone_old_values);
+            }
+        };
+
+        // This only falls back to the current values if a widget reported a change on a frame without any input
+        take_old_values = quote! {
+            let _inspect_old_values = _inspect_old_values.take().unwrap_or_else(|| #clone_old_values);
+        };
+        on_set_args.push(quote!(&_inspect_old_values[_inspect_index]));
+    }
+
+    if field_args.on_set_field_name() {
+        on_set_args.push(quote!(stringify!(#field_name)));
+    }
+
+    let on_set_callback_impl = match field_args.on_set() {
+        Some(OnSetCallback::Method(ident)) => quote! {
+            if changed {
+                #take_old_values
+                for (_inspect_index, d) in data.iter_mut().enumerate() {
+                    d.#ident(#(#on_set_args),*);
+                }
+            }
+        },
+        Some(OnSetCallback::Function(callback)) => quote! {
+            if changed {
+                #take_old_values
+                for (_inspect_index, d) in data.iter_mut().enumerate() {
+                    (#callback)(&mut **d, #(#on_set_args),*);
+                }
+            }
+        },
+        None => quote! {},
+    };
+
+    OnSetCall {
+        capture_old_values,
+        capture_old_values_for_menu,
+        on_set_callback_impl,
+    }
+}
+
+// Emits the code that passes a field to the InspectVisitor `visitor`. Fields drawn with InspectRenderDefault or
+// InspectRenderStruct are visited with InspectVisit (or visit_unsupported if their type doesn't implement it), slider
+// fields with InspectVisitSlider, and fields using `with` call a `visit` function in the same module as their render
+// functions. Fields drawn with any other render trait are unsupported.
+fn create_visit_call<FieldArgsT: InspectFieldArgs, T: ToTokens>(
+    field_args: &FieldArgsT,
+    render_trait: &syn::Path,
+    arg_type: &syn::Type,
+    args: &T,
+) -> proc_macro2::TokenStream {
+    let field_name = field_args.ident().as_ref().unwrap();
+    let field_type = field_args.ty();
+    let source_type = if let Some(w) = field_args.proxy_type() {
         quote!(#w)
     } else {
         quote!(#field_type)
     };
 
-    let on_set_callback_impl = match on_set {
-        Some(ident) => quote! {{
-            for d in data.iter_mut() {
-                d.#ident();
-            }
-        }},
-        None => quote! {{}},
+    use quote::format_ident;
+    let args_name = format_ident!("_inspect_args_{}", field_name);
+    let args_binding = create_args_binding(field_args, &args_name, arg_type, args);
+
+    let visit_call = if let Some(with) = field_args.with() {
+        quote!(#with::visit(&mut _inspect_values, stringify!(#field_name), visitor, &#args_name))
+    } else if is_render_trait(render_trait, "InspectRenderDefault")
+        || is_render_trait(render_trait, "InspectRenderStruct")
+    {
+        // InspectVisit takes the default args, which include everything in the struct args
+        let visit_args = if is_render_trait(render_trait, "InspectRenderStruct") {
+            quote!(imgui_inspect::InspectArgsDefault::from(#args_name))
+        } else {
+            quote!(#args_name)
+        };
+
+        quote! {{
+            #[allow(unused_imports)]
+            use imgui_inspect::{InspectVisitSupported as _, InspectVisitUnsupported as _};
+            (&&imgui_inspect::InspectVisitTag::<#source_type, #field_type>::new()).visit_field(&mut _inspect_values, stringify!(#field_name), visitor, &#visit_args)
+        }}
+    } else if is_render_trait(render_trait, "InspectRenderSlider") {
+        quote!(<#source_type as imgui_inspect::InspectVisitSlider<#field_type>>::visit_slider(&mut _inspect_values, stringify!(#field_name), visitor, &#args_name))
+    } else {
+        return quote! {
+            _has_any_field_changed |= visitor.visit_unsupported(stringify!(#field_name), std::any::type_name::<#field_type>());
+        };
     };
 
-    quote! {{
-        #[allow(non_upper_case_globals)]
-        const #args_name1 : #arg_type = #args;
-        let mut values : Vec<_> = data.iter_mut().map(|x| &mut x.#field_name1).collect();
-        let mut changed = <#source_type as #render_trait<#field_type>>::render_mut(&mut values.as_mut_slice(), stringify!(#field_name2), ui, &#args_name2);
+    let OnSetCall {
+        capture_old_values,
+        on_set_callback_impl,
+        ..
+    } = create_on_set_call(field_args, false);
 
+    quote! {{
+        #args_binding
+        #capture_old_values
+        let mut _inspect_values : Vec<&mut #field_type> = data.iter_mut().map(|x| &mut x.#field_name).collect();
+        let changed = #visit_call;
         #on_set_callback_impl
-
         _has_any_field_changed |= changed;
     }}
 }
 
+// The bounds that visiting a single field relies on. Fields whose type doesn't implement InspectVisit fall back to
+// visit_unsupported, but that can only be decided for generic fields if the bound is in the where clause.
+fn create_visit_bounds<FieldArgsT: InspectFieldArgs>(
+    field_args: &FieldArgsT,
+    render_trait: &syn::Path,
+) -> Vec<syn::WherePredicate> {
+    let field_type = field_args.ty();
+    let source_type = if let Some(w) = field_args.proxy_type() {
+        quote!(#w)
+    } else {
+        quote!(#field_type)
+    };
+
+    let mut bounds = vec![];
+    if field_args.with().is_none() {
+        if is_render_trait(render_trait, "InspectRenderDefault")
+            || is_render_trait(render_trait, "InspectRenderStruct")
+        {
+            bounds.push(syn::parse_quote!(#source_type: imgui_inspect::InspectVisit<#field_type>));
+        } else if is_render_trait(render_trait, "InspectRenderSlider") {
+            bounds.push(
+                syn::parse_quote!(#source_type: imgui_inspect::InspectVisitSlider<#field_type>),
+            );
+        }
+    }
+
+    if field_args.on_set_old_value() {
+        bounds.push(syn::parse_quote!(#field_type: Clone));
+    }
+
+    bounds
+}
+
+// Emits an expression for the Option<imgui_inspect::InspectClipboardOps<T>> of a type. See imgui_inspect's
+// clipboard module for how the impl is chosen.
+fn create_clipboard_ops(ty: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    quote! {{
+        #[allow(unused_imports)]
+        use imgui_inspect::{InspectClipboardNone as _, InspectClipboardSerde as _, InspectClipboardText as _};
+        (&&&imgui_inspect::InspectClipboardTag::<#ty>::new()).clipboard_ops()
+    }}
+}
+
+// Parses the value of #[inspect(bound = "...")] into a list of where predicates. An empty string is allowed and
+// means no bounds at all.
+fn parse_bound(bound: &str) -> Vec<syn::WherePredicate> {
+    if bound.trim().is_empty() {
+        return vec![];
+    }
+
+    let where_clause = syn::parse_str::<syn::WhereClause>(&format!("where {}", bound))
+        .unwrap_or_else(|e| panic!("Could not parse #[inspect(bound = {:?})]: {}", bound, e));
+    where_clause.predicates.into_iter().collect()
+}
+
 // Provide a way to early out and generate no code. It's going to be a common case for
 // downstream users to want to only conditionally generate code, and it's easier to do this
 // by adding an early-out here that can be configured via a cargo feature, than having to
@@ -259,41 +894,185 @@ fn generate(
     struct_args: InspectStructArgs,
     parsed_fields: Vec<ParsedField>,
 ) -> proc_macro::TokenStream {
-    let struct_name1 = &struct_args.ident;
-    let struct_name2 = &struct_args.ident;
-    let struct_name3 = &struct_args.ident;
-    let struct_name4 = &struct_args.ident;
-    let struct_name5 = &struct_args.ident;
-    let struct_name6 = &struct_args.ident;
+    let struct_name = &struct_args.ident;
+
+    let type_params: Vec<_> = input.generics.type_params().map(|x| &x.ident).collect();
 
     let mut render_impls = vec![];
     let mut render_mut_impls = vec![];
+    let mut inferred_bounds = vec![];
+
+    let mut filter_checks = vec![];
+    let mut reflect_fields = vec![];
+    let mut reflect_bounds: Vec<syn::WherePredicate> = vec![];
+
+    let mut visit_impls = vec![];
+    let mut visit_bounds: Vec<syn::WherePredicate> = vec![];
 
     for parsed_field in parsed_fields {
         render_impls.push(parsed_field.render);
         render_mut_impls.push(parsed_field.render_mut);
+
+        visit_impls.push(parsed_field.visit);
+        if type_uses_params(&parsed_field.ty, &type_params) {
+            visit_bounds.extend(parsed_field.visit_bounds);
+        }
+
+        if let Some(filter_check) = &parsed_field.filter_check {
+            let field_name = parsed_field.ident.to_string();
+            filter_checks.push(quote! {{
+                let _inspect_path = &format!("{}.{}", _inspect_parent_path, #field_name);
+                #filter_check
+            }});
+        }
+
+        let field_type = &parsed_field.ty;
+        if type_uses_params(field_type, &type_params) {
+            reflect_bounds.push(syn::parse_quote!(#field_type: 'static));
+        }
+        reflect_fields.push((
+            parsed_field.ident.clone(),
+            parsed_field.ty.clone(),
+            parsed_field.field_info.clone(),
+        ));
+
+        // Only fields that depend on a type parameter need bounds. Adding bounds for concrete types is harmless but
+        // produces confusing errors if the type doesn't implement the trait.
+        if type_uses_params(&parsed_field.ty, &type_params) {
+            inferred_bounds.extend(parsed_field.bounds);
+        }
+    }
+
+    // The type being inspected. Normally this is the struct itself, but for #[inspect(remote = "...")] the struct is
+    // only a proxy for the remote type.
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let (target_type, header_name) = match &struct_args.remote {
+        Some(remote) => (
+            quote!(#remote),
+            remote.segments.last().unwrap().ident.clone(),
+        ),
+        None => (quote!(#struct_name #ty_generics), struct_name.clone()),
+    };
+
+    // The header is a literal so that im_str! doesn't allocate every frame
+    let header_name = syn::LitStr::new(&header_name.to_string(), header_name.span());
+
+    // #[inspect(default)] constructs the struct's default once per frame so each field can compare against it
+    let mut struct_default = quote!();
+    if struct_args.default {
+        struct_default = quote! {
+            let _inspect_struct_default : #target_type = Default::default();
+        };
+
+        if !type_params.is_empty() {
+            inferred_bounds.push(syn::parse_quote!(#target_type: Default));
+        }
     }
 
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    // Like serde, #[inspect(bound = "...")] replaces the inferred bounds entirely
+    let bounds = match &struct_args.bound {
+        Some(bound) => parse_bound(bound),
+        None => inferred_bounds,
+    };
+
+    let reflect_impl = create_reflect_impl(input, &struct_args, reflect_fields, reflect_bounds);
+
+    let visit_impl = if struct_args.visitor {
+        let visit_bounds = match &struct_args.bound {
+            Some(bound) => parse_bound(bound),
+            None => visit_bounds,
+        };
+
+        let mut generics = input.generics.clone();
+        generics.make_where_clause().predicates.extend(visit_bounds);
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        quote! {
+            impl #impl_generics imgui_inspect::InspectVisit<#target_type> for #struct_name #ty_generics #where_clause {
+                fn visit(data: &mut [&mut #target_type], label: &'static str, visitor: &mut dyn imgui_inspect::InspectVisitor, args: &imgui_inspect::InspectArgsDefault) -> bool {
+                    let struct_args = imgui_inspect::InspectArgsStruct { header: args.header, indent_children: args.indent_children, default_open: args.default_open };
+                    visitor.visit_struct(label, #header_name, &struct_args, &mut |visitor| {
+                        let mut _has_any_field_changed = false;
+                        #(
+                            #visit_impls
+                        )*
+                        _has_any_field_changed
+                    })
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    let mut generics = input.generics.clone();
+    generics.make_where_clause().predicates.extend(bounds);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     let default_impl = quote! {
 
-        impl #impl_generics imgui_inspect::InspectRenderDefault<#struct_name1> for #struct_name2 #ty_generics #where_clause {
-            fn render(data: &[&Self], label: &'static str, ui: &imgui::Ui, args: &imgui_inspect::InspectArgsDefault) {
-                <#struct_name3 as imgui_inspect::InspectRenderStruct<#struct_name4>>::render(data, label, ui, &imgui_inspect::InspectArgsStruct { header: args.header, indent_children: args.indent_children })
+        impl #impl_generics imgui_inspect::InspectRenderDefault<#target_type> for #struct_name #ty_generics #where_clause {
+            fn render(data: &[&#target_type], label: &'static str, ui: &imgui::Ui, args: &imgui_inspect::InspectArgsDefault) {
+                <Self as imgui_inspect::InspectRenderStruct<#target_type>>::render(data, label, ui, &imgui_inspect::InspectArgsStruct { header: args.header, indent_children: args.indent_children, default_open: args.default_open })
             }
 
-            fn render_mut(data: &mut [&mut Self], label: &'static str, ui: &imgui::Ui, args: &imgui_inspect::InspectArgsDefault) -> bool {
-                <#struct_name5 as imgui_inspect::InspectRenderStruct<#struct_name6>>::render_mut(data, label, ui, &imgui_inspect::InspectArgsStruct { header: args.header, indent_children: args.indent_children })
+            fn render_mut(data: &mut [&mut #target_type], label: &'static str, ui: &imgui::Ui, args: &imgui_inspect::InspectArgsDefault) -> bool {
+                <Self as imgui_inspect::InspectRenderStruct<#target_type>>::render_mut(data, label, ui, &imgui_inspect::InspectArgsStruct { header: args.header, indent_children: args.indent_children, default_open: args.default_open })
+            }
+
+            fn filter_matches_children(_inspect_filter: &imgui_inspect::InspectFilter, _inspect_parent_path: &str) -> bool {
+                #(
+                    if #filter_checks {
+                        return true;
+                    }
+                )*
+                false
             }
         }
     };
 
-    let struct_impl = quote! {
-        impl #impl_generics imgui_inspect::InspectRenderStruct<#struct_name1> for #struct_name2 #ty_generics #where_clause {
-            fn render(data: &[&Self], label: &'static str, ui: &imgui::Ui, args: &imgui_inspect::InspectArgsStruct) {
-                let header_name = stringify!(#struct_name3);
+    let struct_clipboard_ops = create_clipboard_ops(&target_type);
 
+    // Everything the struct draws is scoped by its label, and by its per-object ID if it has one. The header is
+    // included so that two fields of the same struct type don't share a header. The label is also pushed onto the
+    // layout path, which identifies the header in an InspectLayoutState.
+    let (push_object_id, pop_object_id) = match &struct_args.id {
+        Some(id) => {
+            let id = id.with_self_replaced();
+            (
+                quote! {
+                    let _inspect_object_id_token = data.first().map(|_inspect_self| imgui_inspect::push_inspect_id(&(#id), ui));
+                },
+                quote! {
+                    if let Some(id_token) = _inspect_object_id_token {
+                        id_token.pop(ui);
+                    }
+                },
+            )
+        }
+        None => (quote!(), quote!()),
+    };
+    // Objects with their own ID can share a label, so the label is only checked if there isn't one
+    let check_label = if struct_args.id.is_none() {
+        quote!(imgui_inspect::check_inspect_id(label, ui);)
+    } else {
+        quote!()
+    };
+    let push_struct_id = quote! {
+        #check_label
+        let _inspect_id_token = ui.push_id(label);
+        #push_object_id
+        let _inspect_layout_token = imgui_inspect::push_layout_path(label);
+    };
+    let pop_struct_id = quote! {
+        _inspect_layout_token.end();
+        #pop_object_id
+        _inspect_id_token.pop(ui);
+    };
+
+    let struct_impl = quote! {
+        impl #impl_generics imgui_inspect::InspectRenderStruct<#target_type> for #struct_name #ty_generics #where_clause {
+            fn render(data: &[&#target_type], label: &'static str, ui: &imgui::Ui, args: &imgui_inspect::InspectArgsStruct) {
                 let mut header = true;
                 if let Some(h) = args.header {
                     header = h;
@@ -301,29 +1080,45 @@ fn generate(
 
                 let mut indent_children = true;
                 if let Some(ic) = args.indent_children {
-                    header = ic;
+                    indent_children = ic;
                 }
 
+                #push_struct_id
+
+                let mut _inspect_header_claimed = false;
                 let should_render_children = if header {
-                    imgui::CollapsingHeader::new(&imgui::im_str!( "{}", header_name)).default_open(true).build(&ui)
+                    imgui_inspect::layout_restore_next_header(ui);
+                    imgui_inspect::filter_expand_next_header(ui);
+                    let is_open = imgui::CollapsingHeader::new(imgui::im_str!(#header_name)).default_open(args.default_open.unwrap_or(true)).build(&ui);
+                    _inspect_header_claimed = imgui_inspect::claim_field_context_menu(ui);
+                    imgui_inspect::layout_record_header(is_open);
+                    is_open
                 } else {
                     true
                 };
 
+                // If this struct is drawn as a field, the field's menu is attached to the header. Otherwise the header
+                // gets a menu of its own. The ID scope keeps its popup separate from the menus of the struct's fields.
+                if header && !_inspect_header_claimed {
+                    let _inspect_clipboard = #struct_clipboard_ops;
+                    let _inspect_menu_clicked = ui.is_item_clicked(imgui::MouseButton::Right);
+                    let id_token = ui.push_id("inspect_header");
+                    imgui_inspect::render_field_context_menu(label, _inspect_menu_clicked, data.first().copied(), _inspect_clipboard.as_ref(), None, true, ui);
+                    id_token.pop(ui);
+                }
+
                 if should_render_children {
-                    let id_token = ui.push_id(label);
                     if indent_children { ui.indent(); }
                     #(
                         #render_impls
                     )*
                     if indent_children { ui.unindent(); }
-                    id_token.pop(ui);
                 }
-            }
 
-            fn render_mut(data: &mut [&mut Self], label: &'static str, ui: &imgui::Ui, args: &imgui_inspect::InspectArgsStruct) -> bool {
-                let header_name = stringify!(#struct_name4);
+                #pop_struct_id
+            }
 
+            fn render_mut(data: &mut [&mut #target_type], label: &'static str, ui: &imgui::Ui, args: &imgui_inspect::InspectArgsStruct) -> bool {
                 let mut header = true;
                 if let Some(h) = args.header {
                     header = h;
@@ -334,22 +1129,58 @@ fn generate(
                     indent_children = ic;
                 }
 
+                #push_struct_id
+
+                let mut _inspect_header_claimed = false;
                 let should_render_children = if header {
-                    imgui::CollapsingHeader::new(&imgui::im_str!("{}", header_name)).default_open(true).build(&ui)
+                    imgui_inspect::layout_restore_next_header(ui);
+                    imgui_inspect::filter_expand_next_header(ui);
+                    let is_open = imgui::CollapsingHeader::new(imgui::im_str!(#header_name)).default_open(args.default_open.unwrap_or(true)).build(&ui);
+                    _inspect_header_claimed = imgui_inspect::claim_field_context_menu(ui);
+                    imgui_inspect::layout_record_header(is_open);
+                    is_open
                 } else {
                     true
                 };
 
                 let mut _has_any_field_changed = false;
+
+                // The whole struct can be copied or pasted from the header's context menu. If this struct is drawn as
+                // a field, the field's menu (which can also reset it) is attached to the header instead.
+                if header && !_inspect_header_claimed {
+                    let _inspect_clipboard = #struct_clipboard_ops;
+                    let _inspect_menu_clicked = ui.is_item_clicked(imgui::MouseButton::Right);
+                    let id_token = ui.push_id("inspect_header");
+                    let _inspect_menu_action = imgui_inspect::render_field_context_menu(
+                        label,
+                        _inspect_menu_clicked,
+                        data.first().map(|x| &**x),
+                        _inspect_clipboard.as_ref(),
+                        None,
+                        false,
+                        ui
+                    );
+                    id_token.pop(ui);
+
+                    if let (Some(text), Some(clipboard)) = (&_inspect_menu_action.paste, &_inspect_clipboard) {
+                        for d in data.iter_mut() {
+                            if let Ok(value) = (clipboard.from_text)(text) {
+                                **d = value;
+                                _has_any_field_changed = true;
+                            }
+                        }
+                    }
+                }
                 if should_render_children {
-                    let id_token = ui.push_id(label);
                     if indent_children { ui.indent(); }
+                    #struct_default
                     #(
                         #render_mut_impls
                     )*
                     if indent_children { ui.unindent(); }
-                    id_token.pop(ui);
                 }
+
+                #pop_struct_id
                 _has_any_field_changed
             }
         }
@@ -358,5 +1189,80 @@ fn generate(
     proc_macro::TokenStream::from(quote! {
         #default_impl
         #struct_impl
+        #reflect_impl
+        #visit_impl
     })
 }
+
+// Emits InspectReflect for the struct. This is skipped for remote proxies (the trait would have to be implemented on
+// the remote type, which the orphan rule doesn't allow) and for structs with lifetimes (fields must be 'static to be
+// used as dyn Any). The bounds are independent of #[inspect(bound = "...")] since they only need to cover Any.
+#[cfg(feature = "generate_code")]
+fn create_reflect_impl(
+    input: &syn::DeriveInput,
+    struct_args: &InspectStructArgs,
+    reflect_fields: Vec<(syn::Ident, syn::Type, proc_macro2::TokenStream)>,
+    reflect_bounds: Vec<syn::WherePredicate>,
+) -> proc_macro2::TokenStream {
+    if struct_args.remote.is_some() || input.generics.lifetimes().next().is_some() {
+        return quote!();
+    }
+
+    let struct_name = &struct_args.ident;
+
+    let mut generics = input.generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .extend(reflect_bounds);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let field_infos = reflect_fields.iter().map(|(_, _, info)| info);
+    let names: Vec<_> = reflect_fields
+        .iter()
+        .map(|(ident, _, _)| ident.to_string())
+        .collect();
+    let idents: Vec<_> = reflect_fields.iter().map(|(ident, _, _)| ident).collect();
+    let types = reflect_fields.iter().map(|(_, ty, _)| ty);
+
+    let names1 = names.iter();
+    let names2 = names.iter();
+    let names3 = names.iter();
+    let idents1 = idents.iter();
+    let idents2 = idents.iter();
+    let idents3 = idents.iter();
+
+    quote! {
+        impl #impl_generics imgui_inspect::InspectReflect for #struct_name #ty_generics #where_clause {
+            fn inspect_fields(&self) -> &'static [imgui_inspect::InspectFieldInfo] {
+                const FIELDS: &[imgui_inspect::InspectFieldInfo] = &[#(#field_infos),*];
+                FIELDS
+            }
+
+            fn get_field(&self, name: &str) -> Option<&dyn std::any::Any> {
+                match name {
+                    #(#names1 => Some(&self.#idents1),)*
+                    _ => None,
+                }
+            }
+
+            fn get_field_mut(&mut self, name: &str) -> Option<&mut dyn std::any::Any> {
+                match name {
+                    #(#names2 => Some(&mut self.#idents2),)*
+                    _ => None,
+                }
+            }
+
+            fn set_field(&mut self, name: &str, _inspect_value: Box<dyn std::any::Any>) -> Result<(), imgui_inspect::InspectSetFieldError> {
+                match name {
+                    #(#names3 => {
+                        let value = _inspect_value.downcast::<#types>().map_err(|_| imgui_inspect::InspectSetFieldError::WrongType)?;
+                        self.#idents3 = *value;
+                        Ok(())
+                    })*
+                    _ => Err(imgui_inspect::InspectSetFieldError::NoSuchField),
+                }
+            }
+        }
+    }
+}
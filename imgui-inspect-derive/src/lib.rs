@@ -4,7 +4,17 @@ mod inspect_macro;
 
 use crate::proc_macro::TokenStream;
 
-#[proc_macro_derive(Inspect, attributes(inspect, inspect_slider, inspect_struct))]
+#[proc_macro_derive(
+    Inspect,
+    attributes(
+        inspect,
+        inspect_slider,
+        inspect_struct,
+        inspect_plot,
+        inspect_curve,
+        inspect_gradient
+    )
+)]
 pub fn inspect_macro_derive(input: TokenStream) -> TokenStream {
     inspect_macro::impl_inspect_macro(input)
 }
@@ -0,0 +1,147 @@
+use crate::protocol::{write_message, MessageReader, Request, Response, Stream, MAX_RESPONSE_LEN};
+use crate::{PropertyEdit, PropertyNode};
+use std::io::{ErrorKind, Read};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+/// How long requests wait for the server to answer by default. See `InspectClient::set_timeout`
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// An error while talking to an `InspectServer`
+#[derive(Debug)]
+pub enum InspectRemoteError {
+    /// The connection failed or was closed
+    Io(std::io::Error),
+
+    /// A message couldn't be parsed
+    Protocol(serde_json::Error),
+
+    /// The server couldn't handle the request (i.e. an edit's path doesn't exist anymore)
+    Remote(String),
+}
+
+impl std::fmt::Display for InspectRemoteError {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            InspectRemoteError::Io(e) => write!(f, "connection error: {}", e),
+            InspectRemoteError::Protocol(e) => write!(f, "invalid message: {}", e),
+            InspectRemoteError::Remote(message) => write!(f, "server error: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for InspectRemoteError {}
+
+impl From<std::io::Error> for InspectRemoteError {
+    fn from(e: std::io::Error) -> Self {
+        InspectRemoteError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for InspectRemoteError {
+    fn from(e: serde_json::Error) -> Self {
+        InspectRemoteError::Protocol(e)
+    }
+}
+
+/// Connects to an `InspectServer` to fetch the property tree of the value it serves, and to edit it. Requests block
+/// until the server answers, which happens the next time it's polled, or until the timeout passes.
+pub struct InspectClient {
+    stream: Box<dyn Stream>,
+    reader: MessageReader,
+    // Requests that timed out. Their responses are skipped when they arrive so that they aren't mistaken for the
+    // response to a later request.
+    timed_out_requests: usize,
+}
+
+impl InspectClient {
+    pub fn connect_tcp<A: ToSocketAddrs>(addr: A) -> Result<Self, InspectRemoteError> {
+        let stream = TcpStream::connect(addr)?;
+        stream.set_nodelay(true)?;
+        Self::new(Box::new(stream))
+    }
+
+    #[cfg(unix)]
+    pub fn connect_unix<P: AsRef<std::path::Path>>(path: P) -> Result<Self, InspectRemoteError> {
+        let stream = std::os::unix::net::UnixStream::connect(path)?;
+        Self::new(Box::new(stream))
+    }
+
+    fn new(stream: Box<dyn Stream>) -> Result<Self, InspectRemoteError> {
+        stream.set_read_timeout(Some(DEFAULT_TIMEOUT))?;
+        Ok(InspectClient {
+            stream,
+            reader: MessageReader::new(MAX_RESPONSE_LEN),
+            timed_out_requests: 0,
+        })
+    }
+
+    /// Sets how long a request waits for the server to answer before failing with an `Io` error. None waits forever.
+    /// A server only answers when it's polled, so this should be longer than the time between polls.
+    pub fn set_timeout(
+        &mut self,
+        timeout: Option<Duration>,
+    ) -> Result<(), InspectRemoteError> {
+        Ok(self.stream.set_read_timeout(timeout)?)
+    }
+
+    /// Fetches the current property tree. Returns None if visiting the served value didn't produce a node.
+    pub fn fetch_tree(&mut self) -> Result<Option<PropertyNode>, InspectRemoteError> {
+        match self.request(&Request::GetTree)? {
+            Response::Tree(tree) => Ok(tree),
+            response => Err(Self::unexpected(response)),
+        }
+    }
+
+    /// Sends an edit (i.e. one returned by `visit_property_tree`) and waits for it to be applied
+    pub fn send_edit(
+        &mut self,
+        edit: PropertyEdit,
+    ) -> Result<(), InspectRemoteError> {
+        match self.request(&Request::Edit(edit))? {
+            Response::EditApplied => Ok(()),
+            response => Err(Self::unexpected(response)),
+        }
+    }
+
+    fn request(
+        &mut self,
+        request: &Request,
+    ) -> Result<Response, InspectRemoteError> {
+        write_message(&mut *self.stream, request)?;
+
+        let mut buffer = [0; 4096];
+        loop {
+            if let Some(response) = self.reader.next_message::<Response>() {
+                if self.timed_out_requests > 0 {
+                    self.timed_out_requests -= 1;
+                    continue;
+                }
+
+                return Ok(response?);
+            }
+
+            match self.stream.read(&mut buffer) {
+                Ok(0) => return Err(std::io::Error::from(ErrorKind::UnexpectedEof).into()),
+                Ok(len) => self.reader.push(&buffer[..len])?,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                // Depending on the platform, a read timeout is reported as either of these
+                Err(e) if e.kind() == ErrorKind::WouldBlock || e.kind() == ErrorKind::TimedOut => {
+                    self.timed_out_requests += 1;
+                    return Err(e.into());
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+
+    fn unexpected(response: Response) -> InspectRemoteError {
+        match response {
+            Response::Error(message) => InspectRemoteError::Remote(message),
+            response => InspectRemoteError::Remote(format!("unexpected response {:?}", response)),
+        }
+    }
+}
@@ -0,0 +1,8 @@
+mod client;
+mod property;
+mod protocol;
+mod server;
+
+pub use client::{InspectClient, InspectRemoteError, DEFAULT_TIMEOUT};
+pub use property::*;
+pub use server::InspectServer;
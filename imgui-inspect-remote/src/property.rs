@@ -0,0 +1,737 @@
+// The property tree is a serializable snapshot of a value, built by visiting it with PropertyTreeVisitor. Each node
+// has a path made of the labels from the root (i.e. "player.transform.position"), which is used to send edits back.
+// List elements are visited with an empty label, and are identified by their index instead (i.e. "particles.[3]").
+// Edits are applied by visiting the value again with ApplyEditVisitor, so the code generated by #[derive(Inspect)]
+// runs on_set callbacks the same way it does when a field is edited locally.
+use imgui_inspect::{
+    InspectArgsDefault, InspectArgsSlider, InspectArgsStruct, InspectVisit, InspectVisitor,
+};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::HashSet;
+
+/// The args parsed from a field's attributes. Fields that don't apply to the value's widget are None.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PropertyArgs {
+    pub header: Option<bool>,
+    pub indent_children: Option<bool>,
+    pub default_open: Option<bool>,
+    pub min_value: Option<f32>,
+    pub max_value: Option<f32>,
+    pub step: Option<f32>,
+}
+
+impl From<&InspectArgsDefault> for PropertyArgs {
+    fn from(args: &InspectArgsDefault) -> Self {
+        PropertyArgs {
+            header: args.header,
+            indent_children: args.indent_children,
+            default_open: args.default_open,
+            min_value: args.min_value,
+            max_value: args.max_value,
+            step: args.step,
+        }
+    }
+}
+
+impl From<&InspectArgsSlider> for PropertyArgs {
+    fn from(args: &InspectArgsSlider) -> Self {
+        PropertyArgs {
+            min_value: args.min_value,
+            max_value: args.max_value,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&InspectArgsStruct> for PropertyArgs {
+    fn from(args: &InspectArgsStruct) -> Self {
+        PropertyArgs {
+            header: args.header,
+            indent_children: args.indent_children,
+            default_open: args.default_open,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&PropertyArgs> for InspectArgsDefault {
+    fn from(args: &PropertyArgs) -> Self {
+        InspectArgsDefault {
+            header: args.header,
+            indent_children: args.indent_children,
+            default_open: args.default_open,
+            min_value: args.min_value,
+            max_value: args.max_value,
+            step: args.step,
+        }
+    }
+}
+
+impl From<&PropertyArgs> for InspectArgsSlider {
+    fn from(args: &PropertyArgs) -> Self {
+        InspectArgsSlider {
+            min_value: args.min_value,
+            max_value: args.max_value,
+        }
+    }
+}
+
+impl From<&PropertyArgs> for InspectArgsStruct {
+    fn from(args: &PropertyArgs) -> Self {
+        InspectArgsStruct {
+            header: args.header,
+            indent_children: args.indent_children,
+            default_open: args.default_open,
+        }
+    }
+}
+
+/// The value of a node in the property tree. There is a variant for each method of `InspectVisitor`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum PropertyValue {
+    Bool(bool),
+    F32(f32),
+    U32(u32),
+    Usize(usize),
+    String(String),
+    SliderF32(f32),
+    Struct {
+        type_name: String,
+        fields: Vec<PropertyNode>,
+    },
+    Option(Option<Box<PropertyNode>>),
+    List(Vec<PropertyNode>),
+    Unsupported {
+        type_name: String,
+    },
+}
+
+/// A value and its children, along with the args needed to draw it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PropertyNode {
+    pub label: String,
+    pub path: String,
+    pub args: PropertyArgs,
+    pub value: PropertyValue,
+}
+
+/// Sets the value at a path. Only values that are drawn with a widget (bool, f32, u32, usize, String, and sliders) can
+/// be set, and the value must use the same variant as the node in the property tree.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PropertyEdit {
+    pub path: String,
+    pub value: PropertyValue,
+}
+
+/// The reason an edit could not be applied
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyEditError {
+    /// No value has the edit's path
+    PathNotFound(String),
+
+    /// The edit's value doesn't match the type of the value at its path
+    WrongType(String),
+}
+
+impl std::fmt::Display for PropertyEditError {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            PropertyEditError::PathNotFound(path) => write!(f, "no value at \"{}\"", path),
+            PropertyEditError::WrongType(path) => {
+                write!(
+                    f,
+                    "edit does not match the type of the value at \"{}\"",
+                    path
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for PropertyEditError {}
+
+/// Builds a property tree for a value. The root node's path is the given label.
+pub fn property_tree<T: InspectVisit<T>>(
+    value: &mut T,
+    label: &'static str,
+) -> Option<PropertyNode> {
+    let mut visitor = PropertyTreeVisitor::default();
+    T::visit(
+        &mut [value],
+        label,
+        &mut visitor,
+        &InspectArgsDefault::default(),
+    );
+    visitor.nodes.pop()
+}
+
+/// Applies an edit from a property tree built with the same label. Runs on_set callbacks for the edited field.
+pub fn apply_edit<T: InspectVisit<T>>(
+    value: &mut T,
+    label: &'static str,
+    edit: &PropertyEdit,
+) -> Result<(), PropertyEditError> {
+    let mut visitor = ApplyEditVisitor {
+        path: String::new(),
+        edit,
+        result: None,
+    };
+    T::visit(
+        &mut [value],
+        label,
+        &mut visitor,
+        &InspectArgsDefault::default(),
+    );
+    visitor
+        .result
+        .unwrap_or_else(|| Err(PropertyEditError::PathNotFound(edit.path.clone())))
+}
+
+/// Passes a property tree to a visitor (i.e. `ImguiInspectVisitor` to draw it with the normal widgets). The tree is
+/// updated with any changes so that they show up immediately, and an edit is returned for each of them to be sent
+/// back to the inspected process.
+pub fn visit_property_tree(
+    node: &mut PropertyNode,
+    visitor: &mut dyn InspectVisitor,
+) -> Vec<PropertyEdit> {
+    let mut edits = Vec::new();
+    visit_node(node, visitor, &mut edits);
+    edits
+}
+
+// Empty labels (i.e. list elements) have the same path as their parent
+fn child_path(
+    path: &str,
+    label: &str,
+) -> String {
+    if label.is_empty() {
+        path.to_string()
+    } else if path.is_empty() {
+        label.to_string()
+    } else {
+        format!("{}.{}", path, label)
+    }
+}
+
+// Returns true if the value at path contains the value at descendant_path
+fn is_ancestor(
+    path: &str,
+    descendant_path: &str,
+) -> bool {
+    descendant_path.len() > path.len()
+        && descendant_path.starts_with(path)
+        && descendant_path[path.len()..].starts_with('.')
+}
+
+// The path segment for a list element
+fn list_item_label(index: usize) -> String {
+    format!("[{}]", index)
+}
+
+thread_local! {
+    static INTERNED_LABELS: RefCell<HashSet<&'static str>> = RefCell::new(HashSet::new());
+}
+
+// Labels passed to InspectVisitor must be 'static, so each distinct label received from the inspected process is kept
+// for the life of the program. Labels are field names and type names (list elements have an empty label), so there
+// are only as many as the remote types have fields. The table is bounded anyway so that a misbehaving server can't
+// use up memory, and labels past the limit are shown as OVERFLOW_LABEL.
+const MAX_INTERNED_LABELS: usize = 4096;
+const MAX_INTERNED_LABEL_LEN: usize = 256;
+const OVERFLOW_LABEL: &str = "<unknown>";
+
+fn intern_label(label: &str) -> &'static str {
+    INTERNED_LABELS.with(|x| {
+        let mut labels = x.borrow_mut();
+        match labels.get(label) {
+            Some(interned) => *interned,
+            None if labels.len() >= MAX_INTERNED_LABELS || label.len() > MAX_INTERNED_LABEL_LEN => {
+                OVERFLOW_LABEL
+            }
+            None => {
+                let interned: &'static str = Box::leak(label.to_string().into_boxed_str());
+                labels.insert(interned);
+                interned
+            }
+        }
+    })
+}
+
+fn visit_node(
+    node: &mut PropertyNode,
+    visitor: &mut dyn InspectVisitor,
+    edits: &mut Vec<PropertyEdit>,
+) -> bool {
+    let label = intern_label(&node.label);
+    let path = &node.path;
+    let args = &node.args;
+
+    macro_rules! visit_value {
+        ($value:expr, $variant:ident, $visit_fn:ident, $args_type:ty) => {{
+            let changed = visitor.$visit_fn(label, &mut [&mut *$value], &<$args_type>::from(args));
+            if changed {
+                edits.push(PropertyEdit {
+                    path: path.clone(),
+                    value: PropertyValue::$variant((*$value).clone()),
+                });
+            }
+            changed
+        }};
+    }
+
+    match &mut node.value {
+        PropertyValue::Bool(value) => visit_value!(value, Bool, visit_bool, InspectArgsDefault),
+        PropertyValue::F32(value) => visit_value!(value, F32, visit_f32, InspectArgsDefault),
+        PropertyValue::U32(value) => visit_value!(value, U32, visit_u32, InspectArgsDefault),
+        PropertyValue::Usize(value) => visit_value!(value, Usize, visit_usize, InspectArgsDefault),
+        PropertyValue::String(value) => {
+            visit_value!(value, String, visit_string, InspectArgsDefault)
+        }
+        PropertyValue::SliderF32(value) => {
+            visit_value!(value, SliderF32, visit_slider_f32, InspectArgsSlider)
+        }
+        PropertyValue::Struct { type_name, fields } => visitor.visit_struct(
+            label,
+            intern_label(type_name),
+            &InspectArgsStruct::from(args),
+            &mut |visitor| {
+                let mut changed = false;
+                for field in fields.iter_mut() {
+                    changed |= visit_node(field, visitor, edits);
+                }
+                changed
+            },
+        ),
+        PropertyValue::Option(value) => {
+            visitor.visit_option(label, &[value.is_some()], &mut |visitor| match value {
+                Some(value) => visit_node(value, visitor, edits),
+                None => false,
+            })
+        }
+        PropertyValue::List(items) => {
+            visitor.visit_list(label, &[items.len()], &mut |visitor, index| {
+                visit_node(&mut items[index], visitor, edits)
+            })
+        }
+        PropertyValue::Unsupported { type_name } => {
+            visitor.visit_unsupported(label, intern_label(type_name))
+        }
+    }
+}
+
+// Records the visited values as property nodes. Only the first of the selected values is recorded.
+#[derive(Default)]
+struct PropertyTreeVisitor {
+    path: String,
+    nodes: Vec<PropertyNode>,
+}
+
+impl PropertyTreeVisitor {
+    fn push(
+        &mut self,
+        label: &'static str,
+        args: PropertyArgs,
+        value: PropertyValue,
+    ) {
+        self.nodes.push(PropertyNode {
+            label: label.to_string(),
+            path: child_path(&self.path, label),
+            args,
+            value,
+        });
+    }
+
+    // Visits the children of the value with the given label, and returns their nodes
+    fn visit_children(
+        &mut self,
+        label: &'static str,
+        visit_children: impl FnOnce(&mut Self),
+    ) -> Vec<PropertyNode> {
+        let path_len = self.path.len();
+        self.path = child_path(&self.path, label);
+        let parent_nodes = std::mem::take(&mut self.nodes);
+
+        visit_children(self);
+
+        self.path.truncate(path_len);
+        std::mem::replace(&mut self.nodes, parent_nodes)
+    }
+}
+
+macro_rules! record_value {
+    ($visit_fn:ident, $t:ty, $variant:ident, $args_type:ty) => {
+        fn $visit_fn(
+            &mut self,
+            label: &'static str,
+            values: &mut [&mut $t],
+            args: &$args_type,
+        ) -> bool {
+            if let Some(value) = values.first() {
+                self.push(
+                    label,
+                    args.into(),
+                    PropertyValue::$variant((**value).clone()),
+                );
+            }
+            false
+        }
+    };
+}
+
+impl InspectVisitor for PropertyTreeVisitor {
+    record_value!(visit_bool, bool, Bool, InspectArgsDefault);
+    record_value!(visit_f32, f32, F32, InspectArgsDefault);
+    record_value!(visit_u32, u32, U32, InspectArgsDefault);
+    record_value!(visit_usize, usize, Usize, InspectArgsDefault);
+    record_value!(visit_string, String, String, InspectArgsDefault);
+    record_value!(visit_slider_f32, f32, SliderF32, InspectArgsSlider);
+
+    fn visit_struct(
+        &mut self,
+        label: &'static str,
+        type_name: &'static str,
+        args: &InspectArgsStruct,
+        visit_fields: &mut dyn FnMut(&mut dyn InspectVisitor) -> bool,
+    ) -> bool {
+        let fields = self.visit_children(label, |visitor| {
+            visit_fields(visitor);
+        });
+        self.push(
+            label,
+            args.into(),
+            PropertyValue::Struct {
+                type_name: type_name.to_string(),
+                fields,
+            },
+        );
+        false
+    }
+
+    fn visit_option(
+        &mut self,
+        label: &'static str,
+        is_some: &[bool],
+        visit_some: &mut dyn FnMut(&mut dyn InspectVisitor) -> bool,
+    ) -> bool {
+        // The value inside the option is visited with the same label, so it gets the same path as the option
+        let some_value = if is_some.first() == Some(&true) {
+            let parent_nodes = std::mem::take(&mut self.nodes);
+            visit_some(self);
+            std::mem::replace(&mut self.nodes, parent_nodes).pop()
+        } else {
+            None
+        };
+        self.push(
+            label,
+            PropertyArgs::default(),
+            PropertyValue::Option(some_value.map(Box::new)),
+        );
+        false
+    }
+
+    fn visit_list(
+        &mut self,
+        label: &'static str,
+        lens: &[usize],
+        visit_item: &mut dyn FnMut(&mut dyn InspectVisitor, usize) -> bool,
+    ) -> bool {
+        let len = lens.first().copied().unwrap_or(0);
+        let items = self.visit_children(label, |visitor| {
+            for index in 0..len {
+                let path_len = visitor.path.len();
+                visitor.path = child_path(&visitor.path, &list_item_label(index));
+                visit_item(visitor, index);
+                visitor.path.truncate(path_len);
+            }
+        });
+        self.push(label, PropertyArgs::default(), PropertyValue::List(items));
+        false
+    }
+
+    fn visit_unsupported(
+        &mut self,
+        label: &'static str,
+        type_name: &'static str,
+    ) -> bool {
+        self.push(
+            label,
+            PropertyArgs::default(),
+            PropertyValue::Unsupported {
+                type_name: type_name.to_string(),
+            },
+        );
+        false
+    }
+}
+
+// Sets the value at the edit's path, skipping structs and list items that don't contain it
+struct ApplyEditVisitor<'a> {
+    path: String,
+    edit: &'a PropertyEdit,
+    result: Option<Result<(), PropertyEditError>>,
+}
+
+impl ApplyEditVisitor<'_> {
+    fn set_value<T: Clone>(
+        &mut self,
+        label: &'static str,
+        values: &mut [&mut T],
+        value: Option<&T>,
+    ) -> bool {
+        if child_path(&self.path, label) != self.edit.path {
+            return false;
+        }
+
+        match value {
+            Some(value) => {
+                for v in values {
+                    **v = value.clone();
+                }
+                self.result = Some(Ok(()));
+                true
+            }
+            None => {
+                self.result = Some(Err(PropertyEditError::WrongType(self.edit.path.clone())));
+                false
+            }
+        }
+    }
+}
+
+macro_rules! apply_value {
+    ($visit_fn:ident, $t:ty, $variant:ident, $args_type:ty) => {
+        fn $visit_fn(
+            &mut self,
+            label: &'static str,
+            values: &mut [&mut $t],
+            _args: &$args_type,
+        ) -> bool {
+            let value = match &self.edit.value {
+                PropertyValue::$variant(value) => Some(value),
+                _ => None,
+            };
+            self.set_value(label, values, value)
+        }
+    };
+}
+
+impl InspectVisitor for ApplyEditVisitor<'_> {
+    apply_value!(visit_bool, bool, Bool, InspectArgsDefault);
+    apply_value!(visit_f32, f32, F32, InspectArgsDefault);
+    apply_value!(visit_u32, u32, U32, InspectArgsDefault);
+    apply_value!(visit_usize, usize, Usize, InspectArgsDefault);
+    apply_value!(visit_string, String, String, InspectArgsDefault);
+    apply_value!(visit_slider_f32, f32, SliderF32, InspectArgsSlider);
+
+    fn visit_struct(
+        &mut self,
+        label: &'static str,
+        _type_name: &'static str,
+        _args: &InspectArgsStruct,
+        visit_fields: &mut dyn FnMut(&mut dyn InspectVisitor) -> bool,
+    ) -> bool {
+        let path = child_path(&self.path, label);
+        if !is_ancestor(&path, &self.edit.path) {
+            return false;
+        }
+
+        let parent_path = std::mem::replace(&mut self.path, path);
+        let changed = visit_fields(self);
+        self.path = parent_path;
+        changed
+    }
+
+    fn visit_option(
+        &mut self,
+        _label: &'static str,
+        is_some: &[bool],
+        visit_some: &mut dyn FnMut(&mut dyn InspectVisitor) -> bool,
+    ) -> bool {
+        if is_some.iter().any(|x| *x) {
+            visit_some(self)
+        } else {
+            false
+        }
+    }
+
+    fn visit_list(
+        &mut self,
+        label: &'static str,
+        lens: &[usize],
+        visit_item: &mut dyn FnMut(&mut dyn InspectVisitor, usize) -> bool,
+    ) -> bool {
+        let path = child_path(&self.path, label);
+        if !is_ancestor(&path, &self.edit.path) {
+            return false;
+        }
+
+        // Item paths end in "[index]", so the index can be read from the path instead of visiting every item
+        let item_label = self.edit.path[path.len() + 1..]
+            .split('.')
+            .next()
+            .unwrap_or("");
+        let index = item_label
+            .strip_prefix('[')
+            .and_then(|x| x.strip_suffix(']'))
+            .and_then(|x| x.parse::<usize>().ok());
+        let len = lens.iter().copied().max().unwrap_or(0);
+
+        match index {
+            Some(index) if index < len => {
+                let item_path = child_path(&path, &list_item_label(index));
+                let parent_path = std::mem::replace(&mut self.path, item_path);
+                let changed = visit_item(self, index);
+                self.path = parent_path;
+                changed
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imgui_inspect_derive::Inspect;
+
+    #[derive(Inspect, Default)]
+    #[inspect(visitor)]
+    struct Particle {
+        position: f32,
+    }
+
+    #[derive(Inspect, Default)]
+    #[inspect(visitor)]
+    struct Settings {
+        #[inspect(on_set = "|s: &mut Settings| s.set_count += 1")]
+        name: String,
+        #[inspect_slider(min_value = 0.0, max_value = 1.0)]
+        opacity: f32,
+        enabled: bool,
+        particles: Vec<Particle>,
+        #[inspect(skip)]
+        set_count: u32,
+    }
+
+    fn settings() -> Settings {
+        Settings {
+            name: "world".to_string(),
+            opacity: 0.5,
+            enabled: true,
+            particles: vec![Particle { position: 1.0 }, Particle { position: 2.0 }],
+            set_count: 0,
+        }
+    }
+
+    fn find<'a>(
+        node: &'a PropertyNode,
+        path: &str,
+    ) -> Option<&'a PropertyNode> {
+        if node.path == path {
+            return Some(node);
+        }
+
+        match &node.value {
+            PropertyValue::Struct { fields, .. } => fields.iter().find_map(|x| find(x, path)),
+            PropertyValue::List(items) => items.iter().find_map(|x| find(x, path)),
+            PropertyValue::Option(Some(value)) => find(value, path),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn property_tree_has_a_node_per_field() {
+        let tree = property_tree(&mut settings(), "settings").unwrap();
+        assert_eq!(tree.path, "settings");
+
+        let name = find(&tree, "settings.name").unwrap();
+        assert_eq!(name.value, PropertyValue::String("world".to_string()));
+
+        let opacity = find(&tree, "settings.opacity").unwrap();
+        assert_eq!(opacity.value, PropertyValue::SliderF32(0.5));
+        assert_eq!(opacity.args.max_value, Some(1.0));
+
+        assert!(find(&tree, "settings.set_count").is_none());
+    }
+
+    #[test]
+    fn property_tree_identifies_list_elements_by_index() {
+        let tree = property_tree(&mut settings(), "settings").unwrap();
+
+        let particles = find(&tree, "settings.particles").unwrap();
+        match &particles.value {
+            PropertyValue::List(items) => assert_eq!(items.len(), 2),
+            value => panic!("expected a list, got {:?}", value),
+        }
+
+        let element = find(&tree, "settings.particles.[1]").unwrap();
+        assert_eq!(element.label, "");
+
+        let position = find(&tree, "settings.particles.[1].position").unwrap();
+        assert_eq!(position.value, PropertyValue::F32(2.0));
+    }
+
+    #[test]
+    fn apply_edit_sets_the_value_and_runs_on_set() {
+        let mut settings = settings();
+        let edit = PropertyEdit {
+            path: "settings.name".to_string(),
+            value: PropertyValue::String("renamed".to_string()),
+        };
+
+        assert_eq!(apply_edit(&mut settings, "settings", &edit), Ok(()));
+        assert_eq!(settings.name, "renamed");
+        assert_eq!(settings.set_count, 1);
+    }
+
+    #[test]
+    fn apply_edit_sets_list_elements() {
+        let mut settings = settings();
+        let edit = PropertyEdit {
+            path: "settings.particles.[1].position".to_string(),
+            value: PropertyValue::F32(5.0),
+        };
+
+        assert_eq!(apply_edit(&mut settings, "settings", &edit), Ok(()));
+        assert_eq!(settings.particles[0].position, 1.0);
+        assert_eq!(settings.particles[1].position, 5.0);
+    }
+
+    #[test]
+    fn apply_edit_rejects_missing_paths_and_wrong_types() {
+        let mut settings = settings();
+
+        let missing = PropertyEdit {
+            path: "settings.particles.[2].position".to_string(),
+            value: PropertyValue::F32(5.0),
+        };
+        assert_eq!(
+            apply_edit(&mut settings, "settings", &missing),
+            Err(PropertyEditError::PathNotFound(missing.path.clone()))
+        );
+
+        let wrong_type = PropertyEdit {
+            path: "settings.enabled".to_string(),
+            value: PropertyValue::F32(5.0),
+        };
+        assert_eq!(
+            apply_edit(&mut settings, "settings", &wrong_type),
+            Err(PropertyEditError::WrongType(wrong_type.path.clone()))
+        );
+        assert!(settings.enabled);
+        assert_eq!(settings.set_count, 0);
+    }
+
+    #[test]
+    fn interned_labels_are_bounded() {
+        assert_eq!(intern_label("position"), intern_label("position"));
+        assert_eq!(
+            intern_label(&"x".repeat(MAX_INTERNED_LABEL_LEN + 1)),
+            OVERFLOW_LABEL
+        );
+    }
+}
@@ -0,0 +1,178 @@
+// Messages are sent as JSON, one per line. The client sends a Request and waits for the Response to it, so a
+// connection never has more than one message in flight in each direction. Lines have a maximum length so that a peer
+// can't make the other side buffer an unbounded amount of data.
+use crate::{PropertyEdit, PropertyNode};
+use serde::{Deserialize, Serialize};
+use std::io::{ErrorKind, Read, Write};
+use std::time::Duration;
+
+/// The longest request the server accepts. Requests are small, so this is only reached by a misbehaving client.
+pub(crate) const MAX_REQUEST_LEN: usize = 1024 * 1024;
+
+/// The longest response the client accepts. Responses can contain the whole property tree.
+pub(crate) const MAX_RESPONSE_LEN: usize = 256 * 1024 * 1024;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) enum Request {
+    GetTree,
+    Edit(PropertyEdit),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) enum Response {
+    Tree(Option<PropertyNode>),
+    EditApplied,
+    Error(String),
+}
+
+/// A socket that the server or client can communicate over
+pub(crate) trait Stream: Read + Write + Send {
+    fn set_nonblocking(
+        &self,
+        nonblocking: bool,
+    ) -> std::io::Result<()>;
+
+    fn set_read_timeout(
+        &self,
+        timeout: Option<Duration>,
+    ) -> std::io::Result<()>;
+}
+
+impl Stream for std::net::TcpStream {
+    fn set_nonblocking(
+        &self,
+        nonblocking: bool,
+    ) -> std::io::Result<()> {
+        std::net::TcpStream::set_nonblocking(self, nonblocking)
+    }
+
+    fn set_read_timeout(
+        &self,
+        timeout: Option<Duration>,
+    ) -> std::io::Result<()> {
+        std::net::TcpStream::set_read_timeout(self, timeout)
+    }
+}
+
+#[cfg(unix)]
+impl Stream for std::os::unix::net::UnixStream {
+    fn set_nonblocking(
+        &self,
+        nonblocking: bool,
+    ) -> std::io::Result<()> {
+        std::os::unix::net::UnixStream::set_nonblocking(self, nonblocking)
+    }
+
+    fn set_read_timeout(
+        &self,
+        timeout: Option<Duration>,
+    ) -> std::io::Result<()> {
+        std::os::unix::net::UnixStream::set_read_timeout(self, timeout)
+    }
+}
+
+/// Appends a message and the newline that ends it
+pub(crate) fn encode_message<M: Serialize>(
+    message: &M,
+    bytes: &mut Vec<u8>,
+) -> std::io::Result<()> {
+    serde_json::to_writer(&mut *bytes, message)?;
+    bytes.push(b'\n');
+    Ok(())
+}
+
+pub(crate) fn write_message<M: Serialize>(
+    stream: &mut dyn Stream,
+    message: &M,
+) -> std::io::Result<()> {
+    let mut bytes = Vec::new();
+    encode_message(message, &mut bytes)?;
+    stream.write_all(&bytes)?;
+    stream.flush()
+}
+
+// Splits the bytes read from a stream into lines. Partial lines are kept until the rest of the line arrives.
+pub(crate) struct MessageReader {
+    buffer: Vec<u8>,
+    max_line_len: usize,
+    // The length of the last line in the buffer, which hasn't been ended yet
+    partial_line_len: usize,
+}
+
+impl MessageReader {
+    pub(crate) fn new(max_line_len: usize) -> Self {
+        MessageReader {
+            buffer: Vec::new(),
+            max_line_len,
+            partial_line_len: 0,
+        }
+    }
+
+    /// Adds bytes read from the stream. Fails if a line is longer than the maximum, in which case the connection
+    /// should be closed.
+    pub(crate) fn push(
+        &mut self,
+        bytes: &[u8],
+    ) -> std::io::Result<()> {
+        let mut lines = bytes.split(|x| *x == b'\n').peekable();
+        while let Some(line) = lines.next() {
+            self.partial_line_len += line.len();
+            if self.partial_line_len > self.max_line_len {
+                return Err(std::io::Error::new(
+                    ErrorKind::InvalidData,
+                    "message is longer than the maximum length",
+                ));
+            }
+
+            // Every line but the last one was ended by a newline
+            if lines.peek().is_some() {
+                self.partial_line_len = 0;
+            }
+        }
+
+        self.buffer.extend_from_slice(bytes);
+        Ok(())
+    }
+
+    pub(crate) fn next_message<M: for<'de> Deserialize<'de>>(
+        &mut self
+    ) -> Option<serde_json::Result<M>> {
+        let end = self.buffer.iter().position(|x| *x == b'\n')?;
+        let message = serde_json::from_slice(&self.buffer[..end]);
+        self.buffer.drain(..=end);
+        Some(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn message_reader_splits_lines() {
+        let mut reader = MessageReader::new(MAX_REQUEST_LEN);
+        reader.push(b"\"GetTree\"\n\"Get").unwrap();
+        assert!(matches!(
+            reader.next_message::<Request>(),
+            Some(Ok(Request::GetTree))
+        ));
+        assert!(reader.next_message::<Request>().is_none());
+
+        reader.push(b"Tree\"\n").unwrap();
+        assert!(matches!(
+            reader.next_message::<Request>(),
+            Some(Ok(Request::GetTree))
+        ));
+    }
+
+    #[test]
+    fn message_reader_rejects_long_lines() {
+        let mut reader = MessageReader::new(8);
+        reader.push(b"12345678\n12345678\n1234").unwrap();
+        assert!(reader.push(b"56789").is_err());
+
+        // A long line is rejected even if it arrives in one piece with its newline
+        let mut reader = MessageReader::new(8);
+        assert!(reader.push(b"123456789\n").is_err());
+    }
+}
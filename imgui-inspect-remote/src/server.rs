@@ -0,0 +1,282 @@
+use crate::protocol::{
+    encode_message, MessageReader, Request, Response, Stream, MAX_REQUEST_LEN, MAX_RESPONSE_LEN,
+};
+use crate::{apply_edit, property_tree};
+use imgui_inspect::InspectVisit;
+use std::io::{ErrorKind, Read, Write};
+use std::net::{SocketAddr, TcpListener, ToSocketAddrs};
+
+// Responses that haven't been sent yet are queued on the connection. A client that keeps sending requests without
+// reading the responses is dropped once its queue is longer than this.
+const MAX_QUEUED_RESPONSE_LEN: usize = MAX_RESPONSE_LEN;
+
+enum Listener {
+    Tcp(TcpListener),
+    #[cfg(unix)]
+    Unix(std::os::unix::net::UnixListener),
+}
+
+struct Connection {
+    stream: Box<dyn Stream>,
+    reader: MessageReader,
+    // Encoded responses that the socket wasn't ready to take yet
+    write_queue: Vec<u8>,
+}
+
+/// Serves the property tree of a value to `InspectClient`s and applies the edits they send back. The server never
+/// blocks: call `poll` regularly (i.e. once per tick) from the thread that owns the value.
+pub struct InspectServer {
+    listener: Listener,
+    connections: Vec<Connection>,
+}
+
+impl InspectServer {
+    /// Listens for clients on a TCP address. Use port 0 to pick any free port, and `local_addr` to find out which one.
+    pub fn bind_tcp<A: ToSocketAddrs>(addr: A) -> std::io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+        Ok(InspectServer {
+            listener: Listener::Tcp(listener),
+            connections: Vec::new(),
+        })
+    }
+
+    /// Listens for clients on a unix socket at the given path
+    #[cfg(unix)]
+    pub fn bind_unix<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<Self> {
+        let listener = std::os::unix::net::UnixListener::bind(path)?;
+        listener.set_nonblocking(true)?;
+        Ok(InspectServer {
+            listener: Listener::Unix(listener),
+            connections: Vec::new(),
+        })
+    }
+
+    /// The address the server is listening on, if it's listening on TCP
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        match &self.listener {
+            Listener::Tcp(listener) => listener.local_addr().ok(),
+            #[cfg(unix)]
+            Listener::Unix(_) => None,
+        }
+    }
+
+    /// The number of connected clients
+    pub fn connection_count(&self) -> usize {
+        self.connections.len()
+    }
+
+    /// Accepts new clients and answers their requests. Edits are applied to the value, running on_set callbacks for
+    /// the fields they change. Returns true if the value was changed.
+    ///
+    /// The label is the root of every path in the property tree, so it must be the same every time the server is
+    /// polled.
+    pub fn poll<T: InspectVisit<T>>(
+        &mut self,
+        value: &mut T,
+        label: &'static str,
+    ) -> bool {
+        self.accept_connections();
+
+        let mut changed = false;
+        for mut connection in std::mem::take(&mut self.connections) {
+            // Connections are dropped if the client disconnected, sent something that isn't a request, or isn't reading
+            // its responses
+            if let Ok(connection_changed) = Self::poll_connection(&mut connection, value, label) {
+                changed |= connection_changed;
+                self.connections.push(connection);
+            }
+        }
+
+        changed
+    }
+
+    fn accept_connections(&mut self) {
+        loop {
+            let stream: std::io::Result<Box<dyn Stream>> = match &self.listener {
+                Listener::Tcp(listener) => listener.accept().map(|(stream, _)| {
+                    // Requests and responses are small, so don't wait to batch them
+                    let _ = stream.set_nodelay(true);
+                    Box::new(stream) as Box<dyn Stream>
+                }),
+                #[cfg(unix)]
+                Listener::Unix(listener) => listener
+                    .accept()
+                    .map(|(stream, _)| Box::new(stream) as Box<dyn Stream>),
+            };
+
+            match stream {
+                Ok(stream) => {
+                    if stream.set_nonblocking(true).is_ok() {
+                        self.connections.push(Connection {
+                            stream,
+                            reader: MessageReader::new(MAX_REQUEST_LEN),
+                            write_queue: Vec::new(),
+                        });
+                    }
+                }
+                Err(_) => break,
+            }
+        }
+    }
+
+    fn poll_connection<T: InspectVisit<T>>(
+        connection: &mut Connection,
+        value: &mut T,
+        label: &'static str,
+    ) -> std::io::Result<bool> {
+        let mut buffer = [0; 4096];
+        loop {
+            match connection.stream.read(&mut buffer) {
+                Ok(0) => return Err(ErrorKind::UnexpectedEof.into()),
+                Ok(len) => connection.reader.push(&buffer[..len])?,
+                Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        let mut changed = false;
+        while let Some(request) = connection.reader.next_message::<Request>() {
+            let response = match request? {
+                Request::GetTree => Response::Tree(property_tree(value, label)),
+                Request::Edit(edit) => match apply_edit(value, label, &edit) {
+                    Ok(()) => {
+                        changed = true;
+                        Response::EditApplied
+                    }
+                    Err(e) => Response::Error(e.to_string()),
+                },
+            };
+
+            encode_message(&response, &mut connection.write_queue)?;
+            if connection.write_queue.len() > MAX_QUEUED_RESPONSE_LEN {
+                return Err(ErrorKind::WouldBlock.into());
+            }
+        }
+
+        Self::flush_connection(connection)?;
+        Ok(changed)
+    }
+
+    // Writes as much of the queued responses as the socket will take without blocking. The rest is sent the next
+    // time the server is polled.
+    fn flush_connection(connection: &mut Connection) -> std::io::Result<()> {
+        let mut written = 0;
+        while written < connection.write_queue.len() {
+            match connection.stream.write(&connection.write_queue[written..]) {
+                Ok(0) => return Err(ErrorKind::WriteZero.into()),
+                Ok(len) => written += len,
+                Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        connection.write_queue.drain(..written);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{InspectClient, InspectRemoteError, PropertyEdit, PropertyValue};
+    use std::sync::mpsc;
+    use std::time::{Duration, Instant};
+
+    // Polls the server until f finishes on another thread, and returns its result
+    fn poll_while<T: InspectVisit<T>, R: Send + 'static>(
+        server: &mut InspectServer,
+        value: &mut T,
+        f: impl FnOnce() -> R + Send + 'static,
+    ) -> R {
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || sender.send(f()).unwrap());
+
+        let start = Instant::now();
+        loop {
+            server.poll(value, "values");
+            if let Ok(result) = receiver.try_recv() {
+                return result;
+            }
+
+            assert!(start.elapsed() < Duration::from_secs(10), "timed out");
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn client_fetches_the_tree_and_edits_the_value() {
+        let mut server = InspectServer::bind_tcp("127.0.0.1:0").unwrap();
+        let addr = server.local_addr().unwrap();
+        let mut values = vec![1.0f32, 2.0];
+
+        let tree = poll_while(&mut server, &mut values, move || {
+            let mut client = InspectClient::connect_tcp(addr).unwrap();
+            client
+                .send_edit(PropertyEdit {
+                    path: "values.[1]".to_string(),
+                    value: PropertyValue::F32(5.0),
+                })
+                .unwrap();
+            client.fetch_tree().unwrap().unwrap()
+        });
+
+        assert_eq!(values, vec![1.0, 5.0]);
+        match tree.value {
+            PropertyValue::List(items) => assert_eq!(items[1].value, PropertyValue::F32(5.0)),
+            value => panic!("expected a list, got {:?}", value),
+        }
+    }
+
+    #[test]
+    fn client_skips_responses_to_requests_that_timed_out() {
+        let mut server = InspectServer::bind_tcp("127.0.0.1:0").unwrap();
+        let addr = server.local_addr().unwrap();
+        let mut values = vec![1.0f32];
+
+        // The server isn't polled, so the request times out
+        let mut client = InspectClient::connect_tcp(addr).unwrap();
+        client.set_timeout(Some(Duration::from_millis(50))).unwrap();
+        assert!(matches!(
+            client.fetch_tree(),
+            Err(InspectRemoteError::Io(_))
+        ));
+
+        let result = poll_while(&mut server, &mut values, move || {
+            client.set_timeout(None).unwrap();
+            client
+                .send_edit(PropertyEdit {
+                    path: "values.[0]".to_string(),
+                    value: PropertyValue::F32(3.0),
+                })
+                .map_err(|e| e.to_string())
+        });
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(values, vec![3.0]);
+    }
+
+    #[test]
+    fn server_drops_clients_that_send_long_lines() {
+        let mut server = InspectServer::bind_tcp("127.0.0.1:0").unwrap();
+        let addr = server.local_addr().unwrap();
+        let mut values = vec![1.0f32];
+
+        // The stream is kept open so that the connection isn't dropped because the client disconnected
+        let stream = std::net::TcpStream::connect(addr).unwrap();
+        let mut writer = stream.try_clone().unwrap();
+        poll_while(&mut server, &mut values, move || {
+            // The server closes the connection partway through, so the write may fail
+            let _ = writer.write_all(&vec![b' '; MAX_REQUEST_LEN + 1]);
+        });
+
+        let start = Instant::now();
+        while server.connection_count() > 0 {
+            server.poll(&mut values, "values");
+            assert!(start.elapsed() < Duration::from_secs(10), "timed out");
+        }
+        drop(stream);
+    }
+}
@@ -0,0 +1,171 @@
+// Measures the cost of drawing fields with a headless imgui context. Nothing is rendered to the screen, but imgui still
+// does all of its per-frame work, so this is representative of what an inspector costs in a real application.
+//
+// Run with `cargo bench -p imgui-inspect`
+use imgui_inspect::{InspectArgsDefault, InspectRenderDefault};
+use imgui_inspect_derive::Inspect;
+use std::time::{Duration, Instant};
+
+const WARMUP_FRAMES: u32 = 10;
+const FRAMES: u32 = 200;
+const ENTITY_COUNT: usize = 1000;
+
+#[derive(Inspect, Default, Clone)]
+struct Transform {
+    x: f32,
+    y: f32,
+    z: f32,
+}
+
+#[derive(Inspect, Default, Clone)]
+struct Entity {
+    name: String,
+    visible: bool,
+    health: u32,
+    index: usize,
+    speed: Option<f32>,
+    transform: Transform,
+}
+
+// The derived struct itself isn't a field, so Entity has 6 fields plus 3 in Transform
+const FIELDS_PER_ENTITY: usize = 9;
+
+fn create_context() -> imgui::Context {
+    let mut context = imgui::Context::create();
+    context.set_ini_filename(None);
+    context.io_mut().display_size = [1920.0, 1080.0];
+    context.fonts().build_rgba32_texture();
+    context
+}
+
+// Draws f for a number of frames and returns the average time per frame, not counting warm-up frames
+fn run_frames<F: FnMut(&imgui::Ui)>(
+    context: &mut imgui::Context,
+    mut f: F,
+) -> Duration {
+    let mut start = Instant::now();
+    for frame in 0..(WARMUP_FRAMES + FRAMES) {
+        if frame == WARMUP_FRAMES {
+            start = Instant::now();
+        }
+
+        context.io_mut().delta_time = 1.0 / 60.0;
+        let ui = context.frame();
+        imgui::Window::new(imgui::im_str!("Inspector"))
+            .size([400.0, 1000.0], imgui::Condition::Always)
+            .build(&ui, || f(&ui));
+        ui.render();
+    }
+
+    start.elapsed() / FRAMES
+}
+
+fn report(
+    name: &str,
+    frame_time: Duration,
+    field_count: usize,
+) {
+    println!(
+        "{:<40} {:>10.3} ms/frame {:>10.1} ns/field",
+        name,
+        frame_time.as_secs_f64() * 1000.0,
+        frame_time.as_secs_f64() * 1_000_000_000.0 / field_count as f64
+    );
+}
+
+fn bench_render(entities: &[Entity]) {
+    let mut context = create_context();
+    let frame_time = run_frames(&mut context, |ui| {
+        for (i, entity) in entities.iter().enumerate() {
+            let id_token = ui.push_id(i as i32);
+            <Entity as InspectRenderDefault<Entity>>::render(
+                &[entity],
+                "entity",
+                ui,
+                &InspectArgsDefault::default(),
+            );
+            id_token.pop(ui);
+        }
+    });
+
+    report("render", frame_time, entities.len() * FIELDS_PER_ENTITY);
+}
+
+fn bench_render_mut(entities: &mut [Entity]) {
+    let mut context = create_context();
+    let field_count = entities.len() * FIELDS_PER_ENTITY;
+    let frame_time = run_frames(&mut context, |ui| {
+        for (i, entity) in entities.iter_mut().enumerate() {
+            let id_token = ui.push_id(i as i32);
+            <Entity as InspectRenderDefault<Entity>>::render_mut(
+                &mut [entity],
+                "entity",
+                ui,
+                &InspectArgsDefault::default(),
+            );
+            id_token.pop(ui);
+        }
+    });
+
+    report("render_mut", frame_time, field_count);
+}
+
+fn bench_render_mut_multiple_selected(entities: &mut [Entity]) {
+    let mut context = create_context();
+    let field_count = (entities.len() / 2) * FIELDS_PER_ENTITY;
+    let frame_time = run_frames(&mut context, |ui| {
+        for (i, pair) in entities.chunks_exact_mut(2).enumerate() {
+            let id_token = ui.push_id(i as i32);
+            let mut selected: Vec<&mut Entity> = pair.iter_mut().collect();
+            <Entity as InspectRenderDefault<Entity>>::render_mut(
+                &mut selected,
+                "entity",
+                ui,
+                &InspectArgsDefault::default(),
+            );
+            id_token.pop(ui);
+        }
+    });
+
+    report("render_mut (2 selected)", frame_time, field_count);
+}
+
+// Only the visible rows of the current page are drawn, so this should cost about the same as a short list
+fn bench_large_list() {
+    let mut particles: Vec<f32> = (0..100_000).map(|i| i as f32).collect();
+    let mut context = create_context();
+    let frame_time = run_frames(&mut context, |ui| {
+        // Open the list's tree node so that its rows are drawn
+        unsafe {
+            imgui::sys::igSetNextItemOpen(true, imgui::Condition::Always as imgui::sys::ImGuiCond);
+        }
+        <Vec<f32> as InspectRenderDefault<Vec<f32>>>::render_mut(
+            &mut [&mut particles],
+            "particles",
+            ui,
+            &InspectArgsDefault::default(),
+        );
+    });
+
+    report("render_mut (100k element Vec)", frame_time, particles.len());
+}
+
+fn main() {
+    let mut entities: Vec<Entity> = (0..ENTITY_COUNT)
+        .map(|i| Entity {
+            name: format!("Entity {}", i),
+            index: i,
+            ..Default::default()
+        })
+        .collect();
+
+    println!(
+        "{} entities, {} fields each, {} frames",
+        ENTITY_COUNT, FIELDS_PER_ENTITY, FRAMES
+    );
+
+    bench_render(&entities);
+    bench_render_mut(&mut entities);
+    bench_render_mut_multiple_selected(&mut entities);
+    bench_large_list();
+}
@@ -0,0 +1,238 @@
+// Copy/paste for fields of derived structs. Values are stored on the clipboard as text, using Display/FromStr if the
+// type has them, or JSON if the serde feature is enabled and the type implements Serialize/Deserialize.
+//
+// The derive can't know which of these a field's type implements, so it picks one with method resolution. The traits
+// below are implemented for different levels of references to InspectClipboardTag<T>. Calling
+// `(&&&InspectClipboardTag::<T>::new()).clipboard_ops()` finds the impl with the fewest derefs whose bounds are met, so
+// Display/FromStr is preferred over serde, and anything else gets None. This only works when T is a concrete type, so
+// fields whose type is a generic parameter don't support copy/paste.
+//
+// A field's menu opens when the field's own widget is right-clicked. For most fields that's the last item they draw,
+// but values with a header (structs, lists, maps) draw their children after it, and those children may have menus of
+// their own. These values call claim_field_context_menu right after drawing their header so that the field's menu is
+// attached to the header instead. Fields that draw other fields without a header (i.e. a struct with header = false)
+// don't get a menu, since any item they draw belongs to one of their children.
+use crate::scratch::scratch_im_str;
+use std::cell::RefCell;
+use std::marker::PhantomData;
+
+#[derive(Default)]
+struct FieldMenuScope {
+    // Set by claim_field_context_menu
+    header_clicked: Option<bool>,
+    // True if a field was drawn inside this one
+    has_child_fields: bool,
+}
+
+thread_local! {
+    static FIELD_MENU_SCOPES: RefCell<Vec<FieldMenuScope>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Converts a value to and from clipboard text
+pub struct InspectClipboardOps<T> {
+    pub to_text: fn(&T) -> String,
+    pub from_text: fn(&str) -> Result<T, String>,
+}
+
+/// Used by the derive to look up the InspectClipboardOps for T. See `InspectClipboardText`
+pub struct InspectClipboardTag<T>(PhantomData<T>);
+
+impl<T> InspectClipboardTag<T> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        InspectClipboardTag(PhantomData)
+    }
+}
+
+/// Copy/paste using Display and FromStr. Types that implement both are copied as plain text.
+pub trait InspectClipboardText<T> {
+    fn clipboard_ops(&self) -> Option<InspectClipboardOps<T>>;
+}
+
+impl<T> InspectClipboardText<T> for &&InspectClipboardTag<T>
+where
+    T: std::fmt::Display + std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    fn clipboard_ops(&self) -> Option<InspectClipboardOps<T>> {
+        Some(InspectClipboardOps {
+            to_text: |value| value.to_string(),
+            // Whitespace is only trimmed if the text doesn't parse as is, so that strings paste back exactly as
+            // they were copied
+            from_text: |text| {
+                text.parse::<T>()
+                    .or_else(|_| text.trim().parse::<T>())
+                    .map_err(|e| e.to_string())
+            },
+        })
+    }
+}
+
+/// Copy/paste using serde. Types are copied as JSON. This requires the serde feature.
+pub trait InspectClipboardSerde<T> {
+    fn clipboard_ops(&self) -> Option<InspectClipboardOps<T>>;
+}
+
+#[cfg(feature = "serde")]
+impl<T> InspectClipboardSerde<T> for &InspectClipboardTag<T>
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    fn clipboard_ops(&self) -> Option<InspectClipboardOps<T>> {
+        Some(InspectClipboardOps {
+            to_text: |value| serde_json::to_string_pretty(value).unwrap_or_default(),
+            from_text: |text| serde_json::from_str(text).map_err(|e| e.to_string()),
+        })
+    }
+}
+
+/// Fallback for types that can't be copied
+pub trait InspectClipboardNone<T> {
+    fn clipboard_ops(&self) -> Option<InspectClipboardOps<T>>;
+}
+
+impl<T> InspectClipboardNone<T> for InspectClipboardTag<T> {
+    fn clipboard_ops(&self) -> Option<InspectClipboardOps<T>> {
+        None
+    }
+}
+
+/// What the user chose from a field's context menu
+#[derive(Debug, Default)]
+pub struct InspectFieldMenuAction {
+    /// The user chose "Reset to Default"
+    pub reset: bool,
+
+    /// The user chose "Paste". This is the clipboard text, which has already been checked to parse.
+    pub paste: Option<String>,
+}
+
+/// Returned by begin_field_context_menu. Pass it to end_field_context_menu after the field is drawn.
+pub struct InspectFieldMenuScope {
+    depth: usize,
+}
+
+/// Call before drawing a field that has a context menu. The derive calls this for every field.
+pub fn begin_field_context_menu() -> InspectFieldMenuScope {
+    FIELD_MENU_SCOPES.with(|x| {
+        let mut scopes = x.borrow_mut();
+        if let Some(parent) = scopes.last_mut() {
+            parent.has_child_fields = true;
+        }
+
+        scopes.push(FieldMenuScope::default());
+        InspectFieldMenuScope {
+            depth: scopes.len(),
+        }
+    })
+}
+
+/// Call after drawing a field. Returns true if the field's own widget (its header, if it claimed one, or else the
+/// last item it drew) was right-clicked, in which case its context menu should open.
+pub fn end_field_context_menu(
+    scope: InspectFieldMenuScope,
+    ui: &imgui::Ui,
+) -> bool {
+    let field_scope = FIELD_MENU_SCOPES.with(|x| {
+        let mut scopes = x.borrow_mut();
+        scopes.truncate(scope.depth);
+        scopes.pop()
+    });
+
+    match field_scope {
+        Some(FieldMenuScope {
+            header_clicked: Some(header_clicked),
+            ..
+        }) => header_clicked,
+        Some(FieldMenuScope {
+            has_child_fields: true,
+            ..
+        }) => false,
+        _ => ui.is_item_clicked(imgui::MouseButton::Right),
+    }
+}
+
+/// Call right after drawing a header that has other items below it. If the value is being drawn as a field, the
+/// field's context menu opens when the header is right-clicked, and this returns true. Otherwise (the value isn't a
+/// field, or the field already has a header), this returns false and the header may have a menu of its own.
+pub fn claim_field_context_menu(ui: &imgui::Ui) -> bool {
+    FIELD_MENU_SCOPES.with(|x| match x.borrow_mut().last_mut() {
+        Some(scope) if scope.header_clicked.is_none() && !scope.has_child_fields => {
+            scope.header_clicked = Some(ui.is_item_clicked(imgui::MouseButton::Right));
+            true
+        }
+        _ => false,
+    })
+}
+
+/// Draws a field's context menu, and opens it if is_clicked is true (see end_field_context_menu). The menu has "Copy"
+/// and "Paste" if the field's type supports the clipboard, and "Reset to Default" if is_modified is given (i.e. the
+/// field has a default). When multiple values are selected, the first one is copied.
+///
+/// This is called by the derive for every field. `id` must be unique among the fields drawn at the same level.
+pub fn render_field_context_menu<T>(
+    id: &str,
+    is_clicked: bool,
+    value: Option<&T>,
+    clipboard: Option<&InspectClipboardOps<T>>,
+    is_modified: Option<bool>,
+    read_only: bool,
+    ui: &imgui::Ui,
+) -> InspectFieldMenuAction {
+    let mut action = InspectFieldMenuAction::default();
+
+    let has_reset = is_modified.is_some() && !read_only;
+    if clipboard.is_none() && !has_reset {
+        return action;
+    }
+
+    let popup_id = scratch_im_str!("##inspect_context_menu_{}", id);
+    if is_clicked {
+        ui.open_popup(&popup_id);
+    }
+
+    ui.popup(&popup_id, || {
+        if let Some(clipboard) = clipboard {
+            if imgui::MenuItem::new(imgui::im_str!("Copy"))
+                .enabled(value.is_some())
+                .build(ui)
+            {
+                if let Some(value) = value {
+                    ui.set_clipboard_text(&imgui::ImString::new((clipboard.to_text)(value)));
+                }
+            }
+
+            if !read_only {
+                // Only offer to paste if the clipboard holds something that can be parsed, and say why if it can't
+                let clipboard_text = ui.clipboard_text().map(|x| x.to_str().to_string());
+                let paste_error = clipboard_text
+                    .as_ref()
+                    .and_then(|x| (clipboard.from_text)(x).err());
+
+                if imgui::MenuItem::new(imgui::im_str!("Paste"))
+                    .enabled(clipboard_text.is_some() && paste_error.is_none())
+                    .build(ui)
+                {
+                    action.paste = clipboard_text;
+                }
+
+                if let Some(error) = paste_error {
+                    ui.text_colored(
+                        [1.0, 0.0, 0.0, 1.0],
+                        scratch_im_str!("Can't paste: {}", error),
+                    );
+                }
+            }
+        }
+
+        if has_reset
+            && imgui::MenuItem::new(imgui::im_str!("Reset to Default"))
+                .enabled(is_modified == Some(true))
+                .build(ui)
+        {
+            action.reset = true;
+        }
+    });
+
+    action
+}
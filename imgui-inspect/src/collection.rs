@@ -0,0 +1,334 @@
+// Shared widgets for collections. The impls for std (and optional third-party) collections are thin wrappers around
+// these so that all collections look and behave the same way.
+//
+// When multiple collections are selected, the keys shown are the union of all of them. Keys that aren't in every
+// selected collection are drawn as inconsistent. Edits, additions, removals, and renames apply to every selected
+// collection.
+//
+// Keys are always shown sorted. Collections that iterate in an arbitrary order (i.e. HashMap) sort their keys so that
+// the display is the same from frame to frame and from run to run.
+use crate::scratch::scratch_im_str;
+use crate::widget_state::{widget_state_owner, with_widget_state};
+use crate::{
+    claim_field_context_menu, layout_record_header, layout_restore_next_header, push_layout_path,
+    InspectArgsDefault, InspectRenderDefault,
+};
+
+const RENAME_KEY_STATE: &str = "inspect_collection_rename_key";
+const ADD_KEY_STATE: &str = "inspect_collection_add_key";
+
+/// Operations needed to draw a keyed collection. Sets are treated as maps without values.
+pub(crate) trait InspectMapLike {
+    type Key: InspectRenderDefault<Self::Key> + Clone + PartialEq + 'static;
+
+    /// All keys in the collection, in no particular order
+    fn keys(&self) -> Vec<Self::Key>;
+
+    /// Puts keys in the order they should be displayed. Collections with an inherent order can leave this alone.
+    fn sort_keys(_keys: &mut Vec<Self::Key>) {}
+
+    fn contains_key(
+        &self,
+        key: &Self::Key,
+    ) -> bool;
+
+    /// Adds a key, with a default value if the collection has values
+    fn insert_default(
+        &mut self,
+        key: Self::Key,
+    );
+
+    /// Changes a key without changing the value associated with it
+    fn rename_key(
+        &mut self,
+        old_key: &Self::Key,
+        new_key: Self::Key,
+    );
+
+    fn remove_key(
+        &mut self,
+        key: &Self::Key,
+    );
+}
+
+/// Maps also have a value associated with each key
+pub(crate) trait InspectMapValues: InspectMapLike {
+    type Value: InspectRenderDefault<Self::Value>;
+
+    fn get_value(
+        &self,
+        key: &Self::Key,
+    ) -> Option<&Self::Value>;
+
+    fn get_value_mut(
+        &mut self,
+        key: &Self::Key,
+    ) -> Option<&mut Self::Value>;
+}
+
+// Returns the union of keys across all selected collections in display order
+fn collect_keys<M: InspectMapLike>(maps: &[&M]) -> Vec<M::Key> {
+    let first = match maps.first() {
+        Some(first) => *first,
+        None => return vec![],
+    };
+
+    let mut keys = first.keys();
+    let mut extra_keys = vec![];
+    for map in &maps[1..] {
+        for key in map.keys() {
+            if !first.contains_key(&key) && !extra_keys.contains(&key) {
+                extra_keys.push(key);
+            }
+        }
+    }
+
+    keys.append(&mut extra_keys);
+    M::sort_keys(&mut keys);
+    keys
+}
+
+// Draws a key read-only, marking it as inconsistent if not every selected collection has it
+fn render_key<M: InspectMapLike>(
+    in_all_maps: bool,
+    key: &M::Key,
+    ui: &imgui::Ui,
+    args: &InspectArgsDefault,
+) {
+    let style_token = if in_all_maps {
+        None
+    } else {
+        Some(ui.push_style_color(imgui::StyleColor::Text, [1.0, 1.0, 0.0, 1.0]))
+    };
+
+    <M::Key as InspectRenderDefault<M::Key>>::render(&[key], "key", ui, args);
+
+    if let Some(style_token) = style_token {
+        style_token.pop(ui);
+    }
+}
+
+// Draws an editor for a key that will be added or renamed. The key being edited is kept in widget state, and nothing is
+// drawn if there isn't one. Returns the key once the user confirms it. Keys that would collide with an existing key
+// can't be confirmed.
+fn render_key_editor<M: InspectMapLike>(
+    data: &[&mut M],
+    state_name: &'static str,
+    original_key: Option<&M::Key>,
+    confirm_label: &imgui::ImStr,
+    ui: &imgui::Ui,
+    args: &InspectArgsDefault,
+) -> Option<M::Key> {
+    let owner = widget_state_owner(&*data[0], ui);
+    with_widget_state(owner, state_name, |pending: &mut Option<M::Key>| {
+        let pending_key = pending.as_mut()?;
+        <M::Key as InspectRenderDefault<M::Key>>::render_mut(&mut [pending_key], "key", ui, args);
+
+        if original_key == Some(pending_key) {
+            return None;
+        }
+
+        if data.iter().any(|x| x.contains_key(pending_key)) {
+            ui.text_colored([1.0, 0.0, 0.0, 1.0], "A key with this value already exists");
+            return None;
+        }
+
+        if ui.small_button(confirm_label) {
+            pending.take()
+        } else {
+            None
+        }
+    })
+}
+
+fn push_collection_node(
+    label: &str,
+    len: usize,
+    ui: &imgui::Ui,
+) -> Option<imgui::TreeNodeToken> {
+    let layout_token = push_layout_path(label);
+    layout_restore_next_header(ui);
+
+    // The ### keeps the ID stable when the length changes so that the node doesn't close
+    let node_token =
+        imgui::TreeNode::new(&scratch_im_str!("{} ({})###{}", label, len, label)).push(ui);
+    claim_field_context_menu(ui);
+
+    layout_record_header(node_token.is_some());
+    layout_token.end();
+    node_token
+}
+
+/// Draws a keyed collection read-only. render_value is called for each key.
+pub(crate) fn render_map<M: InspectMapLike, F: FnMut(&[&M], &M::Key)>(
+    data: &[&M],
+    label: &str,
+    ui: &imgui::Ui,
+    args: &InspectArgsDefault,
+    mut render_value: F,
+) {
+    if data.is_empty() {
+        ui.text(scratch_im_str!("{}: None", label));
+        return;
+    }
+
+    let keys = collect_keys(data);
+    if let Some(node_token) = push_collection_node(label, keys.len(), ui) {
+        for (index, key) in keys.iter().enumerate() {
+            let id_token = ui.push_id(index as i32);
+            render_key::<M>(data.iter().all(|x| x.contains_key(key)), key, ui, args);
+            render_value(data, key);
+            id_token.pop(ui);
+        }
+
+        node_token.pop(ui);
+    }
+}
+
+/// Draws a keyed collection with widgets for editing, renaming, adding and removing keys. render_value_mut is called
+/// for each key and returns true if the value changed. New keys start out as the key type's default.
+pub(crate) fn render_map_mut<M: InspectMapLike, F: FnMut(&mut [&mut M], &M::Key) -> bool>(
+    data: &mut [&mut M],
+    label: &str,
+    ui: &imgui::Ui,
+    args: &InspectArgsDefault,
+    mut render_value_mut: F,
+) -> bool
+where
+    M::Key: Default,
+{
+    if data.is_empty() {
+        ui.text(scratch_im_str!("{}: None", label));
+        return false;
+    }
+
+    let maps: Vec<&M> = data.iter().map(|x| &**x).collect();
+    let keys = collect_keys(&maps);
+    let node_token = match push_collection_node(label, keys.len(), ui) {
+        Some(node_token) => node_token,
+        None => return false,
+    };
+
+    let mut changed = false;
+    let mut key_to_remove = None;
+    let mut key_to_rename = None;
+    for (index, key) in keys.iter().enumerate() {
+        let id_token = ui.push_id(index as i32);
+
+        render_key::<M>(data.iter().all(|x| x.contains_key(key)), key, ui, args);
+
+        ui.same_line(0.0);
+        if ui.small_button(imgui::im_str!("Rename")) {
+            let owner = widget_state_owner(&*data[0], ui);
+            with_widget_state(owner, RENAME_KEY_STATE, |pending: &mut Option<M::Key>| {
+                *pending = Some(key.clone())
+            });
+            ui.open_popup(imgui::im_str!("Rename"));
+        }
+
+        ui.same_line(0.0);
+        if ui.small_button(imgui::im_str!("Remove")) {
+            key_to_remove = Some(key.clone());
+        }
+
+        ui.popup(imgui::im_str!("Rename"), || {
+            if let Some(new_key) = render_key_editor(
+                data,
+                RENAME_KEY_STATE,
+                Some(key),
+                imgui::im_str!("OK"),
+                ui,
+                args,
+            ) {
+                key_to_rename = Some((key.clone(), new_key));
+                ui.close_current_popup();
+            }
+        });
+
+        changed |= render_value_mut(data, key);
+
+        id_token.pop(ui);
+    }
+
+    if let Some(key) = key_to_remove {
+        for d in data.iter_mut() {
+            d.remove_key(&key);
+        }
+        changed = true;
+    }
+
+    if let Some((old_key, new_key)) = key_to_rename {
+        for d in data.iter_mut() {
+            d.rename_key(&old_key, new_key.clone());
+        }
+        changed = true;
+    }
+
+    ui.separator();
+    let owner = widget_state_owner(&*data[0], ui);
+    with_widget_state(owner, ADD_KEY_STATE, |pending: &mut Option<M::Key>| {
+        pending.get_or_insert_with(Default::default);
+    });
+
+    if let Some(new_key) =
+        render_key_editor(data, ADD_KEY_STATE, None, imgui::im_str!("Add"), ui, args)
+    {
+        for d in data.iter_mut() {
+            d.insert_default(new_key.clone());
+        }
+        changed = true;
+    }
+
+    node_token.pop(ui);
+    changed
+}
+
+/// Draws the values for a key across all selected maps that contain it
+pub(crate) fn render_map_values<M: InspectMapValues>(
+    data: &[&M],
+    key: &M::Key,
+    ui: &imgui::Ui,
+    args: &InspectArgsDefault,
+) {
+    let values: Vec<_> = data.iter().filter_map(|x| x.get_value(key)).collect();
+    ui.indent();
+    <M::Value as InspectRenderDefault<M::Value>>::render(&values, "value", ui, args);
+    ui.unindent();
+}
+
+/// Draws the values for a key across all selected maps that contain it. Returns true if the values changed.
+pub(crate) fn render_map_values_mut<M: InspectMapValues>(
+    data: &mut [&mut M],
+    key: &M::Key,
+    ui: &imgui::Ui,
+    args: &InspectArgsDefault,
+) -> bool {
+    let mut values: Vec<_> = data
+        .iter_mut()
+        .filter_map(|x| x.get_value_mut(key))
+        .collect();
+    ui.indent();
+    let changed =
+        <M::Value as InspectRenderDefault<M::Value>>::render_mut(&mut values, "value", ui, args);
+    ui.unindent();
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{HashMap, HashSet};
+
+    #[test]
+    fn hash_map_keys_are_sorted() {
+        let map: HashMap<u32, f32> = (0..100).rev().map(|x| (x, 0.0)).collect();
+        assert_eq!(collect_keys(&[&map]), (0..100).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn union_of_hash_sets_is_sorted() {
+        let a: HashSet<String> = ["c", "a"].iter().map(|x| x.to_string()).collect();
+        let b: HashSet<String> = ["b", "a", "d"].iter().map(|x| x.to_string()).collect();
+        assert_eq!(collect_keys(&[&a, &b]), vec!["a", "b", "c", "d"]);
+    }
+}
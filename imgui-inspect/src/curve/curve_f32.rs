@@ -0,0 +1,94 @@
+use super::*;
+
+impl CurvePoint for (f32, f32) {
+    fn new(
+        x: f32,
+        y: f32,
+    ) -> Self {
+        (x, y)
+    }
+
+    fn x(&self) -> f32 {
+        self.0
+    }
+
+    fn y(&self) -> f32 {
+        self.1
+    }
+}
+
+impl CurvePoint for [f32; 2] {
+    fn new(
+        x: f32,
+        y: f32,
+    ) -> Self {
+        [x, y]
+    }
+
+    fn x(&self) -> f32 {
+        self[0]
+    }
+
+    fn y(&self) -> f32 {
+        self[1]
+    }
+}
+
+macro_rules! impl_curve {
+    ($point:ty) => {
+        impl InspectRenderCurve<Vec<$point>> for Vec<$point> {
+            fn render(
+                data: &[&Self],
+                label: &'static str,
+                ui: &imgui::Ui,
+                args: &InspectArgsCurve,
+            ) {
+                if data.is_empty() {
+                    ui.text(scratch_im_str!("{}: None", label));
+                    return;
+                }
+
+                let is_consistent = get_same_or_none_ref(data).is_some();
+                let owner = widget_state_owner(data[0], ui);
+                render_curve(data[0], None, owner, label, is_consistent, ui, args);
+            }
+
+            fn render_mut(
+                data: &mut [&mut Self],
+                label: &'static str,
+                ui: &imgui::Ui,
+                args: &InspectArgsCurve,
+            ) -> bool {
+                if data.is_empty() {
+                    ui.text(scratch_im_str!("{}: None", label));
+                    return false;
+                }
+
+                // The first selected curve is edited, and copied to the others if it changes
+                let is_consistent = get_same_or_none_mut_ref(data).is_some();
+                let owner = widget_state_owner(&*data[0], ui);
+                let changed = render_curve(
+                    &[],
+                    Some(&mut *data[0]),
+                    owner,
+                    label,
+                    is_consistent,
+                    ui,
+                    args,
+                );
+
+                if changed {
+                    let (first, rest) = data.split_first_mut().unwrap();
+                    for d in rest {
+                        d.clone_from(first);
+                    }
+                }
+
+                changed
+            }
+        }
+    };
+}
+
+impl_curve!((f32, f32));
+impl_curve!([f32; 2]);
@@ -0,0 +1,53 @@
+use super::*;
+
+impl InspectRenderGradient<Vec<(f32, [f32; 4])>> for Vec<(f32, [f32; 4])> {
+    fn render(
+        data: &[&Self],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsGradient,
+    ) {
+        if data.is_empty() {
+            ui.text(scratch_im_str!("{}: None", label));
+            return;
+        }
+
+        let is_consistent = get_same_or_none_ref(data).is_some();
+        let owner = widget_state_owner(data[0], ui);
+        render_gradient(data[0], None, owner, label, is_consistent, ui, args);
+    }
+
+    fn render_mut(
+        data: &mut [&mut Self],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsGradient,
+    ) -> bool {
+        if data.is_empty() {
+            ui.text(scratch_im_str!("{}: None", label));
+            return false;
+        }
+
+        // The first selected gradient is edited, and copied to the others if it changes
+        let is_consistent = get_same_or_none_mut_ref(data).is_some();
+        let owner = widget_state_owner(&*data[0], ui);
+        let changed = render_gradient(
+            &[],
+            Some(&mut *data[0]),
+            owner,
+            label,
+            is_consistent,
+            ui,
+            args,
+        );
+
+        if changed {
+            let (first, rest) = data.split_first_mut().unwrap();
+            for d in rest {
+                d.clone_from(first);
+            }
+        }
+
+        changed
+    }
+}
@@ -0,0 +1,825 @@
+// Editors for animation curves (keyframes of x/y pairs) and color gradients (color stops). imgui has no widgets for
+// these, so they're drawn with the window's draw list over an invisible button that takes the mouse input:
+//  - Drag a point (or stop) to move it. Points are kept sorted by x as they're moved.
+//  - Double-click an empty spot to add a point there
+//  - Right-click a point to remove it
+//
+// The point being dragged and the selected color stop are kept in widget state, since they aren't part of the value.
+mod curve_f32;
+mod gradient_f32;
+
+pub use super::*;
+
+use crate::widget_state::{widget_state_owner, with_widget_state, WidgetStateOwner};
+
+const CURVE_STATE: &str = "inspect_curve";
+const GRADIENT_STATE: &str = "inspect_gradient";
+
+const DEFAULT_CURVE_HEIGHT: f32 = 120.0;
+const DEFAULT_GRADIENT_HEIGHT: f32 = 24.0;
+const GRADIENT_MARKER_HEIGHT: f32 = 10.0;
+
+// Cubic curves are drawn as this many line segments
+const CURVE_SEGMENTS: usize = 64;
+const POINT_RADIUS: f32 = 4.0;
+// How close the mouse has to be to a point to grab it
+const POINT_GRAB_RADIUS: f32 = 8.0;
+
+const BACKGROUND_COLOR: [f32; 4] = [0.1, 0.1, 0.12, 1.0];
+const BORDER_COLOR: [f32; 4] = [0.4, 0.4, 0.45, 1.0];
+const CURVE_COLOR: [f32; 4] = [0.4, 0.8, 1.0, 1.0];
+const POINT_COLOR: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+const ACTIVE_POINT_COLOR: [f32; 4] = [1.0, 0.8, 0.2, 1.0];
+
+/// How the curve editor previews the values between points
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InspectCurveInterpolation {
+    /// A straight line between each pair of points
+    Linear,
+
+    /// Each point's value is held until the next point
+    Step,
+
+    /// A smooth curve through the points (Catmull-Rom)
+    Cubic,
+}
+
+/// Options for rendering keyframes with the curve editor.
+///
+/// Marking a struct member will give it a default rendering behavior. For example,
+/// `#[inspect_curve(interpolation = "cubic", min_y = -1.0, max_y = 1.0)]`
+#[derive(Debug, Default)]
+pub struct InspectArgsCurve {
+    /// How values between points are previewed. This defaults to linear.
+    pub interpolation: Option<InspectCurveInterpolation>,
+
+    /// The x value at the left edge of the editor. This defaults to 0.0
+    pub min_x: Option<f32>,
+
+    /// The x value at the right edge of the editor. This defaults to 1.0
+    pub max_x: Option<f32>,
+
+    /// The y value at the bottom edge of the editor. This defaults to 0.0
+    pub min_y: Option<f32>,
+
+    /// The y value at the top edge of the editor. This defaults to 1.0
+    pub max_y: Option<f32>,
+
+    /// The height of the editor in pixels
+    pub height: Option<f32>,
+}
+
+impl From<InspectArgsDefault> for InspectArgsCurve {
+    fn from(default_args: InspectArgsDefault) -> Self {
+        Self {
+            min_y: default_args.min_value,
+            max_y: default_args.max_value,
+            ..Default::default()
+        }
+    }
+}
+
+/// Renders keyframes (x/y pairs sorted by x) with the curve editor
+pub trait InspectRenderCurve<T> {
+    /// Render the curve without editing it
+    fn render(
+        data: &[&T],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsCurve,
+    );
+
+    /// Render the curve with points that can be added, moved, and removed
+    fn render_mut(
+        data: &mut [&mut T],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsCurve,
+    ) -> bool;
+}
+
+/// Options for rendering color stops with the gradient editor.
+///
+/// Marking a struct member will give it a default rendering behavior. For example,
+/// `#[inspect_gradient(height = 32.0)]`
+#[derive(Debug, Default)]
+pub struct InspectArgsGradient {
+    /// The height of the gradient bar in pixels
+    pub height: Option<f32>,
+}
+
+impl From<InspectArgsDefault> for InspectArgsGradient {
+    fn from(_default_args: InspectArgsDefault) -> Self {
+        Default::default()
+    }
+}
+
+/// Renders color stops (positions from 0.0 to 1.0, sorted by position) with the gradient editor
+pub trait InspectRenderGradient<T> {
+    /// Render the gradient without editing it
+    fn render(
+        data: &[&T],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsGradient,
+    );
+
+    /// Render the gradient with stops that can be added, moved, removed, and recolored
+    fn render_mut(
+        data: &mut [&mut T],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsGradient,
+    ) -> bool;
+}
+
+// A point of a curve. This lets the editor work on tuples and arrays without converting them.
+pub(crate) trait CurvePoint: Clone {
+    fn new(
+        x: f32,
+        y: f32,
+    ) -> Self;
+    fn x(&self) -> f32;
+    fn y(&self) -> f32;
+}
+
+#[derive(Default)]
+struct CurveState {
+    dragging: Option<usize>,
+    // From the mouse to the dragged point when the drag started, so that grabbing a point off-center doesn't move it
+    grab_offset: [f32; 2],
+}
+
+#[derive(Default)]
+struct GradientState {
+    selected: Option<usize>,
+    dragging: bool,
+    // Horizontal distance from the mouse to the dragged stop when the drag started
+    grab_offset: f32,
+}
+
+// Maps between values and screen positions in the editor's rect
+struct CurveCanvas {
+    min: [f32; 2],
+    size: [f32; 2],
+    min_value: [f32; 2],
+    max_value: [f32; 2],
+}
+
+impl CurveCanvas {
+    fn value_to_screen(
+        &self,
+        x: f32,
+        y: f32,
+    ) -> [f32; 2] {
+        let tx = (x - self.min_value[0]) / (self.max_value[0] - self.min_value[0]);
+        let ty = (y - self.min_value[1]) / (self.max_value[1] - self.min_value[1]);
+        [
+            self.min[0] + tx * self.size[0],
+            self.min[1] + (1.0 - ty) * self.size[1],
+        ]
+    }
+
+    // Values outside of the editor are clamped to its edges
+    fn screen_to_value(
+        &self,
+        pos: [f32; 2],
+    ) -> (f32, f32) {
+        let tx = ((pos[0] - self.min[0]) / self.size[0]).clamp(0.0, 1.0);
+        let ty = (1.0 - (pos[1] - self.min[1]) / self.size[1]).clamp(0.0, 1.0);
+        (
+            self.min_value[0] + tx * (self.max_value[0] - self.min_value[0]),
+            self.min_value[1] + ty * (self.max_value[1] - self.min_value[1]),
+        )
+    }
+
+    fn max(&self) -> [f32; 2] {
+        [self.min[0] + self.size[0], self.min[1] + self.size[1]]
+    }
+}
+
+fn distance_squared(
+    a: [f32; 2],
+    b: [f32; 2],
+) -> f32 {
+    (a[0] - b[0]) * (a[0] - b[0]) + (a[1] - b[1]) * (a[1] - b[1])
+}
+
+// Moves the element at index left or right until the slice is sorted again, and returns its new index
+fn resort<T, F: Fn(&T) -> f32>(
+    values: &mut [T],
+    mut index: usize,
+    key: F,
+) -> usize {
+    while index > 0 && key(&values[index - 1]) > key(&values[index]) {
+        values.swap(index - 1, index);
+        index -= 1;
+    }
+
+    while index + 1 < values.len() && key(&values[index + 1]) < key(&values[index]) {
+        values.swap(index + 1, index);
+        index += 1;
+    }
+
+    index
+}
+
+// The value of the curve at x. Points must be sorted by x. Before the first point and after the last, the curve is
+// flat.
+fn evaluate_curve<P: CurvePoint>(
+    points: &[P],
+    x: f32,
+    interpolation: InspectCurveInterpolation,
+) -> Option<f32> {
+    let first = points.first()?;
+    let last = points.last()?;
+    if x <= first.x() {
+        return Some(first.y());
+    }
+    if x >= last.x() {
+        return Some(last.y());
+    }
+
+    let i = points.windows(2).position(|w| x < w[1].x())?;
+    let (p0, p1) = (&points[i], &points[i + 1]);
+    let width = p1.x() - p0.x();
+    if width <= 0.0 {
+        return Some(p1.y());
+    }
+
+    let t = (x - p0.x()) / width;
+    Some(match interpolation {
+        InspectCurveInterpolation::Linear => p0.y() + (p1.y() - p0.y()) * t,
+        InspectCurveInterpolation::Step => p0.y(),
+        InspectCurveInterpolation::Cubic => {
+            // Cubic hermite with Catmull-Rom tangents. The end points use a one-sided tangent.
+            let tangent = |i: usize| {
+                let before = &points[i.saturating_sub(1)];
+                let after = &points[(i + 1).min(points.len() - 1)];
+                let dx = after.x() - before.x();
+                if dx > 0.0 {
+                    (after.y() - before.y()) / dx
+                } else {
+                    0.0
+                }
+            };
+
+            let (m0, m1) = (tangent(i), tangent(i + 1));
+            let t2 = t * t;
+            let t3 = t2 * t;
+            (2.0 * t3 - 3.0 * t2 + 1.0) * p0.y()
+                + (t3 - 2.0 * t2 + t) * width * m0
+                + (-2.0 * t3 + 3.0 * t2) * p1.y()
+                + (t3 - t2) * width * m1
+        }
+    })
+}
+
+/// Draws the curve editor. If values is None, the curve is drawn read-only. Returns true if the points changed.
+pub(crate) fn render_curve<P: CurvePoint>(
+    points: &[P],
+    mut values: Option<&mut Vec<P>>,
+    owner: WidgetStateOwner,
+    label: &str,
+    is_consistent: bool,
+    ui: &imgui::Ui,
+    args: &InspectArgsCurve,
+) -> bool {
+    let canvas = CurveCanvas {
+        min: ui.cursor_screen_pos(),
+        size: [
+            ui.calc_item_width().max(1.0),
+            args.height.unwrap_or(DEFAULT_CURVE_HEIGHT),
+        ],
+        min_value: [args.min_x.unwrap_or(0.0), args.min_y.unwrap_or(0.0)],
+        max_value: [args.max_x.unwrap_or(1.0), args.max_y.unwrap_or(1.0)],
+    };
+
+    ui.invisible_button(&scratch_im_str!("{}", label), canvas.size);
+    let is_hovered = ui.is_item_hovered();
+    let mouse_pos = ui.io().mouse_pos;
+
+    let mut changed = false;
+    let active_point = with_widget_state(owner, CURVE_STATE, |state: &mut CurveState| {
+        let values = match values.as_mut() {
+            Some(values) => values,
+            None => return None,
+        };
+
+        let hovered_point = values
+            .iter()
+            .map(|p| distance_squared(canvas.value_to_screen(p.x(), p.y()), mouse_pos))
+            .enumerate()
+            .filter(|(_, d)| *d <= POINT_GRAB_RADIUS * POINT_GRAB_RADIUS)
+            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|(i, _)| i)
+            .filter(|_| is_hovered);
+
+        if is_hovered {
+            if ui.is_mouse_double_clicked(imgui::MouseButton::Left) && hovered_point.is_none() {
+                let (x, y) = canvas.screen_to_value(mouse_pos);
+                let index = values.len();
+                values.push(P::new(x, y));
+                state.dragging = Some(resort(values, index, |p| p.x()));
+                state.grab_offset = [0.0, 0.0];
+                changed = true;
+            } else if ui.is_mouse_clicked(imgui::MouseButton::Left) {
+                state.dragging = hovered_point;
+                if let Some(index) = hovered_point {
+                    let pos = canvas.value_to_screen(values[index].x(), values[index].y());
+                    state.grab_offset = [pos[0] - mouse_pos[0], pos[1] - mouse_pos[1]];
+                }
+            } else if ui.is_mouse_clicked(imgui::MouseButton::Right) {
+                if let Some(index) = hovered_point {
+                    values.remove(index);
+                    state.dragging = None;
+                    changed = true;
+                }
+            }
+        }
+
+        // The index can be stale if the points were changed by something else
+        state.dragging = state.dragging.filter(|x| *x < values.len());
+        if let Some(index) = state.dragging {
+            if !ui.is_mouse_down(imgui::MouseButton::Left) {
+                state.dragging = None;
+            } else if ui.is_mouse_dragging(imgui::MouseButton::Left) {
+                // Nothing changes until the mouse moves, so clicking a point doesn't edit it
+                let (x, y) = canvas.screen_to_value([
+                    mouse_pos[0] + state.grab_offset[0],
+                    mouse_pos[1] + state.grab_offset[1],
+                ]);
+                if values[index].x() != x || values[index].y() != y {
+                    values[index] = P::new(x, y);
+                    state.dragging = Some(resort(values, index, |p| p.x()));
+                    changed = true;
+                }
+            }
+        }
+
+        state.dragging.or(hovered_point)
+    });
+
+    let points = match values.as_ref() {
+        Some(values) => &values[..],
+        None => points,
+    };
+    draw_curve(points, active_point, &canvas, ui, args);
+
+    if let Some(point) = active_point.and_then(|x| points.get(x)) {
+        ui.tooltip_text(scratch_im_str!("x: {:.3}  y: {:.3}", point.x(), point.y()));
+    }
+
+    ui.same_line(0.0);
+    render_editor_label(label, is_consistent, ui);
+
+    changed
+}
+
+fn draw_curve<P: CurvePoint>(
+    points: &[P],
+    active_point: Option<usize>,
+    canvas: &CurveCanvas,
+    ui: &imgui::Ui,
+    args: &InspectArgsCurve,
+) {
+    let draw_list = ui.get_window_draw_list();
+    draw_list
+        .add_rect(canvas.min, canvas.max(), BACKGROUND_COLOR)
+        .filled(true)
+        .build();
+
+    let interpolation = args
+        .interpolation
+        .unwrap_or(InspectCurveInterpolation::Linear);
+
+    draw_list.with_clip_rect(canvas.min, canvas.max(), || {
+        let mut previous: Option<[f32; 2]> = None;
+        let mut line_to = |pos: [f32; 2]| {
+            if let Some(previous) = previous {
+                draw_list
+                    .add_line(previous, pos, CURVE_COLOR)
+                    .thickness(2.0)
+                    .build();
+            }
+            previous = Some(pos);
+        };
+
+        match interpolation {
+            // Lines and steps are drawn exactly, rather than sampled, so corners stay sharp
+            InspectCurveInterpolation::Linear | InspectCurveInterpolation::Step => {
+                if let (Some(first), Some(last)) = (points.first(), points.last()) {
+                    line_to(canvas.value_to_screen(canvas.min_value[0], first.y()));
+                    for (i, point) in points.iter().enumerate() {
+                        if interpolation == InspectCurveInterpolation::Step && i > 0 {
+                            line_to(canvas.value_to_screen(point.x(), points[i - 1].y()));
+                        }
+                        line_to(canvas.value_to_screen(point.x(), point.y()));
+                    }
+                    line_to(canvas.value_to_screen(canvas.max_value[0], last.y()));
+                }
+            }
+            InspectCurveInterpolation::Cubic => {
+                for i in 0..=CURVE_SEGMENTS {
+                    let x = canvas.min_value[0]
+                        + (canvas.max_value[0] - canvas.min_value[0]) * i as f32
+                            / CURVE_SEGMENTS as f32;
+                    if let Some(y) = evaluate_curve(points, x, interpolation) {
+                        line_to(canvas.value_to_screen(x, y));
+                    }
+                }
+            }
+        }
+
+        for (i, point) in points.iter().enumerate() {
+            let color = if active_point == Some(i) {
+                ACTIVE_POINT_COLOR
+            } else {
+                POINT_COLOR
+            };
+
+            draw_list
+                .add_circle(
+                    canvas.value_to_screen(point.x(), point.y()),
+                    POINT_RADIUS,
+                    color,
+                )
+                .filled(true)
+                .build();
+        }
+    });
+
+    draw_list
+        .add_rect(canvas.min, canvas.max(), BORDER_COLOR)
+        .build();
+}
+
+// The color of the gradient at a position. Stops must be sorted by position.
+fn evaluate_gradient(
+    stops: &[(f32, [f32; 4])],
+    position: f32,
+) -> Option<[f32; 4]> {
+    let first = stops.first()?;
+    let last = stops.last()?;
+    if position <= first.0 {
+        return Some(first.1);
+    }
+    if position >= last.0 {
+        return Some(last.1);
+    }
+
+    let i = stops.windows(2).position(|w| position < w[1].0)?;
+    let (s0, s1) = (&stops[i], &stops[i + 1]);
+    let width = s1.0 - s0.0;
+    if width <= 0.0 {
+        return Some(s1.1);
+    }
+
+    let t = (position - s0.0) / width;
+    let mut color = [0.0; 4];
+    for (c, (a, b)) in color.iter_mut().zip(s0.1.iter().zip(s1.1.iter())) {
+        *c = a + (b - a) * t;
+    }
+    Some(color)
+}
+
+/// Draws the gradient editor. If values is None, the gradient is drawn read-only. Returns true if the stops changed.
+pub(crate) fn render_gradient(
+    stops: &[(f32, [f32; 4])],
+    mut values: Option<&mut Vec<(f32, [f32; 4])>>,
+    owner: WidgetStateOwner,
+    label: &str,
+    is_consistent: bool,
+    ui: &imgui::Ui,
+    args: &InspectArgsGradient,
+) -> bool {
+    let bar_height = args.height.unwrap_or(DEFAULT_GRADIENT_HEIGHT);
+    let canvas = CurveCanvas {
+        min: ui.cursor_screen_pos(),
+        size: [ui.calc_item_width().max(1.0), bar_height],
+        min_value: [0.0, 0.0],
+        max_value: [1.0, 1.0],
+    };
+
+    // The markers under the bar are part of the button too, so they can be grabbed
+    ui.invisible_button(
+        &scratch_im_str!("{}", label),
+        [canvas.size[0], bar_height + GRADIENT_MARKER_HEIGHT],
+    );
+    let is_hovered = ui.is_item_hovered();
+    let mouse_pos = ui.io().mouse_pos;
+
+    let mut changed = false;
+    let selected = with_widget_state(owner, GRADIENT_STATE, |state: &mut GradientState| {
+        let values = match values.as_mut() {
+            Some(values) => values,
+            None => return None,
+        };
+
+        let hovered_stop = values
+            .iter()
+            .map(|s| (canvas.value_to_screen(s.0, 0.0)[0] - mouse_pos[0]).abs())
+            .enumerate()
+            .filter(|(_, d)| *d <= POINT_GRAB_RADIUS)
+            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|(i, _)| i);
+
+        if is_hovered {
+            if ui.is_mouse_double_clicked(imgui::MouseButton::Left) && hovered_stop.is_none() {
+                let (position, _) = canvas.screen_to_value(mouse_pos);
+                let color = evaluate_gradient(values, position).unwrap_or([1.0; 4]);
+                let index = values.len();
+                values.push((position, color));
+                state.selected = Some(resort(values, index, |s| s.0));
+                changed = true;
+            } else if ui.is_mouse_clicked(imgui::MouseButton::Left) {
+                if let Some(index) = hovered_stop {
+                    state.selected = hovered_stop;
+                    state.grab_offset =
+                        canvas.value_to_screen(values[index].0, 0.0)[0] - mouse_pos[0];
+                }
+                state.dragging = hovered_stop.is_some();
+            } else if ui.is_mouse_clicked(imgui::MouseButton::Right) {
+                if let Some(index) = hovered_stop {
+                    values.remove(index);
+                    state.selected = None;
+                    state.dragging = false;
+                    changed = true;
+                }
+            }
+        }
+
+        // The index can be stale if the stops were changed by something else
+        state.selected = state.selected.filter(|x| *x < values.len());
+        if let (Some(index), true) = (state.selected, state.dragging) {
+            if !ui.is_mouse_down(imgui::MouseButton::Left) {
+                state.dragging = false;
+            } else if ui.is_mouse_dragging(imgui::MouseButton::Left) {
+                // Nothing changes until the mouse moves, so clicking a stop only selects it
+                let (position, _) =
+                    canvas.screen_to_value([mouse_pos[0] + state.grab_offset, mouse_pos[1]]);
+                if values[index].0 != position {
+                    values[index].0 = position;
+                    state.selected = Some(resort(values, index, |s| s.0));
+                    changed = true;
+                }
+            }
+        }
+
+        state.selected
+    });
+
+    let stops = match values.as_ref() {
+        Some(values) => &values[..],
+        None => stops,
+    };
+    draw_gradient(stops, selected, &canvas, ui);
+
+    ui.same_line(0.0);
+    render_editor_label(label, is_consistent, ui);
+
+    if let (Some(index), Some(values)) = (selected, values) {
+        let id_token = ui.push_id(label);
+        changed |= imgui::ColorEdit::new(imgui::im_str!("Color"), &mut values[index].1).build(ui);
+        ui.text(scratch_im_str!("Position: {:.3}", values[index].0));
+        id_token.pop(ui);
+    }
+
+    changed
+}
+
+fn draw_gradient(
+    stops: &[(f32, [f32; 4])],
+    selected: Option<usize>,
+    canvas: &CurveCanvas,
+    ui: &imgui::Ui,
+) {
+    let draw_list = ui.get_window_draw_list();
+    let bar_min = canvas.min;
+    let bar_max = canvas.max();
+
+    match (stops.first(), stops.last()) {
+        (Some(first), Some(last)) => {
+            // Solid color before the first stop and after the last, and a blend between each pair of stops
+            let first_x = canvas.value_to_screen(first.0, 0.0)[0];
+            let last_x = canvas.value_to_screen(last.0, 0.0)[0];
+            draw_list
+                .add_rect(bar_min, [first_x, bar_max[1]], first.1)
+                .filled(true)
+                .build();
+            for pair in stops.windows(2) {
+                let x0 = canvas.value_to_screen(pair[0].0, 0.0)[0];
+                let x1 = canvas.value_to_screen(pair[1].0, 0.0)[0];
+                draw_list.add_rect_filled_multicolor(
+                    [x0, bar_min[1]],
+                    [x1, bar_max[1]],
+                    pair[0].1,
+                    pair[1].1,
+                    pair[1].1,
+                    pair[0].1,
+                );
+            }
+            draw_list
+                .add_rect([last_x, bar_min[1]], bar_max, last.1)
+                .filled(true)
+                .build();
+        }
+        _ => {
+            draw_list
+                .add_rect(bar_min, bar_max, BACKGROUND_COLOR)
+                .filled(true)
+                .build();
+        }
+    }
+
+    draw_list.add_rect(bar_min, bar_max, BORDER_COLOR).build();
+
+    // A marker under the bar for each stop, filled with the stop's color
+    for (i, stop) in stops.iter().enumerate() {
+        let x = canvas.value_to_screen(stop.0, 0.0)[0];
+        let tip = [x, bar_max[1]];
+        let left = [
+            x - GRADIENT_MARKER_HEIGHT * 0.6,
+            bar_max[1] + GRADIENT_MARKER_HEIGHT,
+        ];
+        let right = [
+            x + GRADIENT_MARKER_HEIGHT * 0.6,
+            bar_max[1] + GRADIENT_MARKER_HEIGHT,
+        ];
+        let outline = if selected == Some(i) {
+            ACTIVE_POINT_COLOR
+        } else {
+            BORDER_COLOR
+        };
+
+        draw_list
+            .add_triangle(tip, left, right, stop.1)
+            .filled(true)
+            .build();
+        draw_list.add_triangle(tip, left, right, outline).build();
+    }
+}
+
+// The label is drawn next to the editor like other widgets, in the "inconsistent" color if the selected values differ
+fn render_editor_label(
+    label: &str,
+    is_consistent: bool,
+    ui: &imgui::Ui,
+) {
+    if is_consistent {
+        ui.text(label);
+    } else {
+        ui.text_colored([1.0, 1.0, 0.0, 1.0], label);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(
+        actual: f32,
+        expected: f32,
+    ) {
+        assert!(
+            (actual - expected).abs() < 1e-5,
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    const POINTS: [(f32, f32); 3] = [(0.0, 0.0), (1.0, 2.0), (3.0, 1.0)];
+
+    #[test]
+    fn empty_curve_has_no_value() {
+        let points: [(f32, f32); 0] = [];
+        assert_eq!(
+            evaluate_curve(&points, 0.5, InspectCurveInterpolation::Linear),
+            None
+        );
+    }
+
+    #[test]
+    fn curve_is_flat_outside_points() {
+        for interpolation in &[
+            InspectCurveInterpolation::Linear,
+            InspectCurveInterpolation::Step,
+            InspectCurveInterpolation::Cubic,
+        ] {
+            assert_eq!(evaluate_curve(&POINTS, -1.0, *interpolation), Some(0.0));
+            assert_eq!(evaluate_curve(&POINTS, 5.0, *interpolation), Some(1.0));
+        }
+    }
+
+    #[test]
+    fn curve_passes_through_points() {
+        for interpolation in &[
+            InspectCurveInterpolation::Linear,
+            InspectCurveInterpolation::Step,
+            InspectCurveInterpolation::Cubic,
+        ] {
+            for (x, y) in &POINTS {
+                assert_close(evaluate_curve(&POINTS, *x, *interpolation).unwrap(), *y);
+            }
+        }
+    }
+
+    #[test]
+    fn linear_interpolates_between_points() {
+        let linear = InspectCurveInterpolation::Linear;
+        assert_close(evaluate_curve(&POINTS, 0.5, linear).unwrap(), 1.0);
+        assert_close(evaluate_curve(&POINTS, 2.0, linear).unwrap(), 1.5);
+    }
+
+    #[test]
+    fn step_holds_previous_point() {
+        let step = InspectCurveInterpolation::Step;
+        assert_close(evaluate_curve(&POINTS, 0.99, step).unwrap(), 0.0);
+        assert_close(evaluate_curve(&POINTS, 2.5, step).unwrap(), 2.0);
+    }
+
+    #[test]
+    fn cubic_keeps_straight_lines_straight() {
+        let points = [[0.0, 0.0], [1.0, 1.0], [3.0, 3.0]];
+        for x in &[0.25, 0.5, 1.5, 2.75] {
+            let y = evaluate_curve(&points, *x, InspectCurveInterpolation::Cubic).unwrap();
+            assert_close(y, *x);
+        }
+    }
+
+    #[test]
+    fn cubic_is_smooth_through_points() {
+        // The slope just before and after a middle point matches the Catmull-Rom tangent, (1.0 - 0.0) / (3.0 - 0.0)
+        let cubic = InspectCurveInterpolation::Cubic;
+        let h = 1e-3;
+        let before = (evaluate_curve(&POINTS, 1.0, cubic).unwrap()
+            - evaluate_curve(&POINTS, 1.0 - h, cubic).unwrap())
+            / h;
+        let after = (evaluate_curve(&POINTS, 1.0 + h, cubic).unwrap()
+            - evaluate_curve(&POINTS, 1.0, cubic).unwrap())
+            / h;
+        assert!((before - 1.0 / 3.0).abs() < 0.01, "{}", before);
+        assert!((after - 1.0 / 3.0).abs() < 0.01, "{}", after);
+    }
+
+    #[test]
+    fn points_with_the_same_x_jump() {
+        let points = [(0.0, 0.0), (1.0, 1.0), (1.0, 5.0), (2.0, 5.0)];
+        let linear = InspectCurveInterpolation::Linear;
+        assert_close(evaluate_curve(&points, 0.5, linear).unwrap(), 0.5);
+        assert_close(evaluate_curve(&points, 1.5, linear).unwrap(), 5.0);
+    }
+
+    const STOPS: [(f32, [f32; 4]); 2] = [(0.2, [0.0, 0.0, 0.0, 1.0]), (0.6, [1.0, 0.5, 0.0, 0.0])];
+
+    #[test]
+    fn gradient_blends_between_stops() {
+        let color = evaluate_gradient(&STOPS, 0.4).unwrap();
+        for (actual, expected) in color.iter().zip(&[0.5, 0.25, 0.0, 0.5]) {
+            assert_close(*actual, *expected);
+        }
+    }
+
+    #[test]
+    fn gradient_is_flat_outside_stops() {
+        assert_eq!(evaluate_gradient(&STOPS, 0.0), Some(STOPS[0].1));
+        assert_eq!(evaluate_gradient(&STOPS, 1.0), Some(STOPS[1].1));
+        assert_eq!(evaluate_gradient(&[], 0.5), None);
+    }
+
+    #[test]
+    fn resort_moves_element_into_place() {
+        let mut values = vec![0.0, 0.5, 0.2, 0.8];
+        assert_eq!(resort(&mut values, 1, |x| *x), 2);
+        assert_eq!(values, [0.0, 0.2, 0.5, 0.8]);
+
+        values[3] = 0.1;
+        assert_eq!(resort(&mut values, 3, |x| *x), 1);
+        assert_eq!(values, [0.0, 0.1, 0.2, 0.5]);
+
+        assert_eq!(resort(&mut values, 2, |x| *x), 2);
+    }
+
+    #[test]
+    fn canvas_maps_values_to_screen_and_back() {
+        let canvas = CurveCanvas {
+            min: [10.0, 20.0],
+            size: [200.0, 100.0],
+            min_value: [0.0, -1.0],
+            max_value: [2.0, 1.0],
+        };
+
+        // y increases upwards in the editor but downwards on screen
+        assert_eq!(canvas.value_to_screen(0.0, 1.0), [10.0, 20.0]);
+        assert_eq!(canvas.value_to_screen(2.0, -1.0), [210.0, 120.0]);
+
+        let pos = canvas.value_to_screen(0.5, 0.25);
+        let (x, y) = canvas.screen_to_value(pos);
+        assert_close(x, 0.5);
+        assert_close(y, 0.25);
+
+        // Positions outside of the editor are clamped to its edges
+        assert_eq!(canvas.screen_to_value([0.0, 500.0]), (0.0, -1.0));
+    }
+}
@@ -10,7 +10,7 @@ impl InspectRenderDefault<bool> for bool {
         if data.is_empty() {
             // Values are inconsistent
             let style_token = ui.push_style_color(imgui::StyleColor::Text, [1.0, 0.0, 0.0, 1.0]);
-            ui.text(&imgui::im_str!("{}: ", label));
+            ui.text(scratch_im_str!("{}: ", label));
             style_token.pop(ui);
             return;
         }
@@ -18,13 +18,13 @@ impl InspectRenderDefault<bool> for bool {
         match get_same_or_none(data) {
             Some(_v) => {
                 // Values are consistent
-                ui.text(&imgui::im_str!("{}: {}", label, data[0]))
+                ui.text(scratch_im_str!("{}: {}", label, data[0]))
             }
             None => {
                 // Values are inconsistent
                 let style_token =
                     ui.push_style_color(imgui::StyleColor::Text, [1.0, 1.0, 0.0, 1.0]);
-                ui.text(&imgui::im_str!("{}: ", label));
+                ui.text(scratch_im_str!("{}: ", label));
                 style_token.pop(ui);
             }
         }
@@ -49,7 +49,7 @@ impl InspectRenderDefault<bool> for bool {
         };
 
         let mut changed = false;
-        if ui.checkbox(&imgui::im_str!("{}", label), &mut value) {
+        if ui.checkbox(&scratch_im_str!("{}", label), &mut value) {
             for d in data {
                 **d = value;
                 changed = true;
@@ -0,0 +1,91 @@
+use super::*;
+use crate::math::{impl_inspect_math, TransformScale, Trs};
+use cgmath::{Decomposed, Matrix3, Matrix4, Point2, Point3, Quaternion, Vector2, Vector3, Vector4};
+
+impl_inspect_math!(
+    vector Vector2<f32>,
+    |x: &Vector2<f32>| -> [f32; 2] { (*x).into() },
+    |x: &mut Vector2<f32>, v: [f32; 2]| *x = v.into()
+);
+impl_inspect_math!(
+    vector Vector3<f32>,
+    |x: &Vector3<f32>| -> [f32; 3] { (*x).into() },
+    |x: &mut Vector3<f32>, v: [f32; 3]| *x = v.into()
+);
+impl_inspect_math!(
+    vector Vector4<f32>,
+    |x: &Vector4<f32>| -> [f32; 4] { (*x).into() },
+    |x: &mut Vector4<f32>, v: [f32; 4]| *x = v.into()
+);
+impl_inspect_math!(
+    vector Point2<f32>,
+    |x: &Point2<f32>| -> [f32; 2] { (*x).into() },
+    |x: &mut Point2<f32>, v: [f32; 2]| *x = v.into()
+);
+impl_inspect_math!(
+    vector Point3<f32>,
+    |x: &Point3<f32>| -> [f32; 3] { (*x).into() },
+    |x: &mut Point3<f32>, v: [f32; 3]| *x = v.into()
+);
+
+fn quaternion_to_array(q: &Quaternion<f32>) -> [f32; 4] {
+    [q.v.x, q.v.y, q.v.z, q.s]
+}
+
+fn array_to_quaternion(q: [f32; 4]) -> Quaternion<f32> {
+    Quaternion::new(q[3], q[0], q[1], q[2])
+}
+
+impl_inspect_math!(
+    rotation Quaternion<f32>,
+    quaternion_to_array,
+    |x: &mut Quaternion<f32>, q| *x = array_to_quaternion(q)
+);
+
+fn matrix3_to_matrix(m: &Matrix3<f32>) -> [[f32; 4]; 4] {
+    let c: [[f32; 3]; 3] = (*m).into();
+    [
+        [c[0][0], c[0][1], c[0][2], 0.0],
+        [c[1][0], c[1][1], c[1][2], 0.0],
+        [c[2][0], c[2][1], c[2][2], 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]
+}
+
+fn matrix_to_matrix3(m: &[[f32; 4]; 4]) -> Matrix3<f32> {
+    Matrix3::from([
+        [m[0][0], m[0][1], m[0][2]],
+        [m[1][0], m[1][1], m[1][2]],
+        [m[2][0], m[2][1], m[2][2]],
+    ])
+}
+
+impl_inspect_math!(
+    matrix Matrix3<f32>,
+    3,
+    matrix3_to_matrix,
+    |x: &mut Matrix3<f32>, m| *x = matrix_to_matrix3(&m)
+);
+impl_inspect_math!(
+    matrix Matrix4<f32>,
+    4,
+    |x: &Matrix4<f32>| -> [[f32; 4]; 4] { (*x).into() },
+    |x: &mut Matrix4<f32>, m: [[f32; 4]; 4]| *x = m.into()
+);
+
+impl_inspect_math!(
+    transform Decomposed<Vector3<f32>, Quaternion<f32>>,
+    TransformScale::Uniform,
+    |x: &Decomposed<Vector3<f32>, Quaternion<f32>>| Trs {
+        translation: x.disp.into(),
+        rotation: quaternion_to_array(&x.rot),
+        scale: [x.scale; 3],
+    },
+    |x: &mut Decomposed<Vector3<f32>, Quaternion<f32>>, trs| {
+        *x = Decomposed {
+            scale: trs.scale[0],
+            rot: array_to_quaternion(trs.rotation),
+            disp: trs.translation.into(),
+        }
+    }
+);
@@ -0,0 +1,34 @@
+use super::*;
+use crate::text_value::{render_text_value, render_text_value_mut};
+
+impl InspectRenderDefault<char> for char {
+    fn render(
+        data: &[&char],
+        label: &'static str,
+        ui: &imgui::Ui,
+        _args: &InspectArgsDefault,
+    ) {
+        render_text_value(data, label, ui, |x| x.to_string());
+    }
+
+    fn render_mut(
+        data: &mut [&mut char],
+        label: &'static str,
+        ui: &imgui::Ui,
+        _args: &InspectArgsDefault,
+    ) -> bool {
+        render_text_value_mut(
+            data,
+            label,
+            ui,
+            |x| x.to_string(),
+            |text| {
+                let mut chars = text.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Ok(c),
+                    _ => Err("Enter a single character".to_string()),
+                }
+            },
+        )
+    }
+}
@@ -0,0 +1,197 @@
+use super::*;
+use crate::widget_state::{widget_state_owner, with_widget_state};
+use std::time::Duration;
+
+const DURATION_UNIT_STATE: &str = "inspect_duration_unit";
+
+// The number of seconds in each unit that a duration can be edited in
+const DURATION_UNITS: [f64; 6] = [1e-9, 1e-6, 1e-3, 1.0, 60.0, 3600.0];
+
+// Names of DURATION_UNITS, for the unit combo box. This is a function because im_str! can't be used in a const.
+fn duration_unit_names() -> [&'static imgui::ImStr; 6] {
+    [
+        imgui::im_str!("ns"),
+        imgui::im_str!("us"),
+        imgui::im_str!("ms"),
+        imgui::im_str!("s"),
+        imgui::im_str!("min"),
+        imgui::im_str!("h"),
+    ]
+}
+
+const SECONDS_UNIT_INDEX: usize = 3;
+
+// Picks the largest unit that the duration is at least one of
+fn default_unit_index(duration: Duration) -> usize {
+    let seconds = duration.as_secs_f64();
+    DURATION_UNITS
+        .iter()
+        .rposition(|scale| seconds >= *scale)
+        .unwrap_or(SECONDS_UNIT_INDEX)
+}
+
+// The duration as a number of the given unit, for editing
+fn duration_to_unit(
+    duration: Duration,
+    unit_index: usize,
+) -> f64 {
+    duration.as_secs_f64() / DURATION_UNITS[unit_index]
+}
+
+// Converts an edited number of the given unit back to a duration. Returns None if it's negative or too large.
+fn duration_from_unit(
+    value: f64,
+    unit_index: usize,
+) -> Option<Duration> {
+    let seconds = value * DURATION_UNITS[unit_index];
+    if seconds.is_finite() && seconds >= 0.0 && seconds < u64::MAX as f64 {
+        Some(Duration::from_secs_f64(seconds))
+    } else {
+        None
+    }
+}
+
+impl InspectRenderDefault<Duration> for Duration {
+    fn render(
+        data: &[&Duration],
+        label: &'static str,
+        ui: &imgui::Ui,
+        _args: &InspectArgsDefault,
+    ) {
+        if data.is_empty() {
+            // Values are inconsistent
+            let style_token = ui.push_style_color(imgui::StyleColor::Text, [1.0, 0.0, 0.0, 1.0]);
+            ui.text(scratch_im_str!("{}: ", label));
+            style_token.pop(ui);
+            return;
+        }
+
+        match get_same_or_none(data) {
+            Some(v) => {
+                // Values are consistent
+                ui.text(scratch_im_str!("{}: {:?}", label, v))
+            }
+            None => {
+                // Values are inconsistent
+                let style_token =
+                    ui.push_style_color(imgui::StyleColor::Text, [1.0, 1.0, 0.0, 1.0]);
+                ui.text(scratch_im_str!("{}: ", label));
+                style_token.pop(ui);
+            }
+        }
+    }
+
+    fn render_mut(
+        data: &mut [&mut Duration],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) -> bool {
+        if data.is_empty() {
+            ui.text(scratch_im_str!("{}: None", label));
+            return false;
+        }
+
+        let same_or_none_value = get_same_or_none_mut(data);
+
+        // The selected unit is remembered so that it doesn't jump around while the value is being dragged
+        let owner = widget_state_owner(&*data[0], ui);
+        with_widget_state(
+            owner,
+            DURATION_UNIT_STATE,
+            |unit_index: &mut Option<usize>| {
+                let unit = unit_index.get_or_insert_with(|| {
+                    default_unit_index(same_or_none_value.unwrap_or_default())
+                });
+
+                // Some reasonable default
+                let mut value = duration_to_unit(same_or_none_value.unwrap_or_default(), *unit);
+
+                let style_token = if same_or_none_value.is_none() {
+                    // If values are inconsistent, push a style
+                    Some(ui.push_style_color(imgui::StyleColor::Text, [1.0, 1.0, 0.0, 1.0]))
+                } else {
+                    None
+                };
+
+                let mut changed = false;
+                let drag_label = scratch_im_str!("##{}", label);
+                let mut drag = imgui::Drag::new(&drag_label)
+                    .range(std::ops::RangeInclusive::new(0.0, f64::MAX))
+                    .display_format(imgui::im_str!("%.3f"));
+                if let Some(step) = args.step {
+                    drag = drag.speed(step);
+                }
+
+                if drag.build(ui, &mut value) {
+                    if let Some(duration) = duration_from_unit(value, *unit) {
+                        for d in data.iter_mut() {
+                            **d = duration;
+                            changed = true;
+                        }
+                    }
+                }
+
+                if let Some(style_token) = style_token {
+                    style_token.pop(ui);
+                }
+
+                // Changing the unit only changes how the value is displayed
+                let unit_names = duration_unit_names();
+                ui.same_line(0.0);
+                ui.set_next_item_width(60.0);
+                imgui::ComboBox::new(&scratch_im_str!("{}", label)).build_simple_string(
+                    ui,
+                    unit,
+                    &unit_names,
+                );
+
+                changed
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_unit_is_largest_whole_unit() {
+        assert_eq!(default_unit_index(Duration::from_nanos(500)), 0);
+        assert_eq!(default_unit_index(Duration::from_micros(20)), 1);
+        assert_eq!(default_unit_index(Duration::from_micros(1500)), 2);
+        assert_eq!(default_unit_index(Duration::from_secs(59)), 3);
+        assert_eq!(default_unit_index(Duration::from_secs(90)), 4);
+        assert_eq!(default_unit_index(Duration::from_secs(7200)), 5);
+    }
+
+    #[test]
+    fn zero_defaults_to_seconds() {
+        assert_eq!(default_unit_index(Duration::ZERO), SECONDS_UNIT_INDEX);
+    }
+
+    #[test]
+    fn unit_conversion_round_trips() {
+        let duration = Duration::from_millis(1500);
+        assert_eq!(duration_to_unit(duration, 2), 1500.0);
+        assert_eq!(duration_to_unit(duration, 3), 1.5);
+        assert_eq!(duration_to_unit(duration, 4), 0.025);
+
+        for unit_index in 0..DURATION_UNITS.len() {
+            let value = duration_to_unit(duration, unit_index);
+            let round_trip = duration_from_unit(value, unit_index).unwrap();
+            let error = round_trip.as_secs_f64() - duration.as_secs_f64();
+            assert!(error.abs() < 1e-9, "unit {}: {:?}", unit_index, round_trip);
+        }
+    }
+
+    #[test]
+    fn invalid_values_are_rejected() {
+        assert_eq!(duration_from_unit(-1.0, 3), None);
+        assert_eq!(duration_from_unit(f64::NAN, 3), None);
+        assert_eq!(duration_from_unit(f64::INFINITY, 0), None);
+        assert_eq!(duration_from_unit(u64::MAX as f64, 5), None);
+        assert_eq!(duration_from_unit(0.0, 5), Some(Duration::ZERO));
+    }
+}
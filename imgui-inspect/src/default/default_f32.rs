@@ -10,7 +10,7 @@ impl InspectRenderDefault<f32> for f32 {
         if data.is_empty() {
             // Values are inconsistent
             let style_token = ui.push_style_color(imgui::StyleColor::Text, [1.0, 0.0, 0.0, 1.0]);
-            ui.text(&imgui::im_str!("{}: ", label));
+            ui.text(scratch_im_str!("{}: ", label));
             style_token.pop(ui);
             return;
         }
@@ -18,13 +18,13 @@ impl InspectRenderDefault<f32> for f32 {
         match get_same_or_none(data) {
             Some(_v) => {
                 // Values are consistent
-                ui.text(&imgui::im_str!("{}: {}", label, data[0]))
+                ui.text(scratch_im_str!("{}: {}", label, data[0]))
             }
             None => {
                 // Values are inconsistent
                 let style_token =
                     ui.push_style_color(imgui::StyleColor::Text, [1.0, 1.0, 0.0, 1.0]);
-                ui.text(&imgui::im_str!("{}: ", label));
+                ui.text(scratch_im_str!("{}: ", label));
                 style_token.pop(ui);
             }
         }
@@ -50,7 +50,7 @@ impl InspectRenderDefault<f32> for f32 {
 
         let mut changed = false;
         if ui
-            .input_float(&imgui::im_str!("{}", label), &mut value)
+            .input_float(&scratch_im_str!("{}", label), &mut value)
             .build()
         {
             for d in data {
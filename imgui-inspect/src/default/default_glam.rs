@@ -0,0 +1,49 @@
+use super::*;
+use crate::math::{impl_inspect_math, matrix_to_trs, trs_to_matrix, TransformScale};
+use glam::{Affine3A, Mat3, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+
+impl_inspect_math!(vector Vec2, |x: &Vec2| x.to_array(), |x: &mut Vec2, v| *x = Vec2::from(v));
+impl_inspect_math!(vector Vec3, |x: &Vec3| x.to_array(), |x: &mut Vec3, v| *x = Vec3::from(v));
+impl_inspect_math!(vector Vec3A, |x: &Vec3A| x.to_array(), |x: &mut Vec3A, v| *x = Vec3A::from(v));
+impl_inspect_math!(vector Vec4, |x: &Vec4| x.to_array(), |x: &mut Vec4, v| *x = Vec4::from(v));
+
+impl_inspect_math!(
+    rotation Quat,
+    |x: &Quat| <[f32; 4]>::from(*x),
+    |x: &mut Quat, q: [f32; 4]| *x = Quat::from_array(q)
+);
+
+fn mat3_to_matrix(m: &Mat3) -> [[f32; 4]; 4] {
+    let c = m.to_cols_array_2d();
+    [
+        [c[0][0], c[0][1], c[0][2], 0.0],
+        [c[1][0], c[1][1], c[1][2], 0.0],
+        [c[2][0], c[2][1], c[2][2], 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]
+}
+
+fn matrix_to_mat3(m: &[[f32; 4]; 4]) -> Mat3 {
+    Mat3::from_cols_array_2d(&[
+        [m[0][0], m[0][1], m[0][2]],
+        [m[1][0], m[1][1], m[1][2]],
+        [m[2][0], m[2][1], m[2][2]],
+    ])
+}
+
+impl_inspect_math!(matrix Mat3, 3, mat3_to_matrix, |x: &mut Mat3, m| *x = matrix_to_mat3(&m));
+impl_inspect_math!(
+    matrix Mat4,
+    4,
+    |x: &Mat4| x.to_cols_array_2d(),
+    |x: &mut Mat4, m| *x = Mat4::from_cols_array_2d(&m)
+);
+
+impl_inspect_math!(
+    transform Affine3A,
+    TransformScale::NonUniform,
+    |x: &Affine3A| matrix_to_trs(&Mat4::from(*x).to_cols_array_2d()),
+    |x: &mut Affine3A, trs| {
+        *x = Affine3A::from_mat4(Mat4::from_cols_array_2d(&trs_to_matrix(&trs)))
+    }
+);
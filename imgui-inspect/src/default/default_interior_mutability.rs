@@ -0,0 +1,164 @@
+use super::*;
+use std::cell::{Cell, Ref, RefCell};
+use std::sync::{Mutex, MutexGuard, RwLock, RwLockReadGuard, TryLockError};
+
+// render_mut receives &mut access, so the wrapped values can always be reached with get_mut() without locking or
+// borrowing. render only has shared access, and the value might be borrowed or locked elsewhere (possibly by the code
+// that is drawing the inspector). In that case an indicator is drawn instead of waiting for it.
+fn render_unavailable(
+    label: &'static str,
+    reason: &'static str,
+    ui: &imgui::Ui,
+) {
+    let style_token = ui.push_style_color(imgui::StyleColor::Text, [1.0, 0.0, 0.0, 1.0]);
+    ui.text(scratch_im_str!("{}: <{}>", label, reason));
+    style_token.pop(ui);
+}
+
+//
+// RefCell<T>
+//
+impl<T: InspectRenderDefault<T>> InspectRenderDefault<RefCell<T>> for RefCell<T> {
+    fn render(
+        data: &[&RefCell<T>],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) {
+        let borrows: Result<Vec<Ref<T>>, _> = data.iter().map(|x| x.try_borrow()).collect();
+        match borrows {
+            Ok(borrows) => {
+                let values: Vec<&T> = borrows.iter().map(|x| &**x).collect();
+                <T as InspectRenderDefault<T>>::render(&values, label, ui, args);
+            }
+            Err(_) => render_unavailable(label, "borrowed elsewhere", ui),
+        }
+    }
+
+    fn render_mut(
+        data: &mut [&mut RefCell<T>],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) -> bool {
+        let mut values: Vec<&mut T> = data.iter_mut().map(|x| x.get_mut()).collect();
+        <T as InspectRenderDefault<T>>::render_mut(&mut values, label, ui, args)
+    }
+}
+
+//
+// Cell<T>
+//
+impl<T: InspectRenderDefault<T> + Copy> InspectRenderDefault<Cell<T>> for Cell<T> {
+    fn render(
+        data: &[&Cell<T>],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) {
+        let copies: Vec<T> = data.iter().map(|x| x.get()).collect();
+        let values: Vec<&T> = copies.iter().collect();
+        <T as InspectRenderDefault<T>>::render(&values, label, ui, args);
+    }
+
+    fn render_mut(
+        data: &mut [&mut Cell<T>],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) -> bool {
+        let mut values: Vec<&mut T> = data.iter_mut().map(|x| x.get_mut()).collect();
+        <T as InspectRenderDefault<T>>::render_mut(&mut values, label, ui, args)
+    }
+}
+
+//
+// Mutex<T>
+//
+impl<T: InspectRenderDefault<T>> InspectRenderDefault<Mutex<T>> for Mutex<T> {
+    fn render(
+        data: &[&Mutex<T>],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) {
+        // A poisoned mutex still holds a value that is worth looking at
+        let guards: Option<Vec<MutexGuard<T>>> = data
+            .iter()
+            .map(|x| match x.try_lock() {
+                Ok(guard) => Some(guard),
+                Err(TryLockError::Poisoned(poisoned)) => Some(poisoned.into_inner()),
+                Err(TryLockError::WouldBlock) => None,
+            })
+            .collect();
+
+        match guards {
+            Some(guards) => {
+                let values: Vec<&T> = guards.iter().map(|x| &**x).collect();
+                <T as InspectRenderDefault<T>>::render(&values, label, ui, args);
+            }
+            None => render_unavailable(label, "locked elsewhere", ui),
+        }
+    }
+
+    fn render_mut(
+        data: &mut [&mut Mutex<T>],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) -> bool {
+        let mut values: Vec<&mut T> = data
+            .iter_mut()
+            .map(|x| match x.get_mut() {
+                Ok(value) => value,
+                Err(poisoned) => poisoned.into_inner(),
+            })
+            .collect();
+        <T as InspectRenderDefault<T>>::render_mut(&mut values, label, ui, args)
+    }
+}
+
+//
+// RwLock<T>
+//
+impl<T: InspectRenderDefault<T>> InspectRenderDefault<RwLock<T>> for RwLock<T> {
+    fn render(
+        data: &[&RwLock<T>],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) {
+        let guards: Option<Vec<RwLockReadGuard<T>>> = data
+            .iter()
+            .map(|x| match x.try_read() {
+                Ok(guard) => Some(guard),
+                Err(TryLockError::Poisoned(poisoned)) => Some(poisoned.into_inner()),
+                Err(TryLockError::WouldBlock) => None,
+            })
+            .collect();
+
+        match guards {
+            Some(guards) => {
+                let values: Vec<&T> = guards.iter().map(|x| &**x).collect();
+                <T as InspectRenderDefault<T>>::render(&values, label, ui, args);
+            }
+            None => render_unavailable(label, "locked elsewhere", ui),
+        }
+    }
+
+    fn render_mut(
+        data: &mut [&mut RwLock<T>],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) -> bool {
+        let mut values: Vec<&mut T> = data
+            .iter_mut()
+            .map(|x| match x.get_mut() {
+                Ok(value) => value,
+                Err(poisoned) => poisoned.into_inner(),
+            })
+            .collect();
+        <T as InspectRenderDefault<T>>::render_mut(&mut values, label, ui, args)
+    }
+}
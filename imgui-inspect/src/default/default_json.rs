@@ -0,0 +1,172 @@
+use super::*;
+use crate::collection::{InspectMapLike, InspectMapValues};
+use crate::document::{render_document, render_document_mut, DocumentKind, DocumentValue};
+use serde_json::{Map, Number, Value};
+
+impl InspectMapLike for Map<String, Value> {
+    type Key = String;
+
+    fn keys(&self) -> Vec<String> {
+        self.keys().cloned().collect()
+    }
+
+    fn contains_key(
+        &self,
+        key: &String,
+    ) -> bool {
+        self.contains_key(key)
+    }
+
+    fn insert_default(
+        &mut self,
+        key: String,
+    ) {
+        self.insert(key, Value::Null);
+    }
+
+    fn rename_key(
+        &mut self,
+        old_key: &String,
+        new_key: String,
+    ) {
+        if let Some(value) = self.remove(old_key) {
+            self.insert(new_key, value);
+        }
+    }
+
+    fn remove_key(
+        &mut self,
+        key: &String,
+    ) {
+        self.remove(key);
+    }
+}
+
+impl InspectMapValues for Map<String, Value> {
+    type Value = Value;
+
+    fn get_value(
+        &self,
+        key: &String,
+    ) -> Option<&Value> {
+        self.get(key)
+    }
+
+    fn get_value_mut(
+        &mut self,
+        key: &String,
+    ) -> Option<&mut Value> {
+        self.get_mut(key)
+    }
+}
+
+impl DocumentValue for Value {
+    type Object = Map<String, Value>;
+
+    const KINDS: &'static [(DocumentKind, &'static str)] = &[
+        (DocumentKind::Null, "null"),
+        (DocumentKind::Bool, "bool"),
+        (DocumentKind::Number, "number"),
+        (DocumentKind::String, "string"),
+        (DocumentKind::Array, "array"),
+        (DocumentKind::Object, "object"),
+    ];
+
+    fn kind(&self) -> DocumentKind {
+        match self {
+            Value::Null => DocumentKind::Null,
+            Value::Bool(_) => DocumentKind::Bool,
+            Value::Number(_) => DocumentKind::Number,
+            Value::String(_) => DocumentKind::String,
+            Value::Array(_) => DocumentKind::Array,
+            Value::Object(_) => DocumentKind::Object,
+        }
+    }
+
+    fn empty_of_kind(kind: DocumentKind) -> Self {
+        match kind {
+            DocumentKind::Bool => Value::Bool(false),
+            DocumentKind::Number | DocumentKind::Integer | DocumentKind::Float => Value::from(0),
+            DocumentKind::String | DocumentKind::Datetime => Value::String(String::new()),
+            DocumentKind::Array => Value::Array(vec![]),
+            DocumentKind::Object => Value::Object(Map::new()),
+            DocumentKind::Null => Value::Null,
+        }
+    }
+
+    fn as_bool(&self) -> Option<bool> {
+        self.as_bool()
+    }
+
+    fn as_i64(&self) -> Option<i64> {
+        self.as_i64()
+    }
+
+    fn as_u64(&self) -> Option<u64> {
+        self.as_u64()
+    }
+
+    fn as_f64(&self) -> Option<f64> {
+        self.as_f64()
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        self.as_str()
+    }
+
+    fn as_array(&self) -> Option<&Vec<Self>> {
+        self.as_array()
+    }
+
+    fn as_array_mut(&mut self) -> Option<&mut Vec<Self>> {
+        self.as_array_mut()
+    }
+
+    fn as_object(&self) -> Option<&Map<String, Value>> {
+        self.as_object()
+    }
+
+    fn as_object_mut(&mut self) -> Option<&mut Map<String, Value>> {
+        self.as_object_mut()
+    }
+
+    fn from_bool(value: bool) -> Self {
+        Value::Bool(value)
+    }
+
+    fn from_i64(value: i64) -> Self {
+        Value::from(value)
+    }
+
+    fn from_u64(value: u64) -> Self {
+        Value::from(value)
+    }
+
+    fn from_f64(value: f64) -> Option<Self> {
+        Number::from_f64(value).map(Value::Number)
+    }
+
+    fn from_string(value: String) -> Self {
+        Value::String(value)
+    }
+}
+
+impl InspectRenderDefault<Value> for Value {
+    fn render(
+        data: &[&Value],
+        label: &'static str,
+        ui: &imgui::Ui,
+        _args: &InspectArgsDefault,
+    ) {
+        render_document(data, label, ui);
+    }
+
+    fn render_mut(
+        data: &mut [&mut Value],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) -> bool {
+        render_document_mut(data, label, ui, args, true)
+    }
+}
@@ -0,0 +1,314 @@
+use super::*;
+use crate::collection::{
+    render_map, render_map_mut, render_map_values, render_map_values_mut, InspectMapLike,
+    InspectMapValues,
+};
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
+
+//
+// HashMap
+//
+impl<K, V, S> InspectMapLike for HashMap<K, V, S>
+where
+    K: InspectRenderDefault<K> + Clone + Default + Ord + Hash + 'static,
+    V: Default,
+    S: BuildHasher,
+{
+    type Key = K;
+
+    fn keys(&self) -> Vec<K> {
+        self.keys().cloned().collect()
+    }
+
+    // Iteration order of a HashMap is arbitrary, so sort to keep the display stable
+    fn sort_keys(keys: &mut Vec<K>) {
+        keys.sort();
+    }
+
+    fn contains_key(
+        &self,
+        key: &K,
+    ) -> bool {
+        self.contains_key(key)
+    }
+
+    fn insert_default(
+        &mut self,
+        key: K,
+    ) {
+        self.insert(key, V::default());
+    }
+
+    fn rename_key(
+        &mut self,
+        old_key: &K,
+        new_key: K,
+    ) {
+        if let Some(value) = self.remove(old_key) {
+            self.insert(new_key, value);
+        }
+    }
+
+    fn remove_key(
+        &mut self,
+        key: &K,
+    ) {
+        self.remove(key);
+    }
+}
+
+impl<K, V, S> InspectMapValues for HashMap<K, V, S>
+where
+    K: InspectRenderDefault<K> + Clone + Default + Ord + Hash + 'static,
+    V: InspectRenderDefault<V> + Default,
+    S: BuildHasher,
+{
+    type Value = V;
+
+    fn get_value(
+        &self,
+        key: &K,
+    ) -> Option<&V> {
+        self.get(key)
+    }
+
+    fn get_value_mut(
+        &mut self,
+        key: &K,
+    ) -> Option<&mut V> {
+        self.get_mut(key)
+    }
+}
+
+impl<K, V, S> InspectRenderDefault<HashMap<K, V, S>> for HashMap<K, V, S>
+where
+    K: InspectRenderDefault<K> + Clone + Default + Ord + Hash + 'static,
+    V: InspectRenderDefault<V> + Default,
+    S: BuildHasher,
+{
+    fn render(
+        data: &[&HashMap<K, V, S>],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) {
+        render_map(data, label, ui, args, |data, key| {
+            render_map_values(data, key, ui, args)
+        });
+    }
+
+    fn render_mut(
+        data: &mut [&mut HashMap<K, V, S>],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) -> bool {
+        render_map_mut(data, label, ui, args, |data, key| {
+            render_map_values_mut(data, key, ui, args)
+        })
+    }
+}
+
+//
+// BTreeMap
+//
+impl<K, V> InspectMapLike for BTreeMap<K, V>
+where
+    K: InspectRenderDefault<K> + Clone + Default + Ord + 'static,
+    V: Default,
+{
+    type Key = K;
+
+    fn keys(&self) -> Vec<K> {
+        self.keys().cloned().collect()
+    }
+
+    // Keys from a single map are already sorted, but the union of several maps may not be
+    fn sort_keys(keys: &mut Vec<K>) {
+        keys.sort();
+    }
+
+    fn contains_key(
+        &self,
+        key: &K,
+    ) -> bool {
+        self.contains_key(key)
+    }
+
+    fn insert_default(
+        &mut self,
+        key: K,
+    ) {
+        self.insert(key, V::default());
+    }
+
+    fn rename_key(
+        &mut self,
+        old_key: &K,
+        new_key: K,
+    ) {
+        if let Some(value) = self.remove(old_key) {
+            self.insert(new_key, value);
+        }
+    }
+
+    fn remove_key(
+        &mut self,
+        key: &K,
+    ) {
+        self.remove(key);
+    }
+}
+
+impl<K, V> InspectMapValues for BTreeMap<K, V>
+where
+    K: InspectRenderDefault<K> + Clone + Default + Ord + 'static,
+    V: InspectRenderDefault<V> + Default,
+{
+    type Value = V;
+
+    fn get_value(
+        &self,
+        key: &K,
+    ) -> Option<&V> {
+        self.get(key)
+    }
+
+    fn get_value_mut(
+        &mut self,
+        key: &K,
+    ) -> Option<&mut V> {
+        self.get_mut(key)
+    }
+}
+
+impl<K, V> InspectRenderDefault<BTreeMap<K, V>> for BTreeMap<K, V>
+where
+    K: InspectRenderDefault<K> + Clone + Default + Ord + 'static,
+    V: InspectRenderDefault<V> + Default,
+{
+    fn render(
+        data: &[&BTreeMap<K, V>],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) {
+        render_map(data, label, ui, args, |data, key| {
+            render_map_values(data, key, ui, args)
+        });
+    }
+
+    fn render_mut(
+        data: &mut [&mut BTreeMap<K, V>],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) -> bool {
+        render_map_mut(data, label, ui, args, |data, key| {
+            render_map_values_mut(data, key, ui, args)
+        })
+    }
+}
+
+//
+// IndexMap (keeps insertion order, so keys are not sorted)
+//
+#[cfg(feature = "indexmap")]
+impl<K, V, S> InspectMapLike for indexmap::IndexMap<K, V, S>
+where
+    K: InspectRenderDefault<K> + Clone + Default + Ord + Hash + 'static,
+    V: Default,
+    S: BuildHasher,
+{
+    type Key = K;
+
+    fn keys(&self) -> Vec<K> {
+        self.keys().cloned().collect()
+    }
+
+    fn contains_key(
+        &self,
+        key: &K,
+    ) -> bool {
+        self.contains_key(key)
+    }
+
+    fn insert_default(
+        &mut self,
+        key: K,
+    ) {
+        self.insert(key, V::default());
+    }
+
+    fn rename_key(
+        &mut self,
+        old_key: &K,
+        new_key: K,
+    ) {
+        if let Some(value) = self.shift_remove(old_key) {
+            self.insert(new_key, value);
+        }
+    }
+
+    fn remove_key(
+        &mut self,
+        key: &K,
+    ) {
+        self.shift_remove(key);
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K, V, S> InspectMapValues for indexmap::IndexMap<K, V, S>
+where
+    K: InspectRenderDefault<K> + Clone + Default + Ord + Hash + 'static,
+    V: InspectRenderDefault<V> + Default,
+    S: BuildHasher,
+{
+    type Value = V;
+
+    fn get_value(
+        &self,
+        key: &K,
+    ) -> Option<&V> {
+        self.get(key)
+    }
+
+    fn get_value_mut(
+        &mut self,
+        key: &K,
+    ) -> Option<&mut V> {
+        self.get_mut(key)
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K, V, S> InspectRenderDefault<indexmap::IndexMap<K, V, S>> for indexmap::IndexMap<K, V, S>
+where
+    K: InspectRenderDefault<K> + Clone + Default + Ord + Hash + 'static,
+    V: InspectRenderDefault<V> + Default,
+    S: BuildHasher,
+{
+    fn render(
+        data: &[&indexmap::IndexMap<K, V, S>],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) {
+        render_map(data, label, ui, args, |data, key| {
+            render_map_values(data, key, ui, args)
+        });
+    }
+
+    fn render_mut(
+        data: &mut [&mut indexmap::IndexMap<K, V, S>],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) -> bool {
+        render_map_mut(data, label, ui, args, |data, key| {
+            render_map_values_mut(data, key, ui, args)
+        })
+    }
+}
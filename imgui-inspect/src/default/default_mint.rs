@@ -0,0 +1,69 @@
+use super::*;
+use crate::math::impl_inspect_math;
+use mint::{ColumnMatrix3, ColumnMatrix4, Point2, Point3, Quaternion, Vector2, Vector3, Vector4};
+
+// mint only provides types for passing values between crates, so there are no transform types
+
+impl_inspect_math!(
+    vector Vector2<f32>,
+    |x: &Vector2<f32>| -> [f32; 2] { (*x).into() },
+    |x: &mut Vector2<f32>, v: [f32; 2]| *x = v.into()
+);
+impl_inspect_math!(
+    vector Vector3<f32>,
+    |x: &Vector3<f32>| -> [f32; 3] { (*x).into() },
+    |x: &mut Vector3<f32>, v: [f32; 3]| *x = v.into()
+);
+impl_inspect_math!(
+    vector Vector4<f32>,
+    |x: &Vector4<f32>| -> [f32; 4] { (*x).into() },
+    |x: &mut Vector4<f32>, v: [f32; 4]| *x = v.into()
+);
+impl_inspect_math!(
+    vector Point2<f32>,
+    |x: &Point2<f32>| -> [f32; 2] { (*x).into() },
+    |x: &mut Point2<f32>, v: [f32; 2]| *x = v.into()
+);
+impl_inspect_math!(
+    vector Point3<f32>,
+    |x: &Point3<f32>| -> [f32; 3] { (*x).into() },
+    |x: &mut Point3<f32>, v: [f32; 3]| *x = v.into()
+);
+
+impl_inspect_math!(
+    rotation Quaternion<f32>,
+    |x: &Quaternion<f32>| [x.v.x, x.v.y, x.v.z, x.s],
+    |x: &mut Quaternion<f32>, q: [f32; 4]| {
+        *x = Quaternion {
+            v: Vector3::from([q[0], q[1], q[2]]),
+            s: q[3],
+        }
+    }
+);
+
+impl_inspect_math!(
+    matrix ColumnMatrix3<f32>,
+    3,
+    |x: &ColumnMatrix3<f32>| {
+        let c: [[f32; 3]; 3] = (*x).into();
+        [
+            [c[0][0], c[0][1], c[0][2], 0.0],
+            [c[1][0], c[1][1], c[1][2], 0.0],
+            [c[2][0], c[2][1], c[2][2], 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]
+    },
+    |x: &mut ColumnMatrix3<f32>, m: [[f32; 4]; 4]| {
+        *x = ColumnMatrix3::from([
+            [m[0][0], m[0][1], m[0][2]],
+            [m[1][0], m[1][1], m[1][2]],
+            [m[2][0], m[2][1], m[2][2]],
+        ])
+    }
+);
+impl_inspect_math!(
+    matrix ColumnMatrix4<f32>,
+    4,
+    |x: &ColumnMatrix4<f32>| -> [[f32; 4]; 4] { (*x).into() },
+    |x: &mut ColumnMatrix4<f32>, m: [[f32; 4]; 4]| *x = m.into()
+);
@@ -0,0 +1,108 @@
+use super::*;
+use crate::math::{impl_inspect_math, matrix_to_trs, trs_to_matrix, TransformScale, Trs};
+use nalgebra::{
+    Affine3, Isometry3, Matrix3, Matrix4, Point2, Point3, Quaternion, Similarity3, Translation3,
+    UnitQuaternion, Vector2, Vector3, Vector4,
+};
+
+impl_inspect_math!(
+    vector Vector2<f32>,
+    |x: &Vector2<f32>| -> [f32; 2] { (*x).into() },
+    |x: &mut Vector2<f32>, v: [f32; 2]| *x = v.into()
+);
+impl_inspect_math!(
+    vector Vector3<f32>,
+    |x: &Vector3<f32>| -> [f32; 3] { (*x).into() },
+    |x: &mut Vector3<f32>, v: [f32; 3]| *x = v.into()
+);
+impl_inspect_math!(
+    vector Vector4<f32>,
+    |x: &Vector4<f32>| -> [f32; 4] { (*x).into() },
+    |x: &mut Vector4<f32>, v: [f32; 4]| *x = v.into()
+);
+impl_inspect_math!(
+    vector Point2<f32>,
+    |x: &Point2<f32>| -> [f32; 2] { x.coords.into() },
+    |x: &mut Point2<f32>, v: [f32; 2]| *x = v.into()
+);
+impl_inspect_math!(
+    vector Point3<f32>,
+    |x: &Point3<f32>| -> [f32; 3] { x.coords.into() },
+    |x: &mut Point3<f32>, v: [f32; 3]| *x = v.into()
+);
+
+fn unit_quaternion_to_array(q: &UnitQuaternion<f32>) -> [f32; 4] {
+    q.coords.into()
+}
+
+fn array_to_unit_quaternion(q: [f32; 4]) -> UnitQuaternion<f32> {
+    UnitQuaternion::new_normalize(Quaternion::new(q[3], q[0], q[1], q[2]))
+}
+
+impl_inspect_math!(
+    rotation UnitQuaternion<f32>,
+    unit_quaternion_to_array,
+    |x: &mut UnitQuaternion<f32>, q| *x = array_to_unit_quaternion(q)
+);
+
+impl_inspect_math!(
+    matrix Matrix3<f32>,
+    3,
+    |x: &Matrix3<f32>| x.to_homogeneous().into(),
+    |x: &mut Matrix3<f32>, m: [[f32; 4]; 4]| *x = Matrix4::from(m).fixed_slice::<3, 3>(0, 0).into()
+);
+impl_inspect_math!(
+    matrix Matrix4<f32>,
+    4,
+    |x: &Matrix4<f32>| (*x).into(),
+    |x: &mut Matrix4<f32>, m: [[f32; 4]; 4]| *x = m.into()
+);
+
+fn isometry_to_trs(x: &Isometry3<f32>) -> Trs {
+    Trs {
+        translation: x.translation.vector.into(),
+        rotation: unit_quaternion_to_array(&x.rotation),
+        ..Default::default()
+    }
+}
+
+fn trs_to_isometry(trs: &Trs) -> Isometry3<f32> {
+    Isometry3::from_parts(
+        Translation3::from(trs.translation),
+        array_to_unit_quaternion(trs.rotation),
+    )
+}
+
+impl_inspect_math!(
+    transform Isometry3<f32>,
+    TransformScale::None,
+    isometry_to_trs,
+    |x: &mut Isometry3<f32>, trs| *x = trs_to_isometry(&trs)
+);
+
+impl_inspect_math!(
+    transform Similarity3<f32>,
+    TransformScale::Uniform,
+    |x: &Similarity3<f32>| Trs {
+        scale: [x.scaling(); 3],
+        ..isometry_to_trs(&x.isometry)
+    },
+    |x: &mut Similarity3<f32>, trs| {
+        // A similarity can't have a scale of zero
+        let scaling = if trs.scale[0] != 0.0 {
+            trs.scale[0]
+        } else {
+            x.scaling()
+        };
+        *x = Similarity3::from_isometry(trs_to_isometry(&trs), scaling)
+    }
+);
+
+impl_inspect_math!(
+    transform Affine3<f32>,
+    TransformScale::NonUniform,
+    |x: &Affine3<f32>| matrix_to_trs(&x.to_homogeneous().into()),
+    |x: &mut Affine3<f32>, trs| {
+        *x = Affine3::from_matrix_unchecked(trs_to_matrix(&trs).into())
+    }
+);
@@ -0,0 +1,41 @@
+use super::*;
+use crate::text_value::{render_text_value, render_text_value_mut};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+// Addresses are edited as text and only written once they parse
+macro_rules! impl_inspect_net {
+    ($t:ty) => {
+        impl InspectRenderDefault<$t> for $t {
+            fn render(
+                data: &[&$t],
+                label: &'static str,
+                ui: &imgui::Ui,
+                _args: &InspectArgsDefault,
+            ) {
+                render_text_value(data, label, ui, |x| x.to_string());
+            }
+
+            fn render_mut(
+                data: &mut [&mut $t],
+                label: &'static str,
+                ui: &imgui::Ui,
+                _args: &InspectArgsDefault,
+            ) -> bool {
+                render_text_value_mut(
+                    data,
+                    label,
+                    ui,
+                    |x| x.to_string(),
+                    |text| text.trim().parse::<$t>().map_err(|e| e.to_string()),
+                )
+            }
+        }
+    };
+}
+
+impl_inspect_net!(IpAddr);
+impl_inspect_net!(Ipv4Addr);
+impl_inspect_net!(Ipv6Addr);
+impl_inspect_net!(SocketAddr);
+impl_inspect_net!(SocketAddrV4);
+impl_inspect_net!(SocketAddrV6);
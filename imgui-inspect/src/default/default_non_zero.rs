@@ -0,0 +1,51 @@
+use super::*;
+use crate::text_value::{render_text_value, render_text_value_mut};
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+// Non-zero integers are edited as text so that the full range of every width can be entered. Zero (or anything else
+// that doesn't parse) is rejected and the old value is kept.
+macro_rules! impl_inspect_non_zero {
+    ($t:ty) => {
+        impl InspectRenderDefault<$t> for $t {
+            fn render(
+                data: &[&$t],
+                label: &'static str,
+                ui: &imgui::Ui,
+                _args: &InspectArgsDefault,
+            ) {
+                render_text_value(data, label, ui, |x| x.to_string());
+            }
+
+            fn render_mut(
+                data: &mut [&mut $t],
+                label: &'static str,
+                ui: &imgui::Ui,
+                _args: &InspectArgsDefault,
+            ) -> bool {
+                render_text_value_mut(
+                    data,
+                    label,
+                    ui,
+                    |x| x.to_string(),
+                    |text| text.trim().parse::<$t>().map_err(|e| e.to_string()),
+                )
+            }
+        }
+    };
+}
+
+impl_inspect_non_zero!(NonZeroU8);
+impl_inspect_non_zero!(NonZeroU16);
+impl_inspect_non_zero!(NonZeroU32);
+impl_inspect_non_zero!(NonZeroU64);
+impl_inspect_non_zero!(NonZeroU128);
+impl_inspect_non_zero!(NonZeroUsize);
+impl_inspect_non_zero!(NonZeroI8);
+impl_inspect_non_zero!(NonZeroI16);
+impl_inspect_non_zero!(NonZeroI32);
+impl_inspect_non_zero!(NonZeroI64);
+impl_inspect_non_zero!(NonZeroI128);
+impl_inspect_non_zero!(NonZeroIsize);
@@ -0,0 +1,59 @@
+use super::*;
+use crate::text_value::{render_text_value, render_text_value_mut};
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+// Paths and OS strings aren't necessarily valid UTF-8, so they are displayed lossily. A value that isn't valid UTF-8
+// is only replaced if the text is edited.
+
+impl InspectRenderDefault<PathBuf> for PathBuf {
+    fn render(
+        data: &[&PathBuf],
+        label: &'static str,
+        ui: &imgui::Ui,
+        _args: &InspectArgsDefault,
+    ) {
+        render_text_value(data, label, ui, |x| x.to_string_lossy().into_owned());
+    }
+
+    fn render_mut(
+        data: &mut [&mut PathBuf],
+        label: &'static str,
+        ui: &imgui::Ui,
+        _args: &InspectArgsDefault,
+    ) -> bool {
+        render_text_value_mut(
+            data,
+            label,
+            ui,
+            |x| x.to_string_lossy().into_owned(),
+            |text| Ok(PathBuf::from(text)),
+        )
+    }
+}
+
+impl InspectRenderDefault<OsString> for OsString {
+    fn render(
+        data: &[&OsString],
+        label: &'static str,
+        ui: &imgui::Ui,
+        _args: &InspectArgsDefault,
+    ) {
+        render_text_value(data, label, ui, |x| x.to_string_lossy().into_owned());
+    }
+
+    fn render_mut(
+        data: &mut [&mut OsString],
+        label: &'static str,
+        ui: &imgui::Ui,
+        _args: &InspectArgsDefault,
+    ) -> bool {
+        render_text_value_mut(
+            data,
+            label,
+            ui,
+            |x| x.to_string_lossy().into_owned(),
+            |text| Ok(OsString::from(text)),
+        )
+    }
+}
@@ -0,0 +1,147 @@
+use super::*;
+use std::convert::TryFrom;
+use std::ops::{Range, RangeInclusive};
+
+// Ranges are drawn as a single widget with a handle for each end. imgui only has range widgets for f32 and i32, so
+// unsigned integer types are clamped to 0..=i32::MAX. Ends past i32::MAX are shown as i32::MAX, and are only written
+// back (clamped) if the range is edited.
+macro_rules! impl_inspect_range {
+    ($t:ty, $widget_t:ty, $min:expr, $max:expr, $to_widget:expr, $from_widget:expr) => {
+        impl_inspect_range!(
+            Range<$t>,
+            $t,
+            $widget_t,
+            $min,
+            $max,
+            $to_widget,
+            $from_widget,
+            |x: &Range<$t>| (x.start, x.end),
+            |start, end| start..end
+        );
+        impl_inspect_range!(
+            RangeInclusive<$t>,
+            $t,
+            $widget_t,
+            $min,
+            $max,
+            $to_widget,
+            $from_widget,
+            |x: &RangeInclusive<$t>| (*x.start(), *x.end()),
+            |start, end| start..=end
+        );
+    };
+    (
+        $range_t:ty,
+        $t:ty,
+        $widget_t:ty,
+        $min:expr,
+        $max:expr,
+        $to_widget:expr,
+        $from_widget:expr,
+        $get:expr,
+        $make:expr
+    ) => {
+        impl InspectRenderDefault<$range_t> for $range_t {
+            fn render(
+                data: &[&$range_t],
+                label: &'static str,
+                ui: &imgui::Ui,
+                _args: &InspectArgsDefault,
+            ) {
+                if data.is_empty() {
+                    // Values are inconsistent
+                    let style_token =
+                        ui.push_style_color(imgui::StyleColor::Text, [1.0, 0.0, 0.0, 1.0]);
+                    ui.text(&scratch_im_str!("{}: ", label));
+                    style_token.pop(ui);
+                    return;
+                }
+
+                match get_same_or_none(data) {
+                    Some(v) => {
+                        // Values are consistent
+                        ui.text(&scratch_im_str!("{}: {:?}", label, v))
+                    }
+                    None => {
+                        // Values are inconsistent
+                        let style_token =
+                            ui.push_style_color(imgui::StyleColor::Text, [1.0, 1.0, 0.0, 1.0]);
+                        ui.text(&scratch_im_str!("{}: ", label));
+                        style_token.pop(ui);
+                    }
+                }
+            }
+
+            fn render_mut(
+                data: &mut [&mut $range_t],
+                label: &'static str,
+                ui: &imgui::Ui,
+                args: &InspectArgsDefault,
+            ) -> bool {
+                let same_or_none_value = get_same_or_none_mut(data);
+
+                // Some reasonable default
+                let (start, end) = same_or_none_value.as_ref().map($get).unwrap_or_default();
+
+                let mut start: $widget_t = ($to_widget)(start);
+                let mut end: $widget_t = ($to_widget)(end);
+
+                let style_token = if same_or_none_value.is_none() {
+                    // If values are inconsistent, push a style
+                    Some(ui.push_style_color(imgui::StyleColor::Text, [1.0, 1.0, 0.0, 1.0]))
+                } else {
+                    None
+                };
+
+                // CAST
+                let min = args
+                    .min_value
+                    .map(|x| (x as $widget_t).max($min))
+                    .unwrap_or($min);
+                let max = args
+                    .max_value
+                    .map(|x| (x as $widget_t).min($max))
+                    .unwrap_or($max);
+
+                let widget_label = scratch_im_str!("{}", label);
+                let mut range_widget = imgui::DragRange::<$widget_t>::new(&widget_label)
+                    .range(RangeInclusive::new(min, max));
+                if let Some(step) = args.step {
+                    range_widget = range_widget.speed(step);
+                }
+
+                let mut changed = false;
+                if range_widget.build(ui, &mut start, &mut end) {
+                    for d in data {
+                        **d = ($make)(($from_widget)(start), ($from_widget)(end));
+                        changed = true;
+                    }
+                }
+
+                if let Some(style_token) = style_token {
+                    style_token.pop(ui);
+                }
+
+                changed
+            }
+        }
+    };
+}
+
+impl_inspect_range!(f32, f32, f32::MIN, f32::MAX, |x| x, |x| x);
+impl_inspect_range!(
+    u32,
+    i32,
+    0,
+    i32::MAX,
+    |x: u32| i32::try_from(x).unwrap_or(i32::MAX),
+    |x: i32| x.max(0) as u32
+);
+impl_inspect_range!(
+    usize,
+    i32,
+    0,
+    i32::MAX,
+    |x: usize| i32::try_from(x).unwrap_or(i32::MAX),
+    |x: i32| x.max(0) as usize
+);
@@ -0,0 +1,152 @@
+use super::*;
+use crate::collection::{render_map, render_map_mut, InspectMapLike};
+use std::collections::{BTreeSet, HashSet};
+use std::hash::{BuildHasher, Hash};
+
+//
+// HashSet
+//
+impl<T, S> InspectMapLike for HashSet<T, S>
+where
+    T: InspectRenderDefault<T> + Clone + Default + Ord + Hash + 'static,
+    S: BuildHasher,
+{
+    type Key = T;
+
+    fn keys(&self) -> Vec<T> {
+        self.iter().cloned().collect()
+    }
+
+    // Iteration order of a HashSet is arbitrary, so sort to keep the display stable
+    fn sort_keys(keys: &mut Vec<T>) {
+        keys.sort();
+    }
+
+    fn contains_key(
+        &self,
+        key: &T,
+    ) -> bool {
+        self.contains(key)
+    }
+
+    fn insert_default(
+        &mut self,
+        key: T,
+    ) {
+        self.insert(key);
+    }
+
+    fn rename_key(
+        &mut self,
+        old_key: &T,
+        new_key: T,
+    ) {
+        if self.remove(old_key) {
+            self.insert(new_key);
+        }
+    }
+
+    fn remove_key(
+        &mut self,
+        key: &T,
+    ) {
+        self.remove(key);
+    }
+}
+
+impl<T, S> InspectRenderDefault<HashSet<T, S>> for HashSet<T, S>
+where
+    T: InspectRenderDefault<T> + Clone + Default + Ord + Hash + 'static,
+    S: BuildHasher,
+{
+    fn render(
+        data: &[&HashSet<T, S>],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) {
+        render_map(data, label, ui, args, |_, _| {});
+    }
+
+    fn render_mut(
+        data: &mut [&mut HashSet<T, S>],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) -> bool {
+        render_map_mut(data, label, ui, args, |_, _| false)
+    }
+}
+
+//
+// BTreeSet
+//
+impl<T> InspectMapLike for BTreeSet<T>
+where
+    T: InspectRenderDefault<T> + Clone + Default + Ord + 'static,
+{
+    type Key = T;
+
+    fn keys(&self) -> Vec<T> {
+        self.iter().cloned().collect()
+    }
+
+    // Elements from a single set are already sorted, but the union of several sets may not be
+    fn sort_keys(keys: &mut Vec<T>) {
+        keys.sort();
+    }
+
+    fn contains_key(
+        &self,
+        key: &T,
+    ) -> bool {
+        self.contains(key)
+    }
+
+    fn insert_default(
+        &mut self,
+        key: T,
+    ) {
+        self.insert(key);
+    }
+
+    fn rename_key(
+        &mut self,
+        old_key: &T,
+        new_key: T,
+    ) {
+        if self.remove(old_key) {
+            self.insert(new_key);
+        }
+    }
+
+    fn remove_key(
+        &mut self,
+        key: &T,
+    ) {
+        self.remove(key);
+    }
+}
+
+impl<T> InspectRenderDefault<BTreeSet<T>> for BTreeSet<T>
+where
+    T: InspectRenderDefault<T> + Clone + Default + Ord + 'static,
+{
+    fn render(
+        data: &[&BTreeSet<T>],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) {
+        render_map(data, label, ui, args, |_, _| {});
+    }
+
+    fn render_mut(
+        data: &mut [&mut BTreeSet<T>],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) -> bool {
+        render_map_mut(data, label, ui, args, |_, _| false)
+    }
+}
@@ -0,0 +1,202 @@
+use super::*;
+use std::borrow::Cow;
+use std::rc::Rc;
+use std::sync::Arc;
+
+//
+// Box<T> is drawn exactly like T
+//
+impl<T: InspectRenderDefault<T>> InspectRenderDefault<Box<T>> for Box<T> {
+    fn render(
+        data: &[&Box<T>],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) {
+        let values: Vec<&T> = data.iter().map(|x| &***x).collect();
+        <T as InspectRenderDefault<T>>::render(&values, label, ui, args);
+    }
+
+    fn render_mut(
+        data: &mut [&mut Box<T>],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) -> bool {
+        let mut values: Vec<&mut T> = data.iter_mut().map(|x| &mut ***x).collect();
+        <T as InspectRenderDefault<T>>::render_mut(&mut values, label, ui, args)
+    }
+
+    fn filter_matches_children(
+        filter: &InspectFilter,
+        path: &str,
+    ) -> bool {
+        filter_matches_children_of::<T, T>(filter, path)
+    }
+}
+
+//
+// Rc<T> is shared, so it's always drawn read-only
+//
+impl<T: InspectRenderDefault<T>> InspectRenderDefault<Rc<T>> for Rc<T> {
+    fn render(
+        data: &[&Rc<T>],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) {
+        let values: Vec<&T> = data.iter().map(|x| &***x).collect();
+        <T as InspectRenderDefault<T>>::render(&values, label, ui, args);
+    }
+
+    fn render_mut(
+        data: &mut [&mut Rc<T>],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) -> bool {
+        let values: Vec<&T> = data.iter().map(|x| &***x).collect();
+        <T as InspectRenderDefault<T>>::render(&values, label, ui, args);
+        false
+    }
+
+    fn filter_matches_children(
+        filter: &InspectFilter,
+        path: &str,
+    ) -> bool {
+        filter_matches_children_of::<T, T>(filter, path)
+    }
+}
+
+//
+// Arc<T> is edited in place if it isn't shared. Shared values are edited through a copy, and if the value changes,
+// Arc::make_mut is used to write it back, which clones the value so that other Arcs don't see the change.
+//
+impl<T: InspectRenderDefault<T> + Clone> InspectRenderDefault<Arc<T>> for Arc<T> {
+    fn render(
+        data: &[&Arc<T>],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) {
+        let values: Vec<&T> = data.iter().map(|x| &***x).collect();
+        <T as InspectRenderDefault<T>>::render(&values, label, ui, args);
+    }
+
+    fn render_mut(
+        data: &mut [&mut Arc<T>],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) -> bool {
+        let is_shared: Vec<bool> = data.iter_mut().map(|x| Arc::get_mut(x).is_none()).collect();
+        let mut copies: Vec<T> = data
+            .iter()
+            .zip(&is_shared)
+            .filter(|(_, is_shared)| **is_shared)
+            .map(|(x, _)| T::clone(x))
+            .collect();
+
+        let changed = {
+            let mut copies = copies.iter_mut();
+            let mut values: Vec<&mut T> = data
+                .iter_mut()
+                .map(|x| match Arc::get_mut(x) {
+                    Some(value) => value,
+                    None => copies.next().unwrap(),
+                })
+                .collect();
+            <T as InspectRenderDefault<T>>::render_mut(&mut values, label, ui, args)
+        };
+
+        if changed {
+            let shared_data = data
+                .iter_mut()
+                .zip(is_shared)
+                .filter(|(_, is_shared)| *is_shared);
+            for ((d, _), copy) in shared_data.zip(copies) {
+                *Arc::make_mut(d) = copy;
+            }
+        }
+
+        changed
+    }
+
+    fn filter_matches_children(
+        filter: &InspectFilter,
+        path: &str,
+    ) -> bool {
+        filter_matches_children_of::<T, T>(filter, path)
+    }
+}
+
+//
+// Cow<T> is drawn using T's owned type (i.e. Cow<str> is drawn like a String). Owned values are drawn and edited in
+// place. Borrowed values are drawn through a copy, and become Cow::Owned if they are changed.
+//
+impl<'a, T> InspectRenderDefault<Cow<'a, T>> for Cow<'a, T>
+where
+    T: ToOwned + ?Sized,
+    T::Owned: InspectRenderDefault<T::Owned>,
+{
+    fn render(
+        data: &[&Cow<'a, T>],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) {
+        let copies: Vec<T::Owned> = data
+            .iter()
+            .filter_map(|x| match x {
+                Cow::Borrowed(borrowed) => Some((*borrowed).to_owned()),
+                Cow::Owned(_) => None,
+            })
+            .collect();
+
+        let mut copies = copies.iter();
+        let values: Vec<&T::Owned> = data
+            .iter()
+            .map(|x| match x {
+                Cow::Borrowed(_) => copies.next().unwrap(),
+                Cow::Owned(owned) => owned,
+            })
+            .collect();
+        <T::Owned as InspectRenderDefault<T::Owned>>::render(&values, label, ui, args);
+    }
+
+    fn render_mut(
+        data: &mut [&mut Cow<'a, T>],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) -> bool {
+        let mut copies: Vec<T::Owned> = data
+            .iter()
+            .filter_map(|x| match x {
+                Cow::Borrowed(borrowed) => Some((*borrowed).to_owned()),
+                Cow::Owned(_) => None,
+            })
+            .collect();
+
+        let changed = {
+            let mut copies = copies.iter_mut();
+            let mut values: Vec<&mut T::Owned> = data
+                .iter_mut()
+                .map(|x| match &mut **x {
+                    Cow::Borrowed(_) => copies.next().unwrap(),
+                    Cow::Owned(owned) => owned,
+                })
+                .collect();
+            <T::Owned as InspectRenderDefault<T::Owned>>::render_mut(&mut values, label, ui, args)
+        };
+
+        if changed {
+            let borrowed_data = data.iter_mut().filter(|x| matches!(***x, Cow::Borrowed(_)));
+            for (d, copy) in borrowed_data.zip(copies) {
+                **d = Cow::Owned(copy);
+            }
+        }
+
+        changed
+    }
+}
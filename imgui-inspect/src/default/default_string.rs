@@ -10,21 +10,21 @@ impl InspectRenderDefault<String> for String {
         if data.is_empty() {
             // Values are inconsistent
             let style_token = ui.push_style_color(imgui::StyleColor::Text, [1.0, 0.0, 0.0, 1.0]);
-            ui.text(&imgui::im_str!("{}: ", label));
+            ui.text(scratch_im_str!("{}: ", label));
             style_token.pop(ui);
             return;
         }
 
-        match get_same_or_none(data) {
+        match get_same_or_none_ref(data) {
             Some(_v) => {
                 // Values are consistent
-                ui.text(&imgui::im_str!("{}: {}", label, data[0]))
+                ui.text(scratch_im_str!("{}: {}", label, data[0]))
             }
             None => {
                 // Values are inconsistent
                 let style_token =
                     ui.push_style_color(imgui::StyleColor::Text, [1.0, 1.0, 0.0, 1.0]);
-                ui.text(&imgui::im_str!("{}: ", label));
+                ui.text(scratch_im_str!("{}: ", label));
                 style_token.pop(ui);
             }
         }
@@ -36,7 +36,8 @@ impl InspectRenderDefault<String> for String {
         ui: &imgui::Ui,
         _args: &InspectArgsDefault,
     ) -> bool {
-        let same_or_none_value = get_same_or_none_mut(data);
+        // Compare by reference so that the strings aren't cloned every frame
+        let same_or_none_value = get_same_or_none_mut_ref(data);
 
         let style_token = if same_or_none_value.is_none() {
             // If values are inconsistent, push a style
@@ -45,20 +46,18 @@ impl InspectRenderDefault<String> for String {
             None
         };
 
-        let value = match same_or_none_value {
-            Some(v) => v,
-            None => "".to_string(), // Some reasonable default
-        };
+        // Some reasonable default
+        let mut value = scratch_im_str!("{}", same_or_none_value.map(|x| x.as_str()).unwrap_or(""));
 
         let mut changed = false;
-        let mut value = imgui::im_str!("{}", value);
         if ui
-            .input_text(&imgui::im_str!("{}", label), &mut value)
+            .input_text(&scratch_im_str!("{}", label), value.as_im_string_mut())
             .resize_buffer(true)
             .build()
         {
             for d in data {
-                **d = value.to_string();
+                d.clear();
+                d.push_str(value.to_str());
                 changed = true;
             }
         }
@@ -0,0 +1,138 @@
+use super::*;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Formats a time as a UTC date and time, i.e. "2021-03-04 05:06:07.890 UTC"
+fn format_system_time(time: &SystemTime) -> String {
+    // Milliseconds relative to the unix epoch, which may be negative
+    let millis = match time.duration_since(UNIX_EPOCH) {
+        Ok(after) => after.as_millis() as i128,
+        Err(before) => -(before.duration().as_millis() as i128),
+    };
+
+    let days = millis.div_euclid(86_400_000);
+    let millis_of_day = millis.rem_euclid(86_400_000);
+
+    // Converts days since the epoch to a civil date (see http://howardhinnant.github.io/date_algorithms.html)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:03} UTC",
+        year,
+        month,
+        day,
+        millis_of_day / 3_600_000,
+        millis_of_day / 60_000 % 60,
+        millis_of_day / 1000 % 60,
+        millis_of_day % 1000
+    )
+}
+
+// System times are always read-only
+impl InspectRenderDefault<SystemTime> for SystemTime {
+    fn render(
+        data: &[&SystemTime],
+        label: &'static str,
+        ui: &imgui::Ui,
+        _args: &InspectArgsDefault,
+    ) {
+        if data.is_empty() {
+            // Values are inconsistent
+            let style_token = ui.push_style_color(imgui::StyleColor::Text, [1.0, 0.0, 0.0, 1.0]);
+            ui.text(scratch_im_str!("{}: ", label));
+            style_token.pop(ui);
+            return;
+        }
+
+        match get_same_or_none(data) {
+            Some(v) => {
+                // Values are consistent
+                ui.text(scratch_im_str!("{}: {}", label, format_system_time(&v)))
+            }
+            None => {
+                // Values are inconsistent
+                let style_token =
+                    ui.push_style_color(imgui::StyleColor::Text, [1.0, 1.0, 0.0, 1.0]);
+                ui.text(scratch_im_str!("{}: ", label));
+                style_token.pop(ui);
+            }
+        }
+    }
+
+    fn render_mut(
+        data: &mut [&mut SystemTime],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) -> bool {
+        let values: Vec<&SystemTime> = data.iter().map(|x| &**x).collect();
+        <SystemTime as InspectRenderDefault<SystemTime>>::render(&values, label, ui, args);
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn from_unix_millis(millis: i64) -> SystemTime {
+        if millis >= 0 {
+            UNIX_EPOCH + Duration::from_millis(millis as u64)
+        } else {
+            UNIX_EPOCH - Duration::from_millis(millis.unsigned_abs())
+        }
+    }
+
+    #[test]
+    fn formats_epoch() {
+        assert_eq!(
+            format_system_time(&UNIX_EPOCH),
+            "1970-01-01 00:00:00.000 UTC"
+        );
+    }
+
+    #[test]
+    fn formats_dates_after_epoch() {
+        assert_eq!(
+            format_system_time(&from_unix_millis(1_614_834_367_890)),
+            "2021-03-04 05:06:07.890 UTC"
+        );
+        assert_eq!(
+            format_system_time(&from_unix_millis(946_684_800_000)),
+            "2000-01-01 00:00:00.000 UTC"
+        );
+    }
+
+    #[test]
+    fn formats_leap_day() {
+        assert_eq!(
+            format_system_time(&from_unix_millis(1_582_977_600_000)),
+            "2020-02-29 12:00:00.000 UTC"
+        );
+    }
+
+    #[test]
+    fn formats_dates_before_epoch() {
+        assert_eq!(
+            format_system_time(&from_unix_millis(-1)),
+            "1969-12-31 23:59:59.999 UTC"
+        );
+        assert_eq!(
+            format_system_time(&from_unix_millis(-2_203_891_200_000)),
+            "1900-03-01 00:00:00.000 UTC"
+        );
+    }
+}
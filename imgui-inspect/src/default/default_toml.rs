@@ -0,0 +1,184 @@
+use super::*;
+use crate::collection::{InspectMapLike, InspectMapValues};
+use crate::document::{render_document, render_document_mut, DocumentKind, DocumentValue};
+use toml::value::{Datetime, Table, Value};
+
+impl InspectMapLike for Table {
+    type Key = String;
+
+    fn keys(&self) -> Vec<String> {
+        self.keys().cloned().collect()
+    }
+
+    fn contains_key(
+        &self,
+        key: &String,
+    ) -> bool {
+        self.contains_key(key)
+    }
+
+    // TOML has no null, so new keys start as an empty string
+    fn insert_default(
+        &mut self,
+        key: String,
+    ) {
+        self.insert(key, Value::String(String::new()));
+    }
+
+    fn rename_key(
+        &mut self,
+        old_key: &String,
+        new_key: String,
+    ) {
+        if let Some(value) = self.remove(old_key) {
+            self.insert(new_key, value);
+        }
+    }
+
+    fn remove_key(
+        &mut self,
+        key: &String,
+    ) {
+        self.remove(key);
+    }
+}
+
+impl InspectMapValues for Table {
+    type Value = Value;
+
+    fn get_value(
+        &self,
+        key: &String,
+    ) -> Option<&Value> {
+        self.get(key)
+    }
+
+    fn get_value_mut(
+        &mut self,
+        key: &String,
+    ) -> Option<&mut Value> {
+        self.get_mut(key)
+    }
+}
+
+impl DocumentValue for Value {
+    type Object = Table;
+
+    const KINDS: &'static [(DocumentKind, &'static str)] = &[
+        (DocumentKind::String, "string"),
+        (DocumentKind::Integer, "integer"),
+        (DocumentKind::Float, "float"),
+        (DocumentKind::Bool, "boolean"),
+        (DocumentKind::Datetime, "datetime"),
+        (DocumentKind::Array, "array"),
+        (DocumentKind::Object, "table"),
+    ];
+
+    fn kind(&self) -> DocumentKind {
+        match self {
+            Value::String(_) => DocumentKind::String,
+            Value::Integer(_) => DocumentKind::Integer,
+            Value::Float(_) => DocumentKind::Float,
+            Value::Boolean(_) => DocumentKind::Bool,
+            Value::Datetime(_) => DocumentKind::Datetime,
+            Value::Array(_) => DocumentKind::Array,
+            Value::Table(_) => DocumentKind::Object,
+        }
+    }
+
+    fn empty_of_kind(kind: DocumentKind) -> Self {
+        match kind {
+            DocumentKind::Integer | DocumentKind::Number => Value::Integer(0),
+            DocumentKind::Float => Value::Float(0.0),
+            DocumentKind::Bool => Value::Boolean(false),
+            DocumentKind::Datetime => Value::Datetime("1970-01-01T00:00:00Z".parse().unwrap()),
+            DocumentKind::Array => Value::Array(vec![]),
+            DocumentKind::Object => Value::Table(Table::new()),
+            DocumentKind::String | DocumentKind::Null => Value::String(String::new()),
+        }
+    }
+
+    fn as_bool(&self) -> Option<bool> {
+        self.as_bool()
+    }
+
+    fn as_i64(&self) -> Option<i64> {
+        self.as_integer()
+    }
+
+    // TOML integers are always signed
+    fn as_u64(&self) -> Option<u64> {
+        None
+    }
+
+    fn as_f64(&self) -> Option<f64> {
+        self.as_float()
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        self.as_str()
+    }
+
+    fn as_array(&self) -> Option<&Vec<Self>> {
+        self.as_array()
+    }
+
+    fn as_array_mut(&mut self) -> Option<&mut Vec<Self>> {
+        self.as_array_mut()
+    }
+
+    fn as_object(&self) -> Option<&Self::Object> {
+        self.as_table()
+    }
+
+    fn as_object_mut(&mut self) -> Option<&mut Self::Object> {
+        self.as_table_mut()
+    }
+
+    fn from_bool(value: bool) -> Self {
+        Value::Boolean(value)
+    }
+
+    fn from_i64(value: i64) -> Self {
+        Value::Integer(value)
+    }
+
+    fn from_u64(value: u64) -> Self {
+        Value::Integer(value as i64)
+    }
+
+    fn from_f64(value: f64) -> Option<Self> {
+        Some(Value::Float(value))
+    }
+
+    fn from_string(value: String) -> Self {
+        Value::String(value)
+    }
+
+    fn parse_datetime(text: &str) -> Result<Self, String> {
+        text.trim()
+            .parse::<Datetime>()
+            .map(Value::Datetime)
+            .map_err(|e| e.to_string())
+    }
+}
+
+impl InspectRenderDefault<Value> for Value {
+    fn render(
+        data: &[&Value],
+        label: &'static str,
+        ui: &imgui::Ui,
+        _args: &InspectArgsDefault,
+    ) {
+        render_document(data, label, ui);
+    }
+
+    fn render_mut(
+        data: &mut [&mut Value],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) -> bool {
+        render_document_mut(data, label, ui, args, true)
+    }
+}
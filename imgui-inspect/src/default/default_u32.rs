@@ -10,7 +10,7 @@ impl InspectRenderDefault<u32> for u32 {
         if data.is_empty() {
             // Values are inconsistent
             let style_token = ui.push_style_color(imgui::StyleColor::Text, [1.0, 0.0, 0.0, 1.0]);
-            ui.text(&imgui::im_str!("{}: ", label));
+            ui.text(scratch_im_str!("{}: ", label));
             style_token.pop(ui);
             return;
         }
@@ -18,13 +18,13 @@ impl InspectRenderDefault<u32> for u32 {
         match get_same_or_none(data) {
             Some(_v) => {
                 // Values are consistent
-                ui.text(&imgui::im_str!("{}: {}", label, data[0]))
+                ui.text(scratch_im_str!("{}: {}", label, data[0]))
             }
             None => {
                 // Values are inconsistent
                 let style_token =
                     ui.push_style_color(imgui::StyleColor::Text, [1.0, 1.0, 0.0, 1.0]);
-                ui.text(&imgui::im_str!("{}: ", label));
+                ui.text(scratch_im_str!("{}: ", label));
                 style_token.pop(ui);
             }
         }
@@ -53,7 +53,7 @@ impl InspectRenderDefault<u32> for u32 {
 
         let mut changed = false;
         if ui
-            .input_int(&imgui::im_str!("{}", label), &mut value)
+            .input_int(&scratch_im_str!("{}", label), &mut value)
             .build()
         {
             for d in data {
@@ -0,0 +1,55 @@
+use super::*;
+use crate::list::{render_list, render_list_mut, InspectListLike};
+use std::collections::VecDeque;
+
+//
+// Vec and VecDeque are drawn as pages of rows, one per element. Elements can be edited, but not added or removed.
+//
+macro_rules! impl_inspect_list {
+    ($list:ident) => {
+        impl<T: InspectRenderDefault<T> + 'static> InspectListLike for $list<T> {
+            type Item = T;
+
+            fn item_count(&self) -> usize {
+                self.len()
+            }
+
+            fn get_item(
+                &self,
+                index: usize,
+            ) -> Option<&T> {
+                self.get(index)
+            }
+
+            fn get_item_mut(
+                &mut self,
+                index: usize,
+            ) -> Option<&mut T> {
+                self.get_mut(index)
+            }
+        }
+
+        impl<T: InspectRenderDefault<T> + 'static> InspectRenderDefault<$list<T>> for $list<T> {
+            fn render(
+                data: &[&$list<T>],
+                label: &'static str,
+                ui: &imgui::Ui,
+                args: &InspectArgsDefault,
+            ) {
+                render_list(data, label, ui, args);
+            }
+
+            fn render_mut(
+                data: &mut [&mut $list<T>],
+                label: &'static str,
+                ui: &imgui::Ui,
+                args: &InspectArgsDefault,
+            ) -> bool {
+                render_list_mut(data, label, ui, args)
+            }
+        }
+    };
+}
+
+impl_inspect_list!(Vec);
+impl_inspect_list!(VecDeque);
@@ -0,0 +1,24 @@
+use super::*;
+use std::num::Wrapping;
+
+impl<T: InspectRenderDefault<T>> InspectRenderDefault<Wrapping<T>> for Wrapping<T> {
+    fn render(
+        data: &[&Wrapping<T>],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) {
+        let values: Vec<&T> = data.iter().map(|x| &x.0).collect();
+        <T as InspectRenderDefault<T>>::render(&values, label, ui, args);
+    }
+
+    fn render_mut(
+        data: &mut [&mut Wrapping<T>],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) -> bool {
+        let mut values: Vec<&mut T> = data.iter_mut().map(|x| &mut x.0).collect();
+        <T as InspectRenderDefault<T>>::render_mut(&mut values, label, ui, args)
+    }
+}
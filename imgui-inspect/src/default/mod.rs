@@ -4,6 +4,33 @@ mod default_option;
 mod default_u32;
 mod default_usize;
 mod default_string;
+mod default_map;
+mod default_set;
+mod default_vec;
+mod default_smart_pointer;
+mod default_interior_mutability;
+mod default_char;
+mod default_path;
+mod default_duration;
+mod default_range;
+mod default_non_zero;
+mod default_wrapping;
+mod default_net;
+mod default_system_time;
+
+#[cfg(feature = "glam")]
+mod default_glam;
+#[cfg(feature = "nalgebra")]
+mod default_nalgebra;
+#[cfg(feature = "cgmath")]
+mod default_cgmath;
+#[cfg(feature = "mint")]
+mod default_mint;
+
+#[cfg(feature = "serde_json")]
+mod default_json;
+#[cfg(feature = "toml")]
+mod default_toml;
 
 pub use super::*;
 
@@ -26,6 +53,9 @@ pub struct InspectArgsDefault {
     /// If true, any child elements (i.e. struct members) will be indented. This defaults to true.
     pub indent_children: Option<bool>,
 
+    /// If true, the header starts open the first time it's drawn. This defaults to true.
+    pub default_open: Option<bool>,
+
     /// Minimum value for the widget. The precise meaning of this can vary depending on the widget type
     pub min_value: Option<f32>,
 
@@ -57,4 +87,14 @@ pub trait InspectRenderDefault<T> {
         ui: &imgui::Ui,
         args: &InspectArgsDefault,
     ) -> bool;
+
+    /// Returns true if anything nested inside the value at the given path matches the filter. While filtering, fields
+    /// that don't match by name are only drawn if this returns true. Only types with named children (like structs
+    /// using the derive) need to implement this.
+    fn filter_matches_children(
+        _filter: &InspectFilter,
+        _path: &str,
+    ) -> bool {
+        false
+    }
 }
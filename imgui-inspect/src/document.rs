@@ -0,0 +1,515 @@
+// Widgets for loosely typed document trees like serde_json::Value and toml::Value. Objects are drawn as tree nodes
+// with a child per key, arrays as tree nodes with a child per element, and everything else with a widget for its type.
+//
+// Like everything else, multiple values can be drawn at once. Children are matched up by key (or index), and a child
+// that only some of the values have is drawn for just those values.
+//
+// When the structure can be edited, each node also gets a type selector, objects are drawn with the collection
+// widgets so that keys can be added, removed, and renamed, and arrays get buttons for adding and removing elements.
+// When it can't (i.e. for InspectSerde, where the structure comes from a rust type), only the leaf values can change.
+//
+// Labels here aren't &'static str because object keys are only known at runtime.
+use crate::scratch::scratch_im_str;
+use crate::collection::{render_map_mut, render_map_values_mut, InspectMapLike, InspectMapValues};
+use crate::text_value::{render_text_value, render_text_value_mut};
+use crate::{claim_field_context_menu, InspectArgsDefault, InspectRenderDefault};
+
+// Not every format has every kind
+#[allow(dead_code)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub(crate) enum DocumentKind {
+    Null,
+    Bool,
+    // A number that may be an integer or a float, edited with whichever fits the current value
+    Number,
+    Integer,
+    Float,
+    String,
+    Datetime,
+    Array,
+    Object,
+}
+
+/// A node in a document tree
+pub(crate) trait DocumentValue:
+    Clone + PartialEq + std::fmt::Display + InspectRenderDefault<Self> + 'static
+{
+    type Object: InspectMapValues<Key = String, Value = Self>;
+
+    /// The kinds that can be picked in the type selector, and the name shown for each
+    const KINDS: &'static [(DocumentKind, &'static str)];
+
+    fn kind(&self) -> DocumentKind;
+
+    /// An empty value of the given kind. Used when the type of a node is changed
+    fn empty_of_kind(kind: DocumentKind) -> Self;
+
+    fn as_bool(&self) -> Option<bool>;
+    fn as_i64(&self) -> Option<i64>;
+    fn as_u64(&self) -> Option<u64>;
+    fn as_f64(&self) -> Option<f64>;
+    fn as_str(&self) -> Option<&str>;
+    fn as_array(&self) -> Option<&Vec<Self>>;
+    fn as_array_mut(&mut self) -> Option<&mut Vec<Self>>;
+    fn as_object(&self) -> Option<&Self::Object>;
+    fn as_object_mut(&mut self) -> Option<&mut Self::Object>;
+
+    fn from_bool(value: bool) -> Self;
+    fn from_i64(value: i64) -> Self;
+    fn from_u64(value: u64) -> Self;
+
+    /// Returns None if the value can't be represented (i.e. NaN in JSON)
+    fn from_f64(value: f64) -> Option<Self>;
+    fn from_string(value: String) -> Self;
+
+    /// Parses the text of a datetime. Only needed for formats that have datetimes.
+    fn parse_datetime(_text: &str) -> Result<Self, String> {
+        Err("Datetimes are not supported".to_string())
+    }
+}
+
+// Returns the kind of the values if they are all the same kind
+fn get_same_kind_or_none<'a, V: DocumentValue>(
+    mut values: impl Iterator<Item = &'a V>
+) -> Option<DocumentKind> {
+    let first = values.next()?.kind();
+    if values.all(|x| x.kind() == first) {
+        Some(first)
+    } else {
+        None
+    }
+}
+
+fn render_inconsistent(
+    label: &str,
+    ui: &imgui::Ui,
+) {
+    let style_token = ui.push_style_color(imgui::StyleColor::Text, [1.0, 1.0, 0.0, 1.0]);
+    ui.text(scratch_im_str!("{}: ", label));
+    style_token.pop(ui);
+}
+
+fn push_inconsistent_style(
+    is_consistent: bool,
+    ui: &imgui::Ui,
+) -> Option<imgui::ColorStackToken> {
+    if is_consistent {
+        None
+    } else {
+        Some(ui.push_style_color(imgui::StyleColor::Text, [1.0, 1.0, 0.0, 1.0]))
+    }
+}
+
+fn push_container_node(
+    label: &str,
+    len: usize,
+    ui: &imgui::Ui,
+) -> Option<imgui::TreeNodeToken> {
+    // The ### keeps the ID stable when the length changes so that the node doesn't close
+    let node_token =
+        imgui::TreeNode::new(&scratch_im_str!("{} ({})###{}", label, len, label)).push(ui);
+    claim_field_context_menu(ui);
+    node_token
+}
+
+// Returns the keys of all the objects, in the order they are first seen
+fn collect_object_keys<'a, V: DocumentValue>(values: impl Iterator<Item = &'a V>) -> Vec<String> {
+    let mut keys: Vec<String> = vec![];
+    for object in values.filter_map(|x| x.as_object()) {
+        for key in object.keys() {
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+    }
+
+    keys
+}
+
+fn array_len<'a, V: DocumentValue>(values: impl Iterator<Item = &'a V>) -> usize {
+    values
+        .filter_map(|x| x.as_array().map(|x| x.len()))
+        .max()
+        .unwrap_or(0)
+}
+
+// Draws a combo box for picking the kind of the values. Returns the new kind if one was picked.
+fn render_kind_selector<V: DocumentValue>(
+    kind: Option<DocumentKind>,
+    label: &str,
+    ui: &imgui::Ui,
+) -> Option<DocumentKind> {
+    let preview = V::KINDS
+        .iter()
+        .find(|(k, _)| Some(*k) == kind)
+        .map(|(_, name)| *name)
+        .unwrap_or("");
+
+    let mut selected_kind = None;
+    ui.set_next_item_width(80.0);
+    let combo_label = scratch_im_str!("##kind_{}", label);
+    let combo_preview = scratch_im_str!("{}", preview);
+    if let Some(combo_token) = imgui::ComboBox::new(&combo_label)
+        .preview_value(&combo_preview)
+        .begin(ui)
+    {
+        for (k, name) in V::KINDS {
+            if imgui::Selectable::new(&scratch_im_str!("{}", name))
+                .selected(Some(*k) == kind)
+                .build(ui)
+                && Some(*k) != kind
+            {
+                selected_kind = Some(*k);
+            }
+        }
+
+        combo_token.end(ui);
+    }
+
+    ui.same_line(0.0);
+    selected_kind
+}
+
+/// Draws values as static text
+pub(crate) fn render_document<V: DocumentValue>(
+    data: &[&V],
+    label: &str,
+    ui: &imgui::Ui,
+) {
+    if data.is_empty() {
+        ui.text(scratch_im_str!("{}: None", label));
+        return;
+    }
+
+    match get_same_kind_or_none(data.iter().copied()) {
+        None => render_inconsistent(label, ui),
+        Some(DocumentKind::Array) => {
+            let len = array_len(data.iter().copied());
+            if let Some(node_token) = push_container_node(label, len, ui) {
+                for index in 0..len {
+                    let id_token = ui.push_id(index as i32);
+                    let elements: Vec<&V> = data
+                        .iter()
+                        .filter_map(|x| x.as_array()?.get(index))
+                        .collect();
+                    render_document(&elements, &format!("[{}]", index), ui);
+                    id_token.pop(ui);
+                }
+
+                node_token.pop(ui);
+            }
+        }
+        Some(DocumentKind::Object) => {
+            let keys = collect_object_keys(data.iter().copied());
+            if let Some(node_token) = push_container_node(label, keys.len(), ui) {
+                for (index, key) in keys.iter().enumerate() {
+                    let id_token = ui.push_id(index as i32);
+                    let children: Vec<&V> = data
+                        .iter()
+                        .filter_map(|x| x.as_object()?.get_value(key))
+                        .collect();
+                    render_document(&children, key, ui);
+                    id_token.pop(ui);
+                }
+
+                node_token.pop(ui);
+            }
+        }
+        Some(_) => render_text_value(data, label, ui, |x| x.to_string()),
+    }
+}
+
+/// Draws editors for values. If edit_structure is true, the type of each node can be changed, and keys and elements
+/// can be added and removed. Returns true if the values changed.
+pub(crate) fn render_document_mut<V: DocumentValue>(
+    data: &mut [&mut V],
+    label: &str,
+    ui: &imgui::Ui,
+    args: &InspectArgsDefault,
+    edit_structure: bool,
+) -> bool {
+    if data.is_empty() {
+        ui.text(scratch_im_str!("{}: None", label));
+        return false;
+    }
+
+    let kind = get_same_kind_or_none(data.iter().map(|x| &**x));
+    if edit_structure {
+        if let Some(new_kind) = render_kind_selector::<V>(kind, label, ui) {
+            for d in data.iter_mut() {
+                **d = V::empty_of_kind(new_kind);
+            }
+
+            // The new values are drawn next frame
+            ui.text(label);
+            return true;
+        }
+    }
+
+    let is_consistent = data[1..].iter().all(|x| **x == *data[0]);
+    match kind {
+        None => {
+            render_inconsistent(label, ui);
+            false
+        }
+        Some(DocumentKind::Null) => {
+            ui.text(scratch_im_str!("{}: null", label));
+            false
+        }
+        Some(DocumentKind::Bool) => {
+            let mut value = is_consistent && data[0].as_bool().unwrap_or(false);
+
+            let style_token = push_inconsistent_style(is_consistent, ui);
+            let changed = ui.checkbox(&scratch_im_str!("{}", label), &mut value);
+            if changed {
+                for d in data.iter_mut() {
+                    **d = V::from_bool(value);
+                }
+            }
+
+            if let Some(style_token) = style_token {
+                style_token.pop(ui);
+            }
+
+            changed
+        }
+        Some(DocumentKind::Number) => render_number_mut(data, label, is_consistent, ui),
+        Some(DocumentKind::Integer) => render_integer_mut(data, label, is_consistent, ui),
+        Some(DocumentKind::Float) => render_float_mut(data, label, is_consistent, ui),
+        Some(DocumentKind::String) => {
+            let value = if is_consistent {
+                data[0].as_str().unwrap_or_default()
+            } else {
+                ""
+            };
+            let mut value = scratch_im_str!("{}", value);
+
+            let style_token = push_inconsistent_style(is_consistent, ui);
+            let changed = ui
+                .input_text(&scratch_im_str!("{}", label), value.as_im_string_mut())
+                .resize_buffer(true)
+                .build();
+            if changed {
+                for d in data.iter_mut() {
+                    **d = V::from_string(value.to_str().to_string());
+                }
+            }
+
+            if let Some(style_token) = style_token {
+                style_token.pop(ui);
+            }
+
+            changed
+        }
+        Some(DocumentKind::Datetime) => {
+            render_text_value_mut(data, label, ui, |x| x.to_string(), V::parse_datetime)
+        }
+        Some(DocumentKind::Array) => render_array_mut(data, label, ui, args, edit_structure),
+        Some(DocumentKind::Object) => {
+            if edit_structure {
+                // Children are drawn by the InspectRenderDefault impl for the value type, which edits the structure
+                let mut objects: Vec<&mut V::Object> =
+                    data.iter_mut().filter_map(|x| x.as_object_mut()).collect();
+                render_map_mut(&mut objects, label, ui, args, |maps, key| {
+                    render_map_values_mut(maps, key, ui, args)
+                })
+            } else {
+                render_object_mut(data, label, ui, args)
+            }
+        }
+    }
+}
+
+// Draws an object's children labelled by their keys. The keys themselves can't be changed.
+fn render_object_mut<V: DocumentValue>(
+    data: &mut [&mut V],
+    label: &str,
+    ui: &imgui::Ui,
+    args: &InspectArgsDefault,
+) -> bool {
+    let keys = collect_object_keys(data.iter().map(|x| &**x));
+    let node_token = match push_container_node(label, keys.len(), ui) {
+        Some(node_token) => node_token,
+        None => return false,
+    };
+
+    let mut changed = false;
+    for (index, key) in keys.iter().enumerate() {
+        let id_token = ui.push_id(index as i32);
+        let mut children: Vec<&mut V> = data
+            .iter_mut()
+            .filter_map(|x| x.as_object_mut()?.get_value_mut(key))
+            .collect();
+        changed |= render_document_mut(&mut children, key, ui, args, false);
+        id_token.pop(ui);
+    }
+
+    node_token.pop(ui);
+    changed
+}
+
+fn render_array_mut<V: DocumentValue>(
+    data: &mut [&mut V],
+    label: &str,
+    ui: &imgui::Ui,
+    args: &InspectArgsDefault,
+    edit_structure: bool,
+) -> bool {
+    let len = array_len(data.iter().map(|x| &**x));
+    let node_token = match push_container_node(label, len, ui) {
+        Some(node_token) => node_token,
+        None => return false,
+    };
+
+    let mut changed = false;
+    let mut index_to_remove = None;
+    for index in 0..len {
+        let id_token = ui.push_id(index as i32);
+
+        if edit_structure {
+            if ui.small_button(imgui::im_str!("Remove")) {
+                index_to_remove = Some(index);
+            }
+            ui.same_line(0.0);
+        }
+
+        let mut elements: Vec<&mut V> = data
+            .iter_mut()
+            .filter_map(|x| x.as_array_mut()?.get_mut(index))
+            .collect();
+        changed |= render_document_mut(
+            &mut elements,
+            &format!("[{}]", index),
+            ui,
+            args,
+            edit_structure,
+        );
+
+        id_token.pop(ui);
+    }
+
+    if let Some(index) = index_to_remove {
+        for array in data.iter_mut().filter_map(|x| x.as_array_mut()) {
+            if index < array.len() {
+                array.remove(index);
+            }
+        }
+        changed = true;
+    }
+
+    // New elements are the same kind as the last element, if there is one
+    if edit_structure && ui.small_button(imgui::im_str!("Add")) {
+        for array in data.iter_mut().filter_map(|x| x.as_array_mut()) {
+            let kind = array.last().map(|x| x.kind()).unwrap_or(V::KINDS[0].0);
+            array.push(V::empty_of_kind(kind));
+        }
+        changed = true;
+    }
+
+    node_token.pop(ui);
+    changed
+}
+
+// Numbers are edited with the widest type that can hold all of them. This keeps integers from turning into floats
+// unless a fractional value is entered.
+fn render_number_mut<V: DocumentValue>(
+    data: &mut [&mut V],
+    label: &str,
+    is_consistent: bool,
+    ui: &imgui::Ui,
+) -> bool {
+    if data.iter().all(|x| x.as_i64().is_some()) {
+        render_integer_mut(data, label, is_consistent, ui)
+    } else if data.iter().all(|x| x.as_u64().is_some()) {
+        let imgui_label = scratch_im_str!("{}", label);
+        let style_token = push_inconsistent_style(is_consistent, ui);
+
+        let mut changed = false;
+        let mut value = if is_consistent {
+            data[0].as_u64()
+        } else {
+            None
+        }
+        .unwrap_or(0);
+        if imgui::Drag::new(&imgui_label).build(ui, &mut value) {
+            for d in data.iter_mut() {
+                **d = V::from_u64(value);
+            }
+            changed = true;
+        }
+
+        if let Some(style_token) = style_token {
+            style_token.pop(ui);
+        }
+
+        changed
+    } else {
+        render_float_mut(data, label, is_consistent, ui)
+    }
+}
+
+fn render_integer_mut<V: DocumentValue>(
+    data: &mut [&mut V],
+    label: &str,
+    is_consistent: bool,
+    ui: &imgui::Ui,
+) -> bool {
+    let imgui_label = scratch_im_str!("{}", label);
+    let style_token = push_inconsistent_style(is_consistent, ui);
+
+    let mut changed = false;
+    let mut value = if is_consistent {
+        data[0].as_i64()
+    } else {
+        None
+    }
+    .unwrap_or(0);
+    if imgui::Drag::new(&imgui_label).build(ui, &mut value) {
+        for d in data.iter_mut() {
+            **d = V::from_i64(value);
+        }
+        changed = true;
+    }
+
+    if let Some(style_token) = style_token {
+        style_token.pop(ui);
+    }
+
+    changed
+}
+
+fn render_float_mut<V: DocumentValue>(
+    data: &mut [&mut V],
+    label: &str,
+    is_consistent: bool,
+    ui: &imgui::Ui,
+) -> bool {
+    let imgui_label = scratch_im_str!("{}", label);
+    let style_token = push_inconsistent_style(is_consistent, ui);
+
+    let mut changed = false;
+    let mut value = if is_consistent {
+        data[0].as_f64()
+    } else {
+        None
+    }
+    .unwrap_or(0.0);
+    if imgui::Drag::new(&imgui_label)
+        .speed(0.1)
+        .build(ui, &mut value)
+    {
+        // Some formats can't represent NaN or infinity, so those edits are dropped
+        if let Some(new_value) = V::from_f64(value) {
+            for d in data.iter_mut() {
+                **d = new_value.clone();
+            }
+            changed = true;
+        }
+    }
+
+    if let Some(style_token) = style_token {
+        style_token.pop(ui);
+    }
+
+    changed
+}
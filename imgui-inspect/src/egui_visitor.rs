@@ -0,0 +1,253 @@
+// Draws visited values with egui. This lets structs that derive Inspect with #[inspect(visitor)] be edited in egui
+// tools with the same attributes. Widgets follow the imgui defaults: values that differ across the selection are drawn
+// in yellow, and editing one writes the new value to every selected value.
+//
+// Numbers are drawn with egui's DragValue. min_value and max_value from #[inspect(...)] clamp the value, and step sets
+// how much it changes per pixel dragged.
+//
+// Only the types that InspectVisitor has callbacks for can be edited. Fields of other types (maps, sets, char,
+// Duration, math types, ...) arrive in visit_unsupported and are shown as read-only text. The README lists which
+// types are supported.
+use crate::{
+    get_same_or_none_mut, InspectArgsDefault, InspectArgsSlider, InspectArgsStruct, InspectVisitor,
+};
+
+const INCONSISTENT_VALUE_COLOR: egui::Color32 = egui::Color32::YELLOW;
+
+/// Draws visited values with egui. Like every InspectVisitor, it can only edit the types that have a visit callback
+/// (bool, f32, u32, usize, String, sliders, structs, Option, and lists). Other fields are shown as read-only text.
+pub struct EguiInspectVisitor<'a> {
+    ui: &'a mut egui::Ui,
+    // The index of the list element being visited. List elements are visited with an empty label, so this keeps their
+    // headers' IDs unique.
+    item_index: Option<usize>,
+}
+
+impl<'a> EguiInspectVisitor<'a> {
+    pub fn new(ui: &'a mut egui::Ui) -> Self {
+        EguiInspectVisitor {
+            ui,
+            item_index: None,
+        }
+    }
+
+    fn id_source(
+        &self,
+        label: &'static str,
+    ) -> (&'static str, Option<usize>) {
+        (label, self.item_index)
+    }
+}
+
+// Draws a widget for a value shared by all of the selected values, followed by its label (like imgui does). If the
+// values are inconsistent, the widget starts from the given default and is drawn in yellow.
+fn edit_value<T: PartialEq + Clone>(
+    ui: &mut egui::Ui,
+    label: &'static str,
+    data: &mut [&mut T],
+    default: T,
+    add_widget: impl FnOnce(&mut egui::Ui, &mut T) -> egui::Response,
+) -> bool {
+    let same_or_none_value = get_same_or_none_mut(data);
+    let is_consistent = same_or_none_value.is_some();
+    let mut value = same_or_none_value.unwrap_or(default);
+
+    let response = ui.horizontal(|ui| {
+        if !is_consistent {
+            ui.visuals_mut().override_text_color = Some(INCONSISTENT_VALUE_COLOR);
+        }
+
+        let response = add_widget(ui, &mut value);
+        ui.label(label);
+        response
+    });
+
+    let mut changed = false;
+    if response.inner.changed() {
+        for d in data {
+            **d = value.clone();
+            changed = true;
+        }
+    }
+
+    changed
+}
+
+// A drag widget for a number, clamped to min_value and max_value if either is given, and dragged at a speed of step
+// per pixel if it's given
+fn drag_value<'a, T: egui::emath::Numeric>(
+    value: &'a mut T,
+    args: &InspectArgsDefault,
+) -> egui::DragValue<'a> {
+    let mut drag_value = egui::DragValue::new(value);
+    if args.min_value.is_some() || args.max_value.is_some() {
+        let min = args.min_value.map_or(T::MIN.to_f64(), f64::from);
+        let max = args.max_value.map_or(T::MAX.to_f64(), f64::from);
+        drag_value = drag_value.clamp_range(min..=max);
+    }
+
+    if let Some(step) = args.step {
+        drag_value = drag_value.speed(step);
+    }
+
+    drag_value
+}
+
+impl InspectVisitor for EguiInspectVisitor<'_> {
+    fn visit_bool(
+        &mut self,
+        label: &'static str,
+        values: &mut [&mut bool],
+        _args: &InspectArgsDefault,
+    ) -> bool {
+        edit_value(self.ui, label, values, false, |ui, value| {
+            ui.checkbox(value, "")
+        })
+    }
+
+    fn visit_f32(
+        &mut self,
+        label: &'static str,
+        values: &mut [&mut f32],
+        args: &InspectArgsDefault,
+    ) -> bool {
+        edit_value(self.ui, label, values, 0.0, |ui, value| {
+            ui.add(drag_value(value, args))
+        })
+    }
+
+    fn visit_u32(
+        &mut self,
+        label: &'static str,
+        values: &mut [&mut u32],
+        args: &InspectArgsDefault,
+    ) -> bool {
+        edit_value(self.ui, label, values, 0, |ui, value| {
+            ui.add(drag_value(value, args))
+        })
+    }
+
+    fn visit_usize(
+        &mut self,
+        label: &'static str,
+        values: &mut [&mut usize],
+        args: &InspectArgsDefault,
+    ) -> bool {
+        edit_value(self.ui, label, values, 0, |ui, value| {
+            ui.add(drag_value(value, args))
+        })
+    }
+
+    fn visit_string(
+        &mut self,
+        label: &'static str,
+        values: &mut [&mut String],
+        _args: &InspectArgsDefault,
+    ) -> bool {
+        edit_value(self.ui, label, values, String::new(), |ui, value| {
+            ui.text_edit_singleline(value)
+        })
+    }
+
+    fn visit_slider_f32(
+        &mut self,
+        label: &'static str,
+        values: &mut [&mut f32],
+        args: &InspectArgsSlider,
+    ) -> bool {
+        let min = args.min_value.unwrap_or(-100.0);
+        let max = args.max_value.unwrap_or(100.0);
+        edit_value(self.ui, label, values, 0.0, |ui, value| {
+            ui.add(egui::Slider::new(value, min..=max))
+        })
+    }
+
+    fn visit_struct(
+        &mut self,
+        label: &'static str,
+        type_name: &'static str,
+        args: &InspectArgsStruct,
+        visit_fields: &mut dyn FnMut(&mut dyn InspectVisitor) -> bool,
+    ) -> bool {
+        let header = args.header.unwrap_or(true);
+        let indent_children = args.indent_children.unwrap_or(true);
+        let id_source = self.id_source(label);
+
+        if header {
+            // egui always indents the body of a collapsing header, so indent_children only applies without a header
+            egui::CollapsingHeader::new(type_name)
+                .id_source(id_source)
+                .default_open(args.default_open.unwrap_or(true))
+                .show(self.ui, |ui| visit_fields(&mut EguiInspectVisitor::new(ui)))
+                .body_returned
+                .unwrap_or(false)
+        } else if indent_children {
+            self.ui
+                .indent(id_source, |ui| {
+                    visit_fields(&mut EguiInspectVisitor::new(ui))
+                })
+                .inner
+        } else {
+            self.ui
+                .scope(|ui| visit_fields(&mut EguiInspectVisitor::new(ui)))
+                .inner
+        }
+    }
+
+    fn visit_option(
+        &mut self,
+        label: &'static str,
+        is_some: &[bool],
+        visit_some: &mut dyn FnMut(&mut dyn InspectVisitor) -> bool,
+    ) -> bool {
+        if is_some.iter().any(|x| *x) {
+            visit_some(self)
+        } else {
+            self.ui.label(format!("{}: None", label));
+            false
+        }
+    }
+
+    fn visit_list(
+        &mut self,
+        label: &'static str,
+        lens: &[usize],
+        visit_item: &mut dyn FnMut(&mut dyn InspectVisitor, usize) -> bool,
+    ) -> bool {
+        let len = lens.iter().copied().max().unwrap_or(0);
+
+        // The id_source keeps the header open when the length changes
+        egui::CollapsingHeader::new(format!("{} ({})", label, len))
+            .id_source(self.id_source(label))
+            .show(self.ui, |ui| {
+                let mut changed = false;
+                // Elements are visited with an empty label, so their index is drawn above them
+                for index in 0..len {
+                    ui.label(format!("[{}]", index));
+                    changed |= ui
+                        .scope(|ui| {
+                            visit_item(
+                                &mut EguiInspectVisitor {
+                                    ui,
+                                    item_index: Some(index),
+                                },
+                                index,
+                            )
+                        })
+                        .inner;
+                }
+                changed
+            })
+            .body_returned
+            .unwrap_or(false)
+    }
+
+    fn visit_unsupported(
+        &mut self,
+        label: &'static str,
+        type_name: &'static str,
+    ) -> bool {
+        self.ui.label(format!("{}: <{}>", label, type_name));
+        false
+    }
+}
@@ -0,0 +1,363 @@
+// Filtering hides fields that don't match a search query. The filter is installed for the current thread with
+// InspectFilter::scope, and the code generated by #[derive(Inspect)] checks it for every field. This way the filter
+// reaches nested structs without being passed through every render call.
+//
+// Fields are matched by their path (i.e. "transform.position.x"), so a field that matches shows all of its children,
+// and a field that doesn't match is still shown if something nested inside it matches.
+//
+// The field where a match starts is highlighted by changing the text color while it's drawn. Widgets draw their label
+// and value with the same color, so this colors the whole field rather than just the matched part of its label.
+use crate::InspectRenderDefault;
+use std::cell::RefCell;
+
+/// Text color used for the field where a match starts. This applies to all of the field's text, including its value.
+pub const FILTER_MATCH_TEXT_COLOR: [f32; 4] = [0.4, 1.0, 0.4, 1.0];
+
+struct ActiveFilter {
+    filter: InspectFilter,
+    path: String,
+    // The text color from before the current match was highlighted, restored for the fields under it
+    unhighlighted_text_color: [f32; 4],
+}
+
+thread_local! {
+    static ACTIVE_FILTER: RefCell<Option<ActiveFilter>> = const { RefCell::new(None) };
+}
+
+/// A search query for hiding fields of derived structs. Matching is case-insensitive and checks whether the query
+/// appears anywhere in the field's path.
+///
+/// ```ignore
+/// filter.render_search_bar(ui);
+/// filter.scope(|| {
+///     <MyStruct as InspectRenderDefault<MyStruct>>::render_mut(&mut [&mut value], "value", ui, &args);
+/// });
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct InspectFilter {
+    text: imgui::ImString,
+    query: String,
+}
+
+impl InspectFilter {
+    /// Types nested deeper than this aren't searched when deciding whether a field contains a match. This keeps
+    /// recursive types (i.e. a linked list of boxed nodes) from searching forever.
+    pub const MAX_PATH_DEPTH: usize = 16;
+
+    pub fn new(query: &str) -> Self {
+        let mut filter = InspectFilter::default();
+        filter.set_query(query);
+        filter
+    }
+
+    pub fn query(&self) -> &str {
+        self.text.to_str()
+    }
+
+    pub fn set_query(
+        &mut self,
+        query: &str,
+    ) {
+        self.text = imgui::ImString::new(query);
+        self.query = query.trim().to_lowercase();
+    }
+
+    /// True if the filter doesn't hide anything
+    pub fn is_empty(&self) -> bool {
+        self.query.is_empty()
+    }
+
+    /// Returns true if the path (i.e. "transform.position") matches the query
+    pub fn matches(
+        &self,
+        path: &str,
+    ) -> bool {
+        path.to_lowercase().contains(&self.query)
+    }
+
+    /// Calls f with this filter applied to everything drawn by derived structs on this thread
+    pub fn scope<R, F: FnOnce() -> R>(
+        &self,
+        f: F,
+    ) -> R {
+        let active = if self.is_empty() {
+            None
+        } else {
+            Some(ActiveFilter {
+                filter: self.clone(),
+                path: String::new(),
+                unhighlighted_text_color: [1.0; 4],
+            })
+        };
+
+        let previous = ACTIVE_FILTER.with(|x| x.replace(active));
+        let result = f();
+        ACTIVE_FILTER.with(|x| x.replace(previous));
+        result
+    }
+
+    /// Draws a text box for editing the query, with a button to clear it. Returns true if the query changed.
+    pub fn render_search_bar(
+        &mut self,
+        ui: &imgui::Ui,
+    ) -> bool {
+        let mut changed = ui
+            .input_text(imgui::im_str!("##inspect_filter"), &mut self.text)
+            .hint(imgui::im_str!("Search"))
+            .resize_buffer(true)
+            .build();
+
+        ui.same_line(0.0);
+        if ui.small_button(imgui::im_str!("Clear")) {
+            self.text.clear();
+            changed = true;
+        }
+
+        if changed {
+            self.query = self.text.to_str().trim().to_lowercase();
+        }
+
+        changed
+    }
+}
+
+/// Returned by begin_filtered_field for fields that should be drawn. `end` must be called after the field is drawn.
+pub struct InspectFilterFieldToken {
+    parent_path_len: Option<usize>,
+    style_token: Option<imgui::ColorStackToken>,
+}
+
+impl InspectFilterFieldToken {
+    pub fn end(
+        self,
+        ui: &imgui::Ui,
+    ) {
+        if let Some(style_token) = self.style_token {
+            style_token.pop(ui);
+        }
+
+        if let Some(parent_path_len) = self.parent_path_len {
+            ACTIVE_FILTER.with(|x| {
+                if let Some(active) = x.borrow_mut().as_mut() {
+                    active.path.truncate(parent_path_len);
+                }
+            });
+        }
+    }
+}
+
+/// Checks a field against the active filter. Returns None if the field should be hidden. matches_children is given
+/// the filter and the field's path, and returns true if anything nested inside the field matches. This is called by
+/// the derive for every field.
+pub fn begin_filtered_field<F: FnOnce(&InspectFilter, &str) -> bool>(
+    field_name: &str,
+    matches_children: F,
+    ui: &imgui::Ui,
+) -> Option<InspectFilterFieldToken> {
+    ACTIVE_FILTER.with(|x| {
+        let mut active = x.borrow_mut();
+        let active = match active.as_mut() {
+            Some(active) => active,
+            None => {
+                return Some(InspectFilterFieldToken {
+                    parent_path_len: None,
+                    style_token: None,
+                })
+            }
+        };
+
+        let parent_path_len = active.path.len();
+        let parent_matches = parent_path_len > 0 && active.filter.matches(&active.path);
+        if parent_path_len > 0 {
+            active.path.push('.');
+        }
+        active.path.push_str(field_name);
+
+        let is_match = active.filter.matches(&active.path);
+        if !is_match && !matches_children(&active.filter, &active.path) {
+            active.path.truncate(parent_path_len);
+            return None;
+        }
+
+        // Only the field where the match starts is highlighted, not everything under it
+        let style_token = if is_match && !parent_matches {
+            active.unhighlighted_text_color = ui.style_color(imgui::StyleColor::Text);
+            Some(ui.push_style_color(imgui::StyleColor::Text, FILTER_MATCH_TEXT_COLOR))
+        } else if parent_matches {
+            Some(ui.push_style_color(imgui::StyleColor::Text, active.unhighlighted_text_color))
+        } else {
+            None
+        };
+
+        Some(InspectFilterFieldToken {
+            parent_path_len: Some(parent_path_len),
+            style_token,
+        })
+    })
+}
+
+/// Forces the next collapsing header open if a filter is active. Fields are only drawn while filtering if they contain
+/// a match, so the match would otherwise be hidden in a closed header.
+pub fn filter_expand_next_header(_ui: &imgui::Ui) {
+    if is_filter_active() {
+        unsafe {
+            imgui::sys::igSetNextItemOpen(true, imgui::Condition::Always as imgui::sys::ImGuiCond);
+        }
+    }
+}
+
+pub(crate) fn is_filter_active() -> bool {
+    ACTIVE_FILTER.with(|x| x.borrow().is_some())
+}
+
+/// Returns true if anything nested inside a value of type T at the given path matches the filter. This is a helper for
+/// implementing `InspectRenderDefault::filter_matches_children` on wrapper types.
+pub fn filter_matches_children_of<T, R: InspectRenderDefault<T> + ?Sized>(
+    filter: &InspectFilter,
+    path: &str,
+) -> bool {
+    if path.matches('.').count() >= InspectFilter::MAX_PATH_DEPTH {
+        return false;
+    }
+
+    R::filter_matches_children(filter, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_anywhere_in_path() {
+        let filter = InspectFilter::new("pos");
+        assert!(filter.matches("position"));
+        assert!(filter.matches("transform.position.x"));
+        assert!(filter.matches("spawn_pos"));
+        assert!(!filter.matches("transform.rotation"));
+    }
+
+    #[test]
+    fn matching_ignores_case_and_surrounding_whitespace() {
+        let filter = InspectFilter::new("  Position ");
+        assert!(filter.matches("transform.position"));
+        assert!(filter.matches("Transform.POSITION"));
+        assert_eq!(filter.query(), "  Position ");
+    }
+
+    #[test]
+    fn blank_query_is_empty() {
+        assert!(InspectFilter::default().is_empty());
+        assert!(InspectFilter::new("   ").is_empty());
+        assert!(!InspectFilter::new("x").is_empty());
+    }
+
+    #[test]
+    fn scope_installs_and_restores_filter() {
+        assert!(!is_filter_active());
+        InspectFilter::new("x").scope(|| {
+            assert!(is_filter_active());
+
+            // Nested scopes replace the filter, and an empty one disables filtering
+            InspectFilter::default().scope(|| assert!(!is_filter_active()));
+            assert!(is_filter_active());
+        });
+        assert!(!is_filter_active());
+    }
+
+    #[test]
+    fn deep_paths_are_not_searched() {
+        let filter = InspectFilter::new("x");
+        let path = vec!["next"; InspectFilter::MAX_PATH_DEPTH + 1].join(".");
+        assert!(!filter_matches_children_of::<Option<f32>, Option<f32>>(
+            &filter, &path
+        ));
+    }
+
+    #[cfg(feature = "testing")]
+    mod fields {
+        use super::super::*;
+        use crate::testing::InspectTestContext;
+
+        // Draws a "transform" field containing "position" and "rotation", and returns the labels that were drawn
+        fn draw_fields(query: &str) -> Vec<String> {
+            let mut context = InspectTestContext::new();
+            let filter = InspectFilter::new(query);
+            let frame = context.run(|ui| {
+                filter.scope(|| {
+                    let matches_children = |filter: &InspectFilter, path: &str| {
+                        ["position", "rotation"]
+                            .iter()
+                            .any(|x| filter.matches(&format!("{}.{}", path, x)))
+                    };
+                    if let Some(token) = begin_filtered_field("transform", matches_children, ui) {
+                        ui.text("transform");
+                        for child in &["position", "rotation"] {
+                            if let Some(token) = begin_filtered_field(child, |_, _| false, ui) {
+                                ui.text(child);
+                                token.end(ui);
+                            }
+                        }
+                        token.end(ui);
+                    }
+                    if let Some(token) = begin_filtered_field("name", |_, _| false, ui) {
+                        ui.text("name");
+                        token.end(ui);
+                    }
+                })
+            });
+            frame.labels().map(|x| x.to_string()).collect()
+        }
+
+        #[test]
+        fn empty_filter_shows_everything() {
+            assert_eq!(
+                draw_fields(""),
+                ["transform", "position", "rotation", "name"]
+            );
+        }
+
+        #[test]
+        fn parents_of_matches_are_shown() {
+            assert_eq!(draw_fields("position"), ["transform", "position"]);
+        }
+
+        #[test]
+        fn children_of_matches_are_shown() {
+            assert_eq!(
+                draw_fields("transform"),
+                ["transform", "position", "rotation"]
+            );
+        }
+
+        #[test]
+        fn matches_use_the_full_path() {
+            assert_eq!(draw_fields("transform.rot"), ["transform", "rotation"]);
+            assert!(draw_fields("name.position").is_empty());
+        }
+
+        #[test]
+        fn only_the_first_match_is_highlighted() {
+            let mut context = InspectTestContext::new();
+            let filter = InspectFilter::new("transform");
+            let frame = context.run(|ui| {
+                filter.scope(|| {
+                    let token = begin_filtered_field("transform", |_, _| false, ui).unwrap();
+                    ui.text("transform");
+                    let child = begin_filtered_field("position", |_, _| false, ui).unwrap();
+                    ui.text("position");
+                    child.end(ui);
+                    token.end(ui);
+                })
+            });
+
+            assert_eq!(
+                frame.find_text("transform").unwrap().color,
+                FILTER_MATCH_TEXT_COLOR
+            );
+            assert_ne!(
+                frame.find_text("position").unwrap().color,
+                FILTER_MATCH_TEXT_COLOR
+            );
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use crate::scratch::scratch_im_str;
+use crate::document::{render_document, render_document_mut};
+use crate::widget_state::{widget_state_owner, with_widget_state};
+use crate::{InspectArgsDefault, InspectRenderDefault};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::marker::PhantomData;
+
+const SERDE_PENDING_STATE: &str = "inspect_serde_pending";
+
+/// Renders any type that implements `Serialize` and `Deserialize` by converting it to a tree of values, drawing the
+/// tree, and converting it back when it's edited. This is meant to be used as a proxy type for types that can't
+/// derive Inspect:
+///
+/// ```ignore
+/// #[derive(Inspect)]
+/// pub struct MyStruct {
+///     #[inspect(proxy_type = "InspectSerde<SomeSerdeType>")]
+///     pub value: SomeSerdeType,
+/// }
+/// ```
+///
+/// If an edit produces a tree that can't be converted back to the type (i.e. a string was cleared for an enum), the
+/// value isn't changed. The edited tree is kept so that it can be fixed or reverted, and the error is drawn under it.
+pub struct InspectSerde<T>(PhantomData<T>);
+
+fn render_serialize_error(
+    label: &'static str,
+    error: &serde_json::Error,
+    ui: &imgui::Ui,
+) {
+    ui.text_colored(
+        [1.0, 0.0, 0.0, 1.0],
+        scratch_im_str!("{}: <{}>", label, error),
+    );
+}
+
+impl<T: Serialize + DeserializeOwned> InspectRenderDefault<T> for InspectSerde<T> {
+    fn render(
+        data: &[&T],
+        label: &'static str,
+        ui: &imgui::Ui,
+        _args: &InspectArgsDefault,
+    ) {
+        let values: Result<Vec<Value>, _> = data.iter().map(serde_json::to_value).collect();
+        match values {
+            Ok(values) => {
+                let values: Vec<&Value> = values.iter().collect();
+                render_document(&values, label, ui);
+            }
+            Err(error) => render_serialize_error(label, &error, ui),
+        }
+    }
+
+    fn render_mut(
+        data: &mut [&mut T],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsDefault,
+    ) -> bool {
+        if data.is_empty() {
+            ui.text(scratch_im_str!("{}: None", label));
+            return false;
+        }
+
+        let owner = widget_state_owner(&*data[0], ui);
+        with_widget_state(
+            owner,
+            SERDE_PENDING_STATE,
+            |pending: &mut Option<Vec<Value>>| {
+                // Values that failed to convert back last frame are drawn instead of the current values
+                let pending_values = pending.take();
+                let had_pending_values = pending_values.is_some();
+                let mut values = match pending_values {
+                    Some(values) if values.len() == data.len() => values,
+                    _ => {
+                        let values: Result<Vec<Value>, _> =
+                            data.iter().map(|x| serde_json::to_value(&**x)).collect();
+                        match values {
+                            Ok(values) => values,
+                            Err(error) => {
+                                render_serialize_error(label, &error, ui);
+                                return false;
+                            }
+                        }
+                    }
+                };
+
+                let edited = {
+                    let mut value_refs: Vec<&mut Value> = values.iter_mut().collect();
+                    render_document_mut(&mut value_refs, label, ui, args, false)
+                };
+
+                if !edited && !had_pending_values {
+                    return false;
+                }
+
+                let results: Result<Vec<T>, _> = values.iter().map(T::deserialize).collect();
+                match results {
+                    Ok(results) => {
+                        for (d, result) in data.iter_mut().zip(results) {
+                            **d = result;
+                        }
+                        true
+                    }
+                    Err(error) => {
+                        ui.text_colored([1.0, 0.0, 0.0, 1.0], scratch_im_str!("{}", error));
+                        ui.same_line(0.0);
+                        if !ui.small_button(imgui::im_str!("Revert")) {
+                            *pending = Some(values);
+                        }
+                        false
+                    }
+                }
+            },
+        )
+    }
+}
@@ -0,0 +1,413 @@
+// imgui only keeps the open/closed state of headers for as long as the imgui context lives, so recreating the inspector
+// resets every header to its default. InspectLayoutState keeps this state (and the window's scroll position and
+// column widths) in a form that can be saved with the rest of an editor's settings.
+//
+// Like InspectFilter, the layout is installed for the current thread with InspectLayoutState::scope. Values with a
+// header (derived structs, lists and maps) push their label onto the layout path around everything they draw, so
+// headers are identified by their path from the root (i.e. "player.transform"), the same way filters match fields.
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+struct ActiveLayout {
+    headers: BTreeMap<String, bool>,
+    path: String,
+}
+
+thread_local! {
+    static ACTIVE_LAYOUT: RefCell<Option<ActiveLayout>> = const { RefCell::new(None) };
+}
+
+/// The layout of an inspector: which headers are open, how far the window is scrolled, and the widths of its columns.
+/// With the serde feature this can be serialized so that layouts survive restarts.
+///
+/// ```ignore
+/// ui.columns(2, im_str!("inspector_columns"), true);
+/// layout.sync_columns(ui);
+/// layout.scope(ui, || {
+///     <MyStruct as InspectRenderDefault<MyStruct>>::render_mut(&mut [&mut value], "value", ui, &args);
+/// });
+/// ```
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InspectLayoutState {
+    /// Whether each header that has been toggled or drawn is open, keyed by path (i.e. "player.transform")
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub headers: BTreeMap<String, bool>,
+
+    /// The scroll position of the window the inspector is drawn in
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub scroll: [f32; 2],
+
+    /// The widths of the columns passed to sync_columns
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub column_widths: Vec<f32>,
+
+    // Saved scroll positions and column widths are applied once, the first time they're used
+    #[cfg_attr(feature = "serde", serde(skip))]
+    scroll_restored: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    columns_restored: bool,
+}
+
+impl InspectLayoutState {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns whether the header at the given path is open, or None if it hasn't been drawn with this layout
+    pub fn is_open(
+        &self,
+        path: &str,
+    ) -> Option<bool> {
+        self.headers.get(path).copied()
+    }
+
+    /// Opens or closes the header at the given path the next time it's drawn
+    pub fn set_open(
+        &mut self,
+        path: &str,
+        is_open: bool,
+    ) {
+        self.headers.insert(path.to_string(), is_open);
+    }
+
+    /// Calls f with this layout applied to everything drawn on this thread. Headers are opened or closed to match the
+    /// layout, and toggling them updates it. This must be called inside the window the inspector is drawn in, which
+    /// is scrolled to the saved position the first time.
+    pub fn scope<R, F: FnOnce() -> R>(
+        &mut self,
+        ui: &imgui::Ui,
+        f: F,
+    ) -> R {
+        let restore_scroll = !self.scroll_restored;
+        if restore_scroll {
+            ui.set_scroll_x(self.scroll[0]);
+            ui.set_scroll_y(self.scroll[1]);
+            self.scroll_restored = true;
+        }
+
+        let active = ActiveLayout {
+            headers: std::mem::take(&mut self.headers),
+            path: String::new(),
+        };
+
+        let previous = ACTIVE_LAYOUT.with(|x| x.replace(Some(active)));
+        let result = f();
+        let active = ACTIVE_LAYOUT.with(|x| x.replace(previous));
+        if let Some(active) = active {
+            self.headers = active.headers;
+        }
+
+        // The saved position isn't applied until the next frame
+        if !restore_scroll {
+            self.scroll = [ui.scroll_x(), ui.scroll_y()];
+        }
+
+        result
+    }
+
+    /// Restores the saved column widths the first time it's called, and records the current widths after that. Call
+    /// this right after `ui.columns(...)`. Widths saved for a different number of columns are ignored.
+    pub fn sync_columns(
+        &mut self,
+        ui: &imgui::Ui,
+    ) {
+        let column_count = ui.column_count();
+        if !self.columns_restored {
+            self.columns_restored = true;
+            if self.column_widths.len() == column_count as usize {
+                for (index, width) in self.column_widths.iter().enumerate() {
+                    ui.set_column_width(index as i32, *width);
+                }
+                return;
+            }
+        }
+
+        self.column_widths = (0..column_count).map(|x| ui.column_width(x)).collect();
+    }
+}
+
+/// Returned by push_layout_path. `end` must be called after everything under the path is drawn.
+pub struct InspectLayoutPathToken {
+    parent_path_len: Option<usize>,
+}
+
+impl InspectLayoutPathToken {
+    pub fn end(self) {
+        if let Some(parent_path_len) = self.parent_path_len {
+            ACTIVE_LAYOUT.with(|x| {
+                if let Some(active) = x.borrow_mut().as_mut() {
+                    active.path.truncate(parent_path_len);
+                }
+            });
+        }
+    }
+}
+
+/// Appends a label to the layout path if an InspectLayoutState is active. Custom impls that draw a header should push
+/// their label around everything they draw, and call layout_restore_next_header and layout_record_header around the
+/// header itself. Empty labels (i.e. list elements, which push their index instead) don't change the path.
+pub fn push_layout_path(label: &str) -> InspectLayoutPathToken {
+    ACTIVE_LAYOUT.with(|x| {
+        let mut active = x.borrow_mut();
+        let active = match active.as_mut() {
+            Some(active) => active,
+            None => {
+                return InspectLayoutPathToken {
+                    parent_path_len: None,
+                }
+            }
+        };
+
+        let parent_path_len = active.path.len();
+        if label.is_empty() {
+            return InspectLayoutPathToken {
+                parent_path_len: Some(parent_path_len),
+            };
+        }
+
+        if parent_path_len > 0 {
+            active.path.push('.');
+        }
+        active.path.push_str(label);
+
+        InspectLayoutPathToken {
+            parent_path_len: Some(parent_path_len),
+        }
+    })
+}
+
+/// Opens or closes the next collapsing header or tree node to match the active layout. Headers that aren't in the
+/// layout yet keep their default.
+pub fn layout_restore_next_header(_ui: &imgui::Ui) {
+    let is_open = ACTIVE_LAYOUT.with(|x| {
+        x.borrow()
+            .as_ref()
+            .and_then(|active| active.headers.get(&active.path).copied())
+    });
+
+    if let Some(is_open) = is_open {
+        unsafe {
+            imgui::sys::igSetNextItemOpen(
+                is_open,
+                imgui::Condition::Always as imgui::sys::ImGuiCond,
+            );
+        }
+    }
+}
+
+/// Records whether the header at the current layout path is open. Headers forced open by a filter aren't recorded,
+/// so searching doesn't change the layout.
+pub fn layout_record_header(is_open: bool) {
+    if crate::filter::is_filter_active() {
+        return;
+    }
+
+    ACTIVE_LAYOUT.with(|x| {
+        if let Some(active) = x.borrow_mut().as_mut() {
+            if active.headers.get(&active.path) != Some(&is_open) {
+                let path = active.path.clone();
+                active.headers.insert(path, is_open);
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Installs a layout without a ui, for testing paths. Returns the headers recorded by f.
+    fn with_layout<F: FnOnce()>(
+        headers: BTreeMap<String, bool>,
+        f: F,
+    ) -> BTreeMap<String, bool> {
+        let active = ActiveLayout {
+            headers,
+            path: String::new(),
+        };
+        let previous = ACTIVE_LAYOUT.with(|x| x.replace(Some(active)));
+        f();
+        ACTIVE_LAYOUT.with(|x| x.replace(previous)).unwrap().headers
+    }
+
+    fn current_path() -> String {
+        ACTIVE_LAYOUT.with(|x| x.borrow().as_ref().unwrap().path.clone())
+    }
+
+    #[test]
+    fn paths_are_joined_with_dots() {
+        with_layout(BTreeMap::new(), || {
+            let player = push_layout_path("player");
+            assert_eq!(current_path(), "player");
+
+            let transform = push_layout_path("transform");
+            assert_eq!(current_path(), "player.transform");
+            transform.end();
+
+            let inventory = push_layout_path("inventory");
+            assert_eq!(current_path(), "player.inventory");
+            inventory.end();
+
+            player.end();
+            assert_eq!(current_path(), "");
+        });
+    }
+
+    #[test]
+    fn empty_labels_keep_the_parent_path() {
+        with_layout(BTreeMap::new(), || {
+            let items = push_layout_path("items");
+            let item = push_layout_path("");
+            assert_eq!(current_path(), "items");
+
+            let child = push_layout_path("[0]");
+            assert_eq!(current_path(), "items.[0]");
+            child.end();
+
+            item.end();
+            assert_eq!(current_path(), "items");
+            items.end();
+        });
+    }
+
+    #[test]
+    fn headers_are_recorded_by_path() {
+        let headers = with_layout(BTreeMap::new(), || {
+            let player = push_layout_path("player");
+            layout_record_header(true);
+
+            let transform = push_layout_path("transform");
+            layout_record_header(false);
+            transform.end();
+            player.end();
+        });
+
+        assert_eq!(headers.get("player"), Some(&true));
+        assert_eq!(headers.get("player.transform"), Some(&false));
+        assert_eq!(headers.len(), 2);
+    }
+
+    #[test]
+    fn headers_are_not_recorded_while_filtering() {
+        let headers = with_layout(BTreeMap::new(), || {
+            crate::InspectFilter::new("x").scope(|| {
+                let player = push_layout_path("player");
+                layout_record_header(true);
+                player.end();
+            });
+        });
+
+        assert!(headers.is_empty());
+    }
+
+    #[test]
+    fn paths_do_nothing_without_a_layout() {
+        let token = push_layout_path("player");
+        layout_record_header(true);
+        token.end();
+        assert!(ACTIVE_LAYOUT.with(|x| x.borrow().is_none()));
+    }
+
+    #[test]
+    fn set_open_overrides_header() {
+        let mut layout = InspectLayoutState::new();
+        assert_eq!(layout.is_open("player"), None);
+
+        layout.set_open("player", true);
+        assert_eq!(layout.is_open("player"), Some(true));
+
+        layout.set_open("player", false);
+        assert_eq!(layout.is_open("player"), Some(false));
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn layout_round_trips_through_json() {
+        let mut layout = InspectLayoutState::new();
+        layout.set_open("player.transform", true);
+        layout.scroll = [0.0, 120.0];
+        layout.column_widths = vec![150.0, 250.0];
+        layout.scroll_restored = true;
+
+        let json = serde_json::to_string(&layout).unwrap();
+        let restored: InspectLayoutState = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.headers, layout.headers);
+        assert_eq!(restored.scroll, layout.scroll);
+        assert_eq!(restored.column_widths, layout.column_widths);
+
+        // A loaded layout is applied again the first time it's used
+        assert!(!restored.scroll_restored);
+        assert!(!restored.columns_restored);
+
+        // Older saves may be missing fields
+        let empty: InspectLayoutState = serde_json::from_str("{}").unwrap();
+        assert!(empty.headers.is_empty());
+    }
+
+    #[cfg(feature = "testing")]
+    mod headers {
+        use super::super::*;
+        use crate::testing::InspectTestContext;
+
+        // Draws a "player" header, open by default, containing a "health" label
+        fn draw_header(
+            context: &mut InspectTestContext,
+            layout: &mut InspectLayoutState,
+        ) -> bool {
+            let mut is_open = false;
+            context.run(|ui| {
+                layout.scope(ui, || {
+                    let token = push_layout_path("player");
+                    layout_restore_next_header(ui);
+                    is_open = imgui::CollapsingHeader::new(imgui::im_str!("player"))
+                        .default_open(true)
+                        .build(ui);
+                    layout_record_header(is_open);
+                    if is_open {
+                        ui.text("health");
+                    }
+                    token.end();
+                });
+            });
+            is_open
+        }
+
+        #[test]
+        fn drawn_headers_are_recorded() {
+            let mut context = InspectTestContext::new();
+            let mut layout = InspectLayoutState::new();
+            assert!(draw_header(&mut context, &mut layout));
+            assert_eq!(layout.is_open("player"), Some(true));
+        }
+
+        #[test]
+        fn saved_state_is_restored() {
+            let mut context = InspectTestContext::new();
+            let mut layout = InspectLayoutState::new();
+            layout.set_open("player", false);
+
+            assert!(!draw_header(&mut context, &mut layout));
+            assert!(!context.last_frame().contains_text("health"));
+        }
+
+        #[test]
+        fn toggling_updates_the_layout() {
+            let mut context = InspectTestContext::new();
+            let mut layout = InspectLayoutState::new();
+            draw_header(&mut context, &mut layout);
+
+            context.click("player").unwrap();
+            assert!(!draw_header(&mut context, &mut layout));
+            assert_eq!(layout.is_open("player"), Some(false));
+
+            // The toggled state carries over to a new imgui context, which would otherwise open the header again
+            drop(context);
+            let mut context = InspectTestContext::new();
+            assert!(!draw_header(&mut context, &mut layout));
+        }
+    }
+}
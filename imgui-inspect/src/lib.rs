@@ -1,14 +1,58 @@
+mod curve;
 mod default;
+mod plot;
 mod slider;
+#[cfg(feature = "serde")]
+mod inspect_serde;
 
+mod clipboard;
+mod collection;
+#[cfg(feature = "egui")]
+mod egui_visitor;
+mod filter;
+mod layout;
+mod list;
+#[cfg(any(
+    feature = "glam",
+    feature = "nalgebra",
+    feature = "cgmath",
+    feature = "mint"
+))]
+mod math;
+#[cfg(any(feature = "serde_json", feature = "toml"))]
+mod document;
+mod reflect;
+mod scratch;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod text_value;
+mod visitor;
+mod widget_id;
+mod widget_state;
+
+use scratch::scratch_im_str;
+
+pub use curve::*;
 pub use default::*;
+pub use plot::*;
 pub use slider::*;
+pub use clipboard::*;
+pub use filter::*;
+pub use layout::*;
+pub use reflect::{InspectFieldInfo, InspectReflect, InspectSetFieldError};
+pub use visitor::*;
+pub use widget_id::*;
+#[cfg(feature = "egui")]
+pub use egui_visitor::EguiInspectVisitor;
+#[cfg(feature = "serde")]
+pub use inspect_serde::InspectSerde;
 
 /// Options for rendering a value as a struct (i.e. draw all of its subfields)
 #[derive(Debug, Default)]
 pub struct InspectArgsStruct {
     pub header: Option<bool>,
     pub indent_children: Option<bool>,
+    pub default_open: Option<bool>,
 }
 
 impl From<InspectArgsDefault> for InspectArgsStruct {
@@ -16,6 +60,18 @@ impl From<InspectArgsDefault> for InspectArgsStruct {
         Self {
             header: default_args.header,
             indent_children: default_args.indent_children,
+            default_open: default_args.default_open,
+        }
+    }
+}
+
+impl From<InspectArgsStruct> for InspectArgsDefault {
+    fn from(struct_args: InspectArgsStruct) -> Self {
+        Self {
+            header: struct_args.header,
+            indent_children: struct_args.indent_children,
+            default_open: struct_args.default_open,
+            ..Default::default()
         }
     }
 }
@@ -37,34 +93,68 @@ pub trait InspectRenderStruct<T> {
     ) -> bool;
 }
 
-/// Utility function that, given a list of references, returns Some(T) if they are the same, otherwise None
-pub fn get_same_or_none<T: PartialEq + Clone>(data: &[&T]) -> Option<T> {
-    if data.is_empty() {
-        return None;
+/// Text color used for fields whose value differs from their default
+pub const MODIFIED_FIELD_TEXT_COLOR: [f32; 4] = [0.4, 0.8, 1.0, 1.0];
+
+/// Pushes a text color marking a field as modified from its default. Returns None if the field is not modified. The
+/// token must be popped after the field is drawn.
+///
+/// Values that are inconsistent across the selection are still drawn in the usual "inconsistent" color since widgets
+/// push their own style on top of this one.
+pub fn push_modified_style(
+    is_modified: bool,
+    ui: &imgui::Ui,
+) -> Option<imgui::ColorStackToken> {
+    if is_modified {
+        Some(ui.push_style_color(imgui::StyleColor::Text, MODIFIED_FIELD_TEXT_COLOR))
+    } else {
+        None
     }
+}
 
-    let first = data[0].clone();
-    for d in data {
-        if **d != first {
-            return None;
-        }
+/// Returns true if the input this frame could edit a value: an item is active (i.e. a drag or text edit is in
+/// progress), or a mouse button, key, or gamepad navigation input is down. Widgets only change values in response to
+/// input, so work that's only needed when a value changes (like capturing old values for `on_set_old_value`) can be
+/// skipped on frames where this is false.
+pub fn is_edit_possible(ui: &imgui::Ui) -> bool {
+    if ui.is_any_item_active() {
+        return true;
     }
 
-    Some(first)
+    let io = ui.io();
+    io.mouse_down.iter().any(|x| *x)
+        || io.mouse_wheel != 0.0
+        || io.keys_down.iter().any(|x| *x)
+        || io.nav_inputs.iter().any(|x| *x > 0.0)
 }
 
 /// Utility function that, given a list of references, returns Some(T) if they are the same, otherwise None
-fn get_same_or_none_mut<T: PartialEq + Clone>(data: &mut [&mut T]) -> Option<T> {
-    if data.is_empty() {
-        return None;
-    }
+pub fn get_same_or_none<T: PartialEq + Clone>(data: &[&T]) -> Option<T> {
+    get_same_or_none_ref(data).cloned()
+}
 
-    let first = data[0].clone();
-    for d in data {
-        if **d != first {
-            return None;
-        }
+/// Like get_same_or_none, but returns a reference to the first value instead of cloning it. Prefer this for types that
+/// are expensive to clone.
+pub fn get_same_or_none_ref<'a, T: PartialEq>(data: &[&'a T]) -> Option<&'a T> {
+    let first = *data.first()?;
+    if data[1..].iter().all(|x| **x == *first) {
+        Some(first)
+    } else {
+        None
     }
+}
 
-    Some(first)
+/// Utility function that, given a list of references, returns Some(T) if they are the same, otherwise None
+fn get_same_or_none_mut<T: PartialEq + Clone>(data: &mut [&mut T]) -> Option<T> {
+    get_same_or_none_mut_ref(data).cloned()
+}
+
+/// Like get_same_or_none_mut, but returns a reference to the first value instead of cloning it
+fn get_same_or_none_mut_ref<'a, T: PartialEq>(data: &'a [&mut T]) -> Option<&'a T> {
+    let first = &**data.first()?;
+    if data[1..].iter().all(|x| **x == *first) {
+        Some(first)
+    } else {
+        None
+    }
 }
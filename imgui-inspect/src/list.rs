@@ -0,0 +1,354 @@
+// Shared widgets for lists (Vec, VecDeque). Lists can be very long (i.e. 100k particles), so:
+//  - Lists longer than a page are split into pages, with controls for changing pages and jumping to an index
+//  - Rows are skipped with imgui's list clipper if they aren't visible. This needs every row to be the same height, so
+//    row heights are measured as they're drawn, and clipping is only used while they all match. Expanding a row (i.e.
+//    opening a struct's header) turns clipping off until it's collapsed again.
+//  - When the list is collapsed, its header shows a summary (the length, and the min/max for numbers of short lists)
+//
+// Labels passed to InspectRenderDefault must be 'static, so elements are drawn with an empty label (LIST_ITEM_LABEL)
+// and the row draws its index in front of the element instead. The index is also pushed onto the imgui ID stack and
+// the layout path, so rows keep their own state.
+//
+// When multiple lists are selected, rows are shown up to the length of the longest one. Rows that aren't in every
+// selected list are drawn as inconsistent.
+use crate::scratch::scratch_im_str;
+use crate::widget_state::{widget_state_owner, with_widget_state, WidgetStateOwner};
+use crate::{
+    claim_field_context_menu, layout_record_header, layout_restore_next_header, push_layout_path,
+    InspectArgsDefault, InspectLayoutPathToken, InspectRenderDefault,
+};
+use std::any::Any;
+
+const LIST_STATE: &str = "inspect_list";
+const DEFAULT_PAGE_SIZE: usize = 100;
+const MIN_PAGE_SIZE: usize = 10;
+
+// Lists longer than this don't show a min/max in their summary, since it's computed from every element
+const MAX_SUMMARY_LEN: usize = 10_000;
+
+/// The label that list elements are drawn and visited with. See `begin_list_item`
+pub(crate) const LIST_ITEM_LABEL: &str = "";
+
+/// Operations needed to draw a list
+pub(crate) trait InspectListLike {
+    type Item: InspectRenderDefault<Self::Item> + 'static;
+
+    fn item_count(&self) -> usize;
+
+    fn get_item(
+        &self,
+        index: usize,
+    ) -> Option<&Self::Item>;
+
+    fn get_item_mut(
+        &mut self,
+        index: usize,
+    ) -> Option<&mut Self::Item>;
+}
+
+struct ListState {
+    page: usize,
+    page_size: i32,
+    jump_to_index: i32,
+    scroll_to_index: Option<usize>,
+    was_open: bool,
+    // Some if every row drawn last frame was this tall, in which case rows can be clipped
+    row_height: Option<f32>,
+}
+
+impl Default for ListState {
+    fn default() -> Self {
+        ListState {
+            page: 0,
+            page_size: DEFAULT_PAGE_SIZE as i32,
+            jump_to_index: 0,
+            scroll_to_index: None,
+            was_open: false,
+            row_height: None,
+        }
+    }
+}
+
+/// Draws a list element's index, and pushes it onto the layout path. The element is drawn on the same line with
+/// LIST_ITEM_LABEL. The caller is expected to have pushed the index onto the imgui ID stack.
+pub(crate) fn begin_list_item(
+    index: usize,
+    ui: &imgui::Ui,
+) -> InspectLayoutPathToken {
+    let index_label = scratch_im_str!("[{}]", index);
+    ui.text(&index_label);
+    ui.same_line(0.0);
+    push_layout_path(index_label.to_str())
+}
+
+// The min and max of a list of numbers. Other types don't have a summary.
+fn numeric_range<'a, T: Any, I: Iterator<Item = &'a T>>(items: I) -> Option<(f64, f64)> {
+    fn as_f64(value: &dyn Any) -> Option<f64> {
+        macro_rules! try_downcast {
+            ($($t:ty),*) => {
+                $(
+                    if let Some(v) = value.downcast_ref::<$t>() {
+                        return Some(*v as f64);
+                    }
+                )*
+            };
+        }
+
+        try_downcast!(f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+        None
+    }
+
+    let mut range: Option<(f64, f64)> = None;
+    for item in items {
+        let value = as_f64(item)?;
+        range = Some(match range {
+            Some((min, max)) => (min.min(value), max.max(value)),
+            None => (value, value),
+        });
+    }
+
+    range
+}
+
+// Draws the paging controls, and returns the range of indices on the current page
+fn render_paging(
+    state: &mut ListState,
+    len: usize,
+    ui: &imgui::Ui,
+) -> std::ops::Range<usize> {
+    let page_size = (state.page_size.max(MIN_PAGE_SIZE as i32)) as usize;
+    if len <= page_size {
+        state.page = 0;
+        return 0..len;
+    }
+
+    let page_count = len.div_ceil(page_size);
+    state.page = state.page.min(page_count - 1);
+
+    if ui.small_button(imgui::im_str!("<")) && state.page > 0 {
+        state.page -= 1;
+    }
+    ui.same_line(0.0);
+    ui.text(scratch_im_str!("Page {} of {}", state.page + 1, page_count));
+    ui.same_line(0.0);
+    if ui.small_button(imgui::im_str!(">")) && state.page + 1 < page_count {
+        state.page += 1;
+    }
+
+    ui.set_next_item_width(100.0);
+    ui.input_int(imgui::im_str!("##jump_to_index"), &mut state.jump_to_index)
+        .build();
+    ui.same_line(0.0);
+    if ui.small_button(imgui::im_str!("Go to Index")) {
+        let index = (state.jump_to_index.max(0) as usize).min(len - 1);
+        state.page = index / page_size;
+        state.scroll_to_index = Some(index);
+    }
+
+    ui.set_next_item_width(100.0);
+    ui.input_int(imgui::im_str!("Page Size"), &mut state.page_size)
+        .build();
+
+    let start = state.page * page_size;
+    start..(start + page_size).min(len)
+}
+
+// Draws a single row, and returns its height
+fn render_row<F: FnMut(usize)>(
+    state: &mut ListState,
+    index: usize,
+    ui: &imgui::Ui,
+    draw_row: &mut F,
+) -> f32 {
+    let start_y = ui.cursor_pos()[1];
+
+    let id_token = ui.push_id(index as i32);
+    let layout_token = begin_list_item(index, ui);
+    draw_row(index);
+    layout_token.end();
+    id_token.pop(ui);
+
+    if state.scroll_to_index == Some(index) {
+        ui.set_scroll_here_y();
+        state.scroll_to_index = None;
+    }
+
+    ui.cursor_pos()[1] - start_y
+}
+
+// The summary is only shown while the list is collapsed. It's computed from every element, so it's skipped if the
+// list was open last frame, if the header is scrolled out of view, or if the list is long.
+fn summarize_list<L: InspectListLike>(
+    owner: WidgetStateOwner,
+    list: &L,
+    ui: &imgui::Ui,
+) -> Option<(f64, f64)> {
+    let was_open = with_widget_state(owner, LIST_STATE, |state: &mut ListState| state.was_open);
+    let len = list.item_count();
+    if was_open || len > MAX_SUMMARY_LEN || !is_next_row_visible(ui) {
+        None
+    } else {
+        numeric_range((0..len).filter_map(|i| list.get_item(i)))
+    }
+}
+
+// Returns true if a single line drawn at the cursor would be inside the window's visible area
+fn is_next_row_visible(ui: &imgui::Ui) -> bool {
+    let size = imgui::sys::ImVec2 {
+        x: 1.0,
+        y: ui.text_line_height_with_spacing(),
+    };
+    unsafe { imgui::sys::igIsRectVisibleNil(size) }
+}
+
+// Draws the list's tree node, paging controls, and the visible rows on the current page
+fn render_list_rows<F: FnMut(usize)>(
+    owner: WidgetStateOwner,
+    label: &str,
+    len: usize,
+    summary: Option<(f64, f64)>,
+    ui: &imgui::Ui,
+    mut draw_row: F,
+) {
+    let layout_token = push_layout_path(label);
+    with_widget_state(owner, LIST_STATE, |state: &mut ListState| {
+        // The ### keeps the ID stable when the length or summary changes so that the node doesn't close
+        let node_label = match summary {
+            Some((min, max)) => {
+                scratch_im_str!(
+                    "{} ({}, min: {}, max: {})###{}",
+                    label,
+                    len,
+                    min,
+                    max,
+                    label
+                )
+            }
+            None => scratch_im_str!("{} ({})###{}", label, len, label),
+        };
+
+        layout_restore_next_header(ui);
+        let node_token = imgui::TreeNode::new(&node_label).push(ui);
+        claim_field_context_menu(ui);
+        layout_record_header(node_token.is_some());
+        state.was_open = node_token.is_some();
+        let node_token = match node_token {
+            Some(node_token) => node_token,
+            None => return,
+        };
+
+        let rows = render_paging(state, len, ui);
+
+        // Rows are measured as they're drawn. If they're all the same height, rows are clipped next frame
+        let mut row_height = None;
+        let mut rows_are_uniform = true;
+        let mut measure = |height: f32| match row_height {
+            None => row_height = Some(height),
+            Some(h) => rows_are_uniform &= (h - height).abs() < 0.5,
+        };
+
+        match state.row_height {
+            // Clipping would skip the row being scrolled to
+            Some(height) if state.scroll_to_index.is_none() => {
+                let mut clipper = imgui::ListClipper::new(rows.len() as i32)
+                    .items_height(height)
+                    .begin(ui);
+                while clipper.step() {
+                    for i in clipper.display_start()..clipper.display_end() {
+                        measure(render_row(
+                            state,
+                            rows.start + i as usize,
+                            ui,
+                            &mut draw_row,
+                        ));
+                    }
+                }
+                clipper.end();
+            }
+            _ => {
+                for index in rows {
+                    measure(render_row(state, index, ui, &mut draw_row));
+                }
+            }
+        }
+
+        state.row_height = if rows_are_uniform { row_height } else { None };
+        node_token.pop(ui);
+    });
+    layout_token.end();
+}
+
+/// Draws a list read-only
+pub(crate) fn render_list<L: InspectListLike>(
+    data: &[&L],
+    label: &str,
+    ui: &imgui::Ui,
+    args: &InspectArgsDefault,
+) {
+    if data.is_empty() {
+        ui.text(scratch_im_str!("{}: None", label));
+        return;
+    }
+
+    let len = data.iter().map(|x| x.item_count()).max().unwrap_or(0);
+    let owner = widget_state_owner(data[0], ui);
+    let summary = summarize_list(owner, data[0], ui);
+    render_list_rows(owner, label, len, summary, ui, |index| {
+        let values: Vec<_> = data.iter().filter_map(|x| x.get_item(index)).collect();
+        let style_token = push_missing_style(values.len() != data.len(), ui);
+        <L::Item as InspectRenderDefault<L::Item>>::render(&values, LIST_ITEM_LABEL, ui, args);
+        if let Some(style_token) = style_token {
+            style_token.pop(ui);
+        }
+    });
+}
+
+/// Draws a list with widgets for editing its elements. Returns true if any element changed.
+pub(crate) fn render_list_mut<L: InspectListLike>(
+    data: &mut [&mut L],
+    label: &str,
+    ui: &imgui::Ui,
+    args: &InspectArgsDefault,
+) -> bool {
+    if data.is_empty() {
+        ui.text(scratch_im_str!("{}: None", label));
+        return false;
+    }
+
+    let len = data.iter().map(|x| x.item_count()).max().unwrap_or(0);
+    let owner = widget_state_owner(&*data[0], ui);
+    let summary = summarize_list(owner, &*data[0], ui);
+    let selected_count = data.len();
+
+    let mut changed = false;
+    render_list_rows(owner, label, len, summary, ui, |index| {
+        let mut values: Vec<_> = data
+            .iter_mut()
+            .filter_map(|x| x.get_item_mut(index))
+            .collect();
+        let style_token = push_missing_style(values.len() != selected_count, ui);
+        changed |= <L::Item as InspectRenderDefault<L::Item>>::render_mut(
+            &mut values,
+            LIST_ITEM_LABEL,
+            ui,
+            args,
+        );
+        if let Some(style_token) = style_token {
+            style_token.pop(ui);
+        }
+    });
+
+    changed
+}
+
+// Marks a row as inconsistent if it isn't in every selected list
+fn push_missing_style(
+    is_missing: bool,
+    ui: &imgui::Ui,
+) -> Option<imgui::ColorStackToken> {
+    if is_missing {
+        Some(ui.push_style_color(imgui::StyleColor::Text, [1.0, 1.0, 0.0, 1.0]))
+    } else {
+        None
+    }
+}
@@ -0,0 +1,887 @@
+// Shared widgets for math types. The impls for each math crate convert to and from plain arrays and call these, so that
+// vectors, rotations, and transforms look and behave the same no matter which crate they come from.
+//
+// Rotations are edited as Euler angles in degrees, applied about X, then Y, then Z. More than one set of angles
+// produces the same rotation, so the angles being edited are remembered as long as the rotation they produced hasn't
+// been changed by something else. This keeps the angles from jumping to an equivalent set while they are being
+// dragged.
+//
+// Matrices are column-major [[f32; 4]; 4]. A 3x3 matrix is stored in the upper left with no translation.
+use crate::scratch::scratch_im_str;
+use crate::widget_state::{widget_state_owner, with_widget_state};
+
+const ROTATION_STATE: &str = "inspect_math_rotation";
+
+/// Fixed-size arrays of floats that can be edited with a single inline widget
+pub(crate) trait VectorArray: Copy + PartialEq + Default + std::fmt::Debug {
+    fn components(&self) -> &[f32];
+    fn components_mut(&mut self) -> &mut [f32];
+
+    fn input(
+        &mut self,
+        label: &imgui::ImStr,
+        ui: &imgui::Ui,
+    ) -> bool;
+}
+
+impl VectorArray for [f32; 1] {
+    fn components(&self) -> &[f32] {
+        self
+    }
+
+    fn components_mut(&mut self) -> &mut [f32] {
+        self
+    }
+
+    fn input(
+        &mut self,
+        label: &imgui::ImStr,
+        ui: &imgui::Ui,
+    ) -> bool {
+        ui.input_float(label, &mut self[0]).build()
+    }
+}
+
+impl VectorArray for [f32; 2] {
+    fn components(&self) -> &[f32] {
+        self
+    }
+
+    fn components_mut(&mut self) -> &mut [f32] {
+        self
+    }
+
+    fn input(
+        &mut self,
+        label: &imgui::ImStr,
+        ui: &imgui::Ui,
+    ) -> bool {
+        ui.input_float2(label, self).build()
+    }
+}
+
+impl VectorArray for [f32; 3] {
+    fn components(&self) -> &[f32] {
+        self
+    }
+
+    fn components_mut(&mut self) -> &mut [f32] {
+        self
+    }
+
+    fn input(
+        &mut self,
+        label: &imgui::ImStr,
+        ui: &imgui::Ui,
+    ) -> bool {
+        ui.input_float3(label, self).build()
+    }
+}
+
+impl VectorArray for [f32; 4] {
+    fn components(&self) -> &[f32] {
+        self
+    }
+
+    fn components_mut(&mut self) -> &mut [f32] {
+        self
+    }
+
+    fn input(
+        &mut self,
+        label: &imgui::ImStr,
+        ui: &imgui::Ui,
+    ) -> bool {
+        ui.input_float4(label, self).build()
+    }
+}
+
+/// How the scale of a transform can be edited
+// Not every math crate has a transform with each kind of scale
+#[allow(dead_code)]
+#[derive(Copy, Clone, PartialEq)]
+pub(crate) enum TransformScale {
+    None,
+    Uniform,
+    NonUniform,
+}
+
+/// A transform split into translation, rotation (as a quaternion in x, y, z, w order), and scale
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub(crate) struct Trs {
+    pub translation: [f32; 3],
+    pub rotation: [f32; 4],
+    pub scale: [f32; 3],
+}
+
+impl Default for Trs {
+    fn default() -> Self {
+        Trs {
+            translation: [0.0; 3],
+            rotation: [0.0, 0.0, 0.0, 1.0],
+            scale: [1.0; 3],
+        }
+    }
+}
+
+/// Converts Euler angles in degrees (applied about X, then Y, then Z) to a quaternion
+pub(crate) fn euler_degrees_to_quat(euler: [f32; 3]) -> [f32; 4] {
+    let (sx, cx) = (euler[0].to_radians() * 0.5).sin_cos();
+    let (sy, cy) = (euler[1].to_radians() * 0.5).sin_cos();
+    let (sz, cz) = (euler[2].to_radians() * 0.5).sin_cos();
+
+    [
+        sx * cy * cz - cx * sy * sz,
+        cx * sy * cz + sx * cy * sz,
+        cx * cy * sz - sx * sy * cz,
+        cx * cy * cz + sx * sy * sz,
+    ]
+}
+
+/// Converts a quaternion to Euler angles in degrees (applied about X, then Y, then Z)
+pub(crate) fn quat_to_euler_degrees(q: [f32; 4]) -> [f32; 3] {
+    let [x, y, z, w] = q;
+
+    let sin_y = (2.0 * (w * y - z * x)).clamp(-1.0, 1.0);
+    [
+        (2.0 * (w * x + y * z))
+            .atan2(1.0 - 2.0 * (x * x + y * y))
+            .to_degrees(),
+        sin_y.asin().to_degrees(),
+        (2.0 * (w * z + x * y))
+            .atan2(1.0 - 2.0 * (y * y + z * z))
+            .to_degrees(),
+    ]
+}
+
+// Compares rotations, allowing for error from converting between representations. q and -q are the same rotation.
+fn quat_approx_eq(
+    a: [f32; 4],
+    b: [f32; 4],
+) -> bool {
+    const EPSILON: f32 = 1e-4;
+    let same = (0..4).all(|i| (a[i] - b[i]).abs() < EPSILON);
+    let negated = (0..4).all(|i| (a[i] + b[i]).abs() < EPSILON);
+    same || negated
+}
+
+/// Splits a matrix into translation, rotation, and scale. Shear can't be represented and is lost if the parts are
+/// edited and recombined.
+pub(crate) fn matrix_to_trs(m: &[[f32; 4]; 4]) -> Trs {
+    let length = |c: &[f32; 4]| (c[0] * c[0] + c[1] * c[1] + c[2] * c[2]).sqrt();
+    let mut scale = [length(&m[0]), length(&m[1]), length(&m[2])];
+
+    let determinant = m[0][0] * (m[1][1] * m[2][2] - m[2][1] * m[1][2])
+        - m[1][0] * (m[0][1] * m[2][2] - m[2][1] * m[0][2])
+        + m[2][0] * (m[0][1] * m[1][2] - m[1][1] * m[0][2]);
+    if determinant < 0.0 {
+        scale[0] = -scale[0];
+    }
+
+    // Remove the scale to get a pure rotation matrix. r[column][row]
+    let mut r = [[0.0; 3]; 3];
+    for column in 0..3 {
+        if scale[column] != 0.0 {
+            for row in 0..3 {
+                r[column][row] = m[column][row] / scale[column];
+            }
+        }
+    }
+
+    let trace = r[0][0] + r[1][1] + r[2][2];
+    let rotation = if trace > 0.0 {
+        let s = (trace + 1.0).sqrt() * 2.0;
+        [
+            (r[1][2] - r[2][1]) / s,
+            (r[2][0] - r[0][2]) / s,
+            (r[0][1] - r[1][0]) / s,
+            0.25 * s,
+        ]
+    } else if r[0][0] > r[1][1] && r[0][0] > r[2][2] {
+        let s = (1.0 + r[0][0] - r[1][1] - r[2][2]).sqrt() * 2.0;
+        [
+            0.25 * s,
+            (r[1][0] + r[0][1]) / s,
+            (r[2][0] + r[0][2]) / s,
+            (r[1][2] - r[2][1]) / s,
+        ]
+    } else if r[1][1] > r[2][2] {
+        let s = (1.0 + r[1][1] - r[0][0] - r[2][2]).sqrt() * 2.0;
+        [
+            (r[1][0] + r[0][1]) / s,
+            0.25 * s,
+            (r[2][1] + r[1][2]) / s,
+            (r[2][0] - r[0][2]) / s,
+        ]
+    } else {
+        let s = (1.0 + r[2][2] - r[0][0] - r[1][1]).sqrt() * 2.0;
+        [
+            (r[2][0] + r[0][2]) / s,
+            (r[2][1] + r[1][2]) / s,
+            0.25 * s,
+            (r[0][1] - r[1][0]) / s,
+        ]
+    };
+
+    Trs {
+        translation: [m[3][0], m[3][1], m[3][2]],
+        rotation,
+        scale,
+    }
+}
+
+/// Combines translation, rotation, and scale into a matrix
+pub(crate) fn trs_to_matrix(trs: &Trs) -> [[f32; 4]; 4] {
+    let [x, y, z, w] = trs.rotation;
+    let [sx, sy, sz] = trs.scale;
+    let [tx, ty, tz] = trs.translation;
+
+    [
+        [
+            (1.0 - 2.0 * (y * y + z * z)) * sx,
+            (2.0 * (x * y + w * z)) * sx,
+            (2.0 * (x * z - w * y)) * sx,
+            0.0,
+        ],
+        [
+            (2.0 * (x * y - w * z)) * sy,
+            (1.0 - 2.0 * (x * x + z * z)) * sy,
+            (2.0 * (y * z + w * x)) * sy,
+            0.0,
+        ],
+        [
+            (2.0 * (x * z + w * y)) * sz,
+            (2.0 * (y * z - w * x)) * sz,
+            (1.0 - 2.0 * (x * x + y * y)) * sz,
+            0.0,
+        ],
+        [tx, ty, tz, 1.0],
+    ]
+}
+
+// Returns the value if all values are the same
+fn get_same_or_none_by<T, A: PartialEq, F: Fn(&T) -> A>(
+    data: &[&T],
+    to_value: F,
+) -> Option<A> {
+    let first = to_value(data.first()?);
+    if data[1..].iter().all(|x| to_value(x) == first) {
+        Some(first)
+    } else {
+        None
+    }
+}
+
+fn push_inconsistent_style(
+    is_consistent: bool,
+    ui: &imgui::Ui,
+) -> Option<imgui::ColorStackToken> {
+    if is_consistent {
+        None
+    } else {
+        Some(ui.push_style_color(imgui::StyleColor::Text, [1.0, 1.0, 0.0, 1.0]))
+    }
+}
+
+// Matrices and transforms are drawn under a tree node. A field's context menu is attached to the node rather than the
+// last component editor.
+pub(crate) fn push_tree_node(
+    label: &str,
+    ui: &imgui::Ui,
+) -> Option<imgui::TreeNodeToken> {
+    let node_token = imgui::TreeNode::new(&scratch_im_str!("{}", label)).push(ui);
+    crate::claim_field_context_menu(ui);
+    node_token
+}
+
+/// Draws vectors as static text
+pub(crate) fn render_vector<T, A: VectorArray, F: Fn(&T) -> A>(
+    data: &[&T],
+    label: &'static str,
+    ui: &imgui::Ui,
+    to_array: F,
+) {
+    if data.is_empty() {
+        // Values are inconsistent
+        let style_token = ui.push_style_color(imgui::StyleColor::Text, [1.0, 0.0, 0.0, 1.0]);
+        ui.text(scratch_im_str!("{}: ", label));
+        style_token.pop(ui);
+        return;
+    }
+
+    match get_same_or_none_by(data, to_array) {
+        Some(v) => {
+            // Values are consistent
+            ui.text(scratch_im_str!("{}: {:?}", label, v.components()))
+        }
+        None => {
+            // Values are inconsistent
+            let style_token = ui.push_style_color(imgui::StyleColor::Text, [1.0, 1.0, 0.0, 1.0]);
+            ui.text(scratch_im_str!("{}: ", label));
+            style_token.pop(ui);
+        }
+    }
+}
+
+/// Draws an inline editor for vectors. Only the components that were edited are written, so editing one component of
+/// several vectors that differ leaves their other components alone. Returns true if the values changed.
+pub(crate) fn render_vector_mut<T, A: VectorArray, F: Fn(&T) -> A, G: Fn(&mut T, A)>(
+    data: &mut [&mut T],
+    label: &'static str,
+    ui: &imgui::Ui,
+    to_array: F,
+    from_array: G,
+) -> bool {
+    let values: Vec<&T> = data.iter().map(|x| &**x).collect();
+    let same_or_none_value = get_same_or_none_by(&values, &to_array);
+
+    // Some reasonable default
+    let original = same_or_none_value.unwrap_or_default();
+    let mut value = original;
+
+    let style_token = push_inconsistent_style(same_or_none_value.is_some(), ui);
+
+    let mut changed = false;
+    if value.input(&scratch_im_str!("{}", label), ui) {
+        for d in data.iter_mut() {
+            let mut v = to_array(d);
+            for (i, component) in v.components_mut().iter_mut().enumerate() {
+                if value.components()[i] != original.components()[i] {
+                    *component = value.components()[i];
+                }
+            }
+
+            from_array(d, v);
+            changed = true;
+        }
+    }
+
+    if let Some(style_token) = style_token {
+        style_token.pop(ui);
+    }
+
+    changed
+}
+
+/// Draws rotations as Euler angles in degrees
+pub(crate) fn render_rotation<T, F: Fn(&T) -> [f32; 4]>(
+    data: &[&T],
+    label: &'static str,
+    ui: &imgui::Ui,
+    to_quat: F,
+) {
+    render_vector(data, label, ui, |x| quat_to_euler_degrees(to_quat(x)));
+}
+
+/// Draws an editor for rotations as Euler angles in degrees. Returns true if the values changed.
+pub(crate) fn render_rotation_mut<T, F: Fn(&T) -> [f32; 4], G: Fn(&mut T, [f32; 4])>(
+    data: &mut [&mut T],
+    label: &'static str,
+    ui: &imgui::Ui,
+    to_quat: F,
+    from_quat: G,
+) -> bool {
+    if data.is_empty() {
+        ui.text(scratch_im_str!("{}: None", label));
+        return false;
+    }
+
+    let quats: Vec<[f32; 4]> = data.iter().map(|x| to_quat(x)).collect();
+    let is_consistent = quats[1..].iter().all(|x| quat_approx_eq(*x, quats[0]));
+
+    let owner = widget_state_owner(&*data[0], ui);
+    with_widget_state(
+        owner,
+        ROTATION_STATE,
+        |state: &mut Option<([f32; 4], [f32; 3])>| {
+            // Reuse the angles from last time if they still describe the rotation
+            let original = match *state {
+                Some((quat, euler)) if is_consistent && quat_approx_eq(quat, quats[0]) => euler,
+                _ if is_consistent => quat_to_euler_degrees(quats[0]),
+                _ => Default::default(),
+            };
+            let mut value = original;
+
+            let style_token = push_inconsistent_style(is_consistent, ui);
+
+            let mut changed = false;
+            if value.input(&scratch_im_str!("{}", label), ui) {
+                for (d, quat) in data.iter_mut().zip(&quats) {
+                    let mut euler = if is_consistent {
+                        original
+                    } else {
+                        quat_to_euler_degrees(*quat)
+                    };
+
+                    for i in 0..3 {
+                        if value[i] != original[i] {
+                            euler[i] = value[i];
+                        }
+                    }
+
+                    from_quat(d, euler_degrees_to_quat(euler));
+                    changed = true;
+                }
+
+                *state = if is_consistent {
+                    Some((euler_degrees_to_quat(value), value))
+                } else {
+                    None
+                };
+            }
+
+            if let Some(style_token) = style_token {
+                style_token.pop(ui);
+            }
+
+            changed
+        },
+    )
+}
+
+/// Draws transforms as static translation, rotation, and scale values
+// mint has no transform types
+#[cfg_attr(
+    not(any(feature = "glam", feature = "nalgebra", feature = "cgmath")),
+    allow(dead_code)
+)]
+pub(crate) fn render_transform<T, F: Fn(&T) -> Trs>(
+    data: &[&T],
+    scale: TransformScale,
+    ui: &imgui::Ui,
+    to_trs: F,
+) {
+    render_vector(data, "translation", ui, |x| to_trs(x).translation);
+    render_rotation(data, "rotation", ui, |x| to_trs(x).rotation);
+    match scale {
+        TransformScale::None => {}
+        TransformScale::Uniform => render_vector(data, "scale", ui, |x| [to_trs(x).scale[0]]),
+        TransformScale::NonUniform => render_vector(data, "scale", ui, |x| to_trs(x).scale),
+    }
+}
+
+/// Draws editors for the translation, rotation, and scale of transforms. Returns true if the values changed.
+// mint has no transform types
+#[cfg_attr(
+    not(any(feature = "glam", feature = "nalgebra", feature = "cgmath")),
+    allow(dead_code)
+)]
+pub(crate) fn render_transform_mut<T, F: Fn(&T) -> Trs, G: Fn(&mut T, Trs)>(
+    data: &mut [&mut T],
+    scale: TransformScale,
+    ui: &imgui::Ui,
+    to_trs: F,
+    from_trs: G,
+) -> bool {
+    let mut changed = false;
+
+    changed |= render_vector_mut(
+        data,
+        "translation",
+        ui,
+        |x| to_trs(x).translation,
+        |x, translation| {
+            let trs = to_trs(x);
+            from_trs(x, Trs { translation, ..trs })
+        },
+    );
+
+    changed |= render_rotation_mut(
+        data,
+        "rotation",
+        ui,
+        |x| to_trs(x).rotation,
+        |x, rotation| {
+            let trs = to_trs(x);
+            from_trs(x, Trs { rotation, ..trs })
+        },
+    );
+
+    changed |= match scale {
+        TransformScale::None => false,
+        TransformScale::Uniform => render_vector_mut(
+            data,
+            "scale",
+            ui,
+            |x| [to_trs(x).scale[0]],
+            |x, [scale]| {
+                let trs = to_trs(x);
+                from_trs(
+                    x,
+                    Trs {
+                        scale: [scale; 3],
+                        ..trs
+                    },
+                )
+            },
+        ),
+        TransformScale::NonUniform => render_vector_mut(
+            data,
+            "scale",
+            ui,
+            |x| to_trs(x).scale,
+            |x, scale| {
+                let trs = to_trs(x);
+                from_trs(x, Trs { scale, ..trs })
+            },
+        ),
+    };
+
+    changed
+}
+
+// Draws the rows of a matrix as text
+fn render_matrix_grid(
+    m: &[[f32; 4]; 4],
+    size: usize,
+    ui: &imgui::Ui,
+) {
+    for row in 0..size {
+        let text: Vec<String> = m[..size]
+            .iter()
+            .map(|column| format!("{:>9.3}", column[row]))
+            .collect();
+        ui.text(text.join(" "));
+    }
+}
+
+/// Draws size x size matrices as a read-only grid under a tree node
+pub(crate) fn render_matrix<T, F: Fn(&T) -> [[f32; 4]; 4]>(
+    data: &[&T],
+    label: &'static str,
+    size: usize,
+    ui: &imgui::Ui,
+    to_matrix: F,
+) {
+    if data.is_empty() {
+        ui.text(scratch_im_str!("{}: None", label));
+        return;
+    }
+
+    if let Some(node_token) = push_tree_node(label, ui) {
+        let same_or_none_value = get_same_or_none_by(data, &to_matrix);
+        let style_token = push_inconsistent_style(same_or_none_value.is_some(), ui);
+        render_matrix_grid(&to_matrix(data[0]), size, ui);
+        if let Some(style_token) = style_token {
+            style_token.pop(ui);
+        }
+
+        node_token.pop(ui);
+    }
+}
+
+/// Draws size x size matrices as a read-only grid, followed by editors for the translation, rotation, and scale they
+/// contain. 3x3 matrices have no translation. Returns true if the values changed.
+pub(crate) fn render_matrix_mut<T, F: Fn(&T) -> [[f32; 4]; 4], G: Fn(&mut T, [[f32; 4]; 4])>(
+    data: &mut [&mut T],
+    label: &'static str,
+    size: usize,
+    ui: &imgui::Ui,
+    to_matrix: F,
+    from_matrix: G,
+) -> bool {
+    if data.is_empty() {
+        ui.text(scratch_im_str!("{}: None", label));
+        return false;
+    }
+
+    let mut changed = false;
+    if let Some(node_token) = push_tree_node(label, ui) {
+        let values: Vec<&T> = data.iter().map(|x| &**x).collect();
+        let same_or_none_value = get_same_or_none_by(&values, &to_matrix);
+        let style_token = push_inconsistent_style(same_or_none_value.is_some(), ui);
+        render_matrix_grid(&to_matrix(data[0]), size, ui);
+        if let Some(style_token) = style_token {
+            style_token.pop(ui);
+        }
+
+        ui.separator();
+        if size == 4 {
+            changed = render_vector_mut(
+                data,
+                "translation",
+                ui,
+                |x| matrix_to_trs(&to_matrix(x)).translation,
+                |x, translation| {
+                    // Translation is set directly so that shear isn't lost
+                    let mut m = to_matrix(x);
+                    m[3][0] = translation[0];
+                    m[3][1] = translation[1];
+                    m[3][2] = translation[2];
+                    from_matrix(x, m);
+                },
+            );
+        }
+
+        changed |= render_rotation_mut(
+            data,
+            "rotation",
+            ui,
+            |x| matrix_to_trs(&to_matrix(x)).rotation,
+            |x, rotation| {
+                let trs = matrix_to_trs(&to_matrix(x));
+                from_matrix(x, trs_to_matrix(&Trs { rotation, ..trs }))
+            },
+        );
+
+        changed |= render_vector_mut(
+            data,
+            "scale",
+            ui,
+            |x| matrix_to_trs(&to_matrix(x)).scale,
+            |x, scale| {
+                let trs = matrix_to_trs(&to_matrix(x));
+                from_matrix(x, trs_to_matrix(&Trs { scale, ..trs }))
+            },
+        );
+
+        node_token.pop(ui);
+    }
+
+    changed
+}
+
+// Implements InspectRenderDefault for a math type by converting it to and from arrays. The conversions are closures
+// that take the type explicitly, i.e. |x: &Vec3| x.to_array()
+macro_rules! impl_inspect_math {
+    (vector $t:ty, $to:expr, $from:expr) => {
+        impl InspectRenderDefault<$t> for $t {
+            fn render(
+                data: &[&$t],
+                label: &'static str,
+                ui: &imgui::Ui,
+                _args: &InspectArgsDefault,
+            ) {
+                crate::math::render_vector(data, label, ui, $to);
+            }
+
+            fn render_mut(
+                data: &mut [&mut $t],
+                label: &'static str,
+                ui: &imgui::Ui,
+                _args: &InspectArgsDefault,
+            ) -> bool {
+                crate::math::render_vector_mut(data, label, ui, $to, $from)
+            }
+        }
+    };
+    (rotation $t:ty, $to:expr, $from:expr) => {
+        impl InspectRenderDefault<$t> for $t {
+            fn render(
+                data: &[&$t],
+                label: &'static str,
+                ui: &imgui::Ui,
+                _args: &InspectArgsDefault,
+            ) {
+                crate::math::render_rotation(data, label, ui, $to);
+            }
+
+            fn render_mut(
+                data: &mut [&mut $t],
+                label: &'static str,
+                ui: &imgui::Ui,
+                _args: &InspectArgsDefault,
+            ) -> bool {
+                crate::math::render_rotation_mut(data, label, ui, $to, $from)
+            }
+        }
+    };
+    (matrix $t:ty, $size:expr, $to:expr, $from:expr) => {
+        impl InspectRenderDefault<$t> for $t {
+            fn render(
+                data: &[&$t],
+                label: &'static str,
+                ui: &imgui::Ui,
+                _args: &InspectArgsDefault,
+            ) {
+                crate::math::render_matrix(data, label, $size, ui, $to);
+            }
+
+            fn render_mut(
+                data: &mut [&mut $t],
+                label: &'static str,
+                ui: &imgui::Ui,
+                _args: &InspectArgsDefault,
+            ) -> bool {
+                crate::math::render_matrix_mut(data, label, $size, ui, $to, $from)
+            }
+        }
+    };
+    (transform $t:ty, $scale:expr, $to:expr, $from:expr) => {
+        impl InspectRenderDefault<$t> for $t {
+            fn render(
+                data: &[&$t],
+                label: &'static str,
+                ui: &imgui::Ui,
+                _args: &InspectArgsDefault,
+            ) {
+                if let Some(node_token) = crate::math::push_tree_node(label, ui) {
+                    crate::math::render_transform(data, $scale, ui, $to);
+                    node_token.pop(ui);
+                }
+            }
+
+            fn render_mut(
+                data: &mut [&mut $t],
+                label: &'static str,
+                ui: &imgui::Ui,
+                _args: &InspectArgsDefault,
+            ) -> bool {
+                let mut changed = false;
+                if let Some(node_token) = crate::math::push_tree_node(label, ui) {
+                    changed = crate::math::render_transform_mut(data, $scale, ui, $to, $from);
+                    node_token.pop(ui);
+                }
+
+                changed
+            }
+        }
+    };
+}
+
+pub(crate) use impl_inspect_math;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(
+        actual: &[f32],
+        expected: &[f32],
+    ) {
+        assert!(
+            actual
+                .iter()
+                .zip(expected)
+                .all(|(a, e)| (a - e).abs() < 1e-4),
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn euler_angles_about_one_axis() {
+        let half = std::f32::consts::FRAC_1_SQRT_2;
+        assert_close(
+            &euler_degrees_to_quat([90.0, 0.0, 0.0]),
+            &[half, 0.0, 0.0, half],
+        );
+        assert_close(
+            &euler_degrees_to_quat([0.0, 90.0, 0.0]),
+            &[0.0, half, 0.0, half],
+        );
+        assert_close(
+            &euler_degrees_to_quat([0.0, 0.0, 90.0]),
+            &[0.0, 0.0, half, half],
+        );
+    }
+
+    #[test]
+    fn euler_angles_apply_x_then_y_then_z() {
+        // Rotating +Y by 90 degrees about X gives +Z, which 90 degrees about Y turns into +X, and then about Z into +Y
+        let q = euler_degrees_to_quat([90.0, 90.0, 90.0]);
+        let m = trs_to_matrix(&Trs {
+            rotation: q,
+            ..Default::default()
+        });
+        assert_close(&m[1][..3], &[0.0, 1.0, 0.0]);
+
+        // +X isn't moved by X, goes to -Z about Y, and -Z isn't moved by Z
+        assert_close(&m[0][..3], &[0.0, 0.0, -1.0]);
+    }
+
+    #[test]
+    fn euler_angles_round_trip() {
+        for euler in &[
+            [0.0, 0.0, 0.0],
+            [30.0, -45.0, 60.0],
+            [-170.0, 80.0, 170.0],
+            [10.0, -85.0, -120.0],
+        ] {
+            let q = euler_degrees_to_quat(*euler);
+            assert_close(&quat_to_euler_degrees(q), euler);
+        }
+    }
+
+    #[test]
+    fn equivalent_angles_give_the_same_rotation() {
+        // Different angles can come back out, but they must describe the same rotation
+        let q = euler_degrees_to_quat([180.0, 0.0, 180.0]);
+        let euler = quat_to_euler_degrees(q);
+        assert!(quat_approx_eq(euler_degrees_to_quat(euler), q));
+    }
+
+    #[test]
+    fn negated_quaternion_is_the_same_rotation() {
+        let q = euler_degrees_to_quat([30.0, -45.0, 60.0]);
+        let negated = [-q[0], -q[1], -q[2], -q[3]];
+        assert!(quat_approx_eq(q, negated));
+        assert!(!quat_approx_eq(
+            q,
+            euler_degrees_to_quat([30.0, -45.0, 61.0])
+        ));
+    }
+
+    fn assert_trs_close(
+        actual: &Trs,
+        expected: &Trs,
+    ) {
+        assert_close(&actual.translation, &expected.translation);
+        assert_close(&actual.scale, &expected.scale);
+        assert!(
+            quat_approx_eq(actual.rotation, expected.rotation),
+            "{:?} != {:?}",
+            actual.rotation,
+            expected.rotation
+        );
+    }
+
+    #[test]
+    fn identity_matrix() {
+        let identity = [
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ];
+        assert_eq!(trs_to_matrix(&Trs::default()), identity);
+        assert_trs_close(&matrix_to_trs(&identity), &Trs::default());
+    }
+
+    #[test]
+    fn trs_round_trips_through_matrix() {
+        // These rotations cover each branch of the matrix to quaternion conversion
+        for euler in &[
+            [30.0, -45.0, 60.0],
+            [180.0, 10.0, 0.0],
+            [10.0, 180.0, 0.0],
+            [0.0, 10.0, 180.0],
+        ] {
+            let trs = Trs {
+                translation: [1.0, -2.0, 3.0],
+                rotation: euler_degrees_to_quat(*euler),
+                scale: [2.0, 0.5, 3.0],
+            };
+            assert_trs_close(&matrix_to_trs(&trs_to_matrix(&trs)), &trs);
+        }
+    }
+
+    #[test]
+    fn mirrored_matrix_keeps_negative_scale() {
+        let trs = Trs {
+            translation: [0.0; 3],
+            rotation: euler_degrees_to_quat([0.0, 0.0, 45.0]),
+            scale: [-2.0, 1.0, 1.0],
+        };
+        assert_trs_close(&matrix_to_trs(&trs_to_matrix(&trs)), &trs);
+    }
+
+    #[test]
+    fn same_or_none() {
+        let (a, b) = ([1.0, 2.0], [1.0, 3.0]);
+        assert_eq!(get_same_or_none_by(&[&a, &b], |v| v[0]), Some(1.0));
+        assert_eq!(get_same_or_none_by(&[&a, &b], |v| v[1]), None);
+        assert_eq!(get_same_or_none_by::<[f32; 2], f32, _>(&[], |v| v[0]), None);
+    }
+}
@@ -0,0 +1,171 @@
+mod plot_f32;
+
+pub use super::*;
+
+use std::cell::RefCell;
+
+// Plots are short without a height, since imgui only makes room for the label
+const DEFAULT_PLOT_HEIGHT: f32 = 60.0;
+
+thread_local! {
+    // Values that aren't contiguous (i.e. a VecDeque that has wrapped around) are copied here before they're plotted
+    static PLOT_VALUES: RefCell<Vec<f32>> = const { RefCell::new(Vec::new()) };
+}
+
+/// The imgui widget used to draw a plot
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InspectPlotKind {
+    /// A line through the values, drawn with `plot_lines`
+    Lines,
+
+    /// A bar per value, drawn with `plot_histogram`
+    Histogram,
+}
+
+/// Options for rendering a sequence of numbers as a plot.
+///
+/// Marking a struct member will give it a default rendering behavior. For example,
+/// `#[inspect_plot(kind = "histogram", min = 0.0, max = 33.3, height = 80.0, overlay_text = "ms")]`
+#[derive(Debug, Default)]
+pub struct InspectArgsPlot {
+    /// Whether to draw lines or a histogram. This defaults to lines.
+    pub kind: Option<InspectPlotKind>,
+
+    /// The value at the bottom of the plot. If this isn't set, the plot is scaled to fit the values
+    pub min: Option<f32>,
+
+    /// The value at the top of the plot. If this isn't set, the plot is scaled to fit the values
+    pub max: Option<f32>,
+
+    /// The height of the plot in pixels
+    pub height: Option<f32>,
+
+    /// Text drawn on top of the plot
+    pub overlay_text: Option<&'static str>,
+}
+
+impl From<InspectArgsDefault> for InspectArgsPlot {
+    fn from(default_args: InspectArgsDefault) -> Self {
+        Self {
+            min: default_args.min_value,
+            max: default_args.max_value,
+            ..Default::default()
+        }
+    }
+}
+
+/// Renders a sequence of numbers as a plot. Plots are read-only, so render_mut draws the same thing as render and
+/// never reports a change.
+pub trait InspectRenderPlot<T> {
+    fn render(
+        data: &[&T],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsPlot,
+    );
+
+    fn render_mut(
+        data: &mut [&mut T],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsPlot,
+    ) -> bool;
+}
+
+/// Draws values with imgui's `plot_lines` or `plot_histogram`, followed by their min, average, and max. This is a
+/// helper for implementing `InspectRenderPlot` on other buffer types. If is_consistent is false (i.e. the selected
+/// objects have different values), the label is drawn in the "inconsistent" color.
+pub fn render_plot(
+    values: &[f32],
+    label: &str,
+    is_consistent: bool,
+    ui: &imgui::Ui,
+    args: &InspectArgsPlot,
+) {
+    let style_token = if is_consistent {
+        None
+    } else {
+        Some(ui.push_style_color(imgui::StyleColor::Text, [1.0, 1.0, 0.0, 1.0]))
+    };
+
+    let plot_label = scratch_im_str!("{}", label);
+    let overlay_text = scratch_im_str!("{}", args.overlay_text.unwrap_or(""));
+    let graph_size = [0.0, args.height.unwrap_or(DEFAULT_PLOT_HEIGHT)];
+
+    // imgui scales the plot to fit the values if the min or max is f32::MAX
+    let scale_min = args.min.unwrap_or(f32::MAX);
+    let scale_max = args.max.unwrap_or(f32::MAX);
+
+    match args.kind.unwrap_or(InspectPlotKind::Lines) {
+        InspectPlotKind::Lines => ui
+            .plot_lines(&plot_label, values)
+            .overlay_text(&overlay_text)
+            .scale_min(scale_min)
+            .scale_max(scale_max)
+            .graph_size(graph_size)
+            .build(),
+        InspectPlotKind::Histogram => ui
+            .plot_histogram(&plot_label, values)
+            .overlay_text(&overlay_text)
+            .scale_min(scale_min)
+            .scale_max(scale_max)
+            .graph_size(graph_size)
+            .build(),
+    }
+
+    if let Some(style_token) = style_token {
+        style_token.pop(ui);
+    }
+
+    match plot_summary(values) {
+        Some((min, avg, max)) => ui.text(scratch_im_str!(
+            "min: {:.3}  avg: {:.3}  max: {:.3}  ({} values)",
+            min,
+            avg,
+            max,
+            values.len()
+        )),
+        None => ui.text_disabled("(no values)"),
+    }
+}
+
+/// Like render_plot, but for values split across two slices (i.e. from `VecDeque::as_slices`)
+pub fn render_plot_split(
+    front: &[f32],
+    back: &[f32],
+    label: &str,
+    is_consistent: bool,
+    ui: &imgui::Ui,
+    args: &InspectArgsPlot,
+) {
+    if back.is_empty() {
+        render_plot(front, label, is_consistent, ui, args);
+        return;
+    }
+
+    PLOT_VALUES.with(|x| {
+        let mut values = x.borrow_mut();
+        values.clear();
+        values.extend_from_slice(front);
+        values.extend_from_slice(back);
+        render_plot(&values, label, is_consistent, ui, args);
+    });
+}
+
+// The min, average, and max of the values, or None if there aren't any
+fn plot_summary(values: &[f32]) -> Option<(f32, f32, f32)> {
+    if values.is_empty() {
+        return None;
+    }
+
+    let mut min = f32::MAX;
+    let mut max = f32::MIN;
+    let mut sum = 0.0;
+    for value in values {
+        min = min.min(*value);
+        max = max.max(*value);
+        sum += *value as f64;
+    }
+
+    Some((min, (sum / values.len() as f64) as f32, max))
+}
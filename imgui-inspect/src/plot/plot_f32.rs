@@ -0,0 +1,87 @@
+use super::*;
+use std::collections::VecDeque;
+
+impl InspectRenderPlot<Vec<f32>> for Vec<f32> {
+    fn render(
+        data: &[&Self],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsPlot,
+    ) {
+        if data.is_empty() {
+            ui.text(scratch_im_str!("{}: None", label));
+            return;
+        }
+
+        let is_consistent = get_same_or_none_ref(data).is_some();
+        render_plot(data[0], label, is_consistent, ui, args);
+    }
+
+    fn render_mut(
+        data: &mut [&mut Self],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsPlot,
+    ) -> bool {
+        let data: Vec<&Self> = data.iter().map(|x| &**x).collect();
+        <Self as InspectRenderPlot<Self>>::render(&data, label, ui, args);
+        false
+    }
+}
+
+impl<const N: usize> InspectRenderPlot<[f32; N]> for [f32; N] {
+    fn render(
+        data: &[&Self],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsPlot,
+    ) {
+        if data.is_empty() {
+            ui.text(scratch_im_str!("{}: None", label));
+            return;
+        }
+
+        let is_consistent = get_same_or_none_ref(data).is_some();
+        render_plot(data[0], label, is_consistent, ui, args);
+    }
+
+    fn render_mut(
+        data: &mut [&mut Self],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsPlot,
+    ) -> bool {
+        let data: Vec<&Self> = data.iter().map(|x| &**x).collect();
+        <Self as InspectRenderPlot<Self>>::render(&data, label, ui, args);
+        false
+    }
+}
+
+impl InspectRenderPlot<VecDeque<f32>> for VecDeque<f32> {
+    fn render(
+        data: &[&Self],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsPlot,
+    ) {
+        if data.is_empty() {
+            ui.text(scratch_im_str!("{}: None", label));
+            return;
+        }
+
+        let is_consistent = get_same_or_none_ref(data).is_some();
+        let (front, back) = data[0].as_slices();
+        render_plot_split(front, back, label, is_consistent, ui, args);
+    }
+
+    fn render_mut(
+        data: &mut [&mut Self],
+        label: &'static str,
+        ui: &imgui::Ui,
+        args: &InspectArgsPlot,
+    ) -> bool {
+        let data: Vec<&Self> = data.iter().map(|x| &**x).collect();
+        <Self as InspectRenderPlot<Self>>::render(&data, label, ui, args);
+        false
+    }
+}
@@ -0,0 +1,96 @@
+use std::any::Any;
+
+/// Describes a single field of a struct that derives `Inspect`. The metadata is taken from the struct definition, so
+/// it is available without drawing anything.
+#[derive(Debug, Clone, Copy)]
+pub struct InspectFieldInfo {
+    /// The name of the field
+    pub name: &'static str,
+
+    /// The type of the field as written in the struct definition (i.e. "Vec<f32>"). For the real type, use
+    /// `get_field` and `Any::type_id`
+    pub type_name: &'static str,
+
+    /// The attribute that chooses how the field is drawn (i.e. "inspect" or "inspect_slider")
+    pub attribute: &'static str,
+
+    /// The arguments given to the attribute, as written (i.e. `("min_value", "0.0")`). Flags like `skip` have a value
+    /// of "true". Expressions like `max_value = "self.max"` are returned as the expression's source text.
+    pub args: &'static [(&'static str, &'static str)],
+
+    /// True if the field is marked with `skip`. Skipped fields aren't drawn but can still be accessed by name.
+    pub skip: bool,
+}
+
+impl InspectFieldInfo {
+    /// Returns the value of an attribute argument, if it was given
+    pub fn arg(
+        &self,
+        name: &str,
+    ) -> Option<&'static str> {
+        self.args.iter().find(|(k, _)| *k == name).map(|(_, v)| *v)
+    }
+}
+
+/// The reason `InspectReflect::set_field` failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InspectSetFieldError {
+    /// The struct has no field with the given name
+    NoSuchField,
+
+    /// The value's type doesn't match the field's type
+    WrongType,
+}
+
+impl std::fmt::Display for InspectSetFieldError {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            InspectSetFieldError::NoSuchField => write!(f, "no field with that name"),
+            InspectSetFieldError::WrongType => write!(f, "value does not match the field's type"),
+        }
+    }
+}
+
+impl std::error::Error for InspectSetFieldError {}
+
+/// Runtime access to a struct's fields by name. This is normally generated by putting `#[derive(Inspect)]` on a
+/// struct, and is useful for tools (search, scripting, syncing values over a network, etc.) that need to work with
+/// fields generically.
+///
+/// It isn't generated for `#[inspect(remote = "...")]` proxies or for structs with lifetime parameters, since the
+/// fields must be `'static` to be used as `dyn Any`.
+pub trait InspectReflect {
+    /// Metadata for every field, in the order they are declared
+    fn inspect_fields(&self) -> &'static [InspectFieldInfo];
+
+    /// Metadata for a single field
+    fn inspect_field_info(
+        &self,
+        name: &str,
+    ) -> Option<&'static InspectFieldInfo> {
+        self.inspect_fields().iter().find(|x| x.name == name)
+    }
+
+    /// Returns the field with the given name. Use `downcast_ref` to get the concrete value.
+    fn get_field(
+        &self,
+        name: &str,
+    ) -> Option<&dyn Any>;
+
+    /// Returns the field with the given name. Use `downcast_mut` to get the concrete value.
+    fn get_field_mut(
+        &mut self,
+        name: &str,
+    ) -> Option<&mut dyn Any>;
+
+    /// Replaces the value of the field with the given name. The value must have exactly the field's type. This is a
+    /// plain assignment, so `on_set` callbacks are not called.
+    fn set_field(
+        &mut self,
+        name: &str,
+        value: Box<dyn Any>,
+    ) -> Result<(), InspectSetFieldError>;
+}
@@ -0,0 +1,96 @@
+// imgui-rs needs null-terminated strings for labels, and im_str!("{}", label) allocates a new ImString every time it's
+// called. Widgets draw every frame, so that's at least one allocation per field per frame. Instead, labels are written
+// into buffers that are reused across calls.
+//
+// Buffers are taken from a per-thread pool and returned when the ScratchImString is dropped, so nested widgets can
+// each hold one at the same time.
+use std::cell::RefCell;
+use std::fmt::Write;
+
+// Keeps the pool from growing without bound if something holds onto many buffers at once
+const MAX_POOLED_BUFFERS: usize = 32;
+
+thread_local! {
+    static SCRATCH_BUFFERS: RefCell<Vec<imgui::ImString>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A reused ImString. Derefs to ImStr so it can be passed anywhere a label is expected.
+pub(crate) struct ScratchImString {
+    buffer: Option<imgui::ImString>,
+}
+
+impl ScratchImString {
+    /// Takes a buffer from the pool and writes the formatted text into it. Use the scratch_im_str! macro instead of
+    /// calling this directly.
+    pub(crate) fn format(args: std::fmt::Arguments) -> Self {
+        let mut buffer = SCRATCH_BUFFERS
+            .with(|x| x.borrow_mut().pop())
+            .unwrap_or_else(|| imgui::ImString::with_capacity(64));
+
+        buffer.clear();
+        let _ = ImStringWriter(&mut buffer).write_fmt(args);
+
+        ScratchImString {
+            buffer: Some(buffer),
+        }
+    }
+
+    /// The underlying ImString. This can be passed to input_text to edit it in place.
+    pub(crate) fn as_im_string_mut(&mut self) -> &mut imgui::ImString {
+        self.buffer.as_mut().unwrap()
+    }
+}
+
+impl std::ops::Deref for ScratchImString {
+    type Target = imgui::ImStr;
+
+    fn deref(&self) -> &imgui::ImStr {
+        self.buffer.as_ref().unwrap()
+    }
+}
+
+impl AsRef<imgui::ImStr> for ScratchImString {
+    fn as_ref(&self) -> &imgui::ImStr {
+        self.buffer.as_ref().unwrap()
+    }
+}
+
+impl AsRef<str> for ScratchImString {
+    fn as_ref(&self) -> &str {
+        self.buffer.as_ref().unwrap().to_str()
+    }
+}
+
+impl Drop for ScratchImString {
+    fn drop(&mut self) {
+        if let Some(buffer) = self.buffer.take() {
+            SCRATCH_BUFFERS.with(|x| {
+                let mut buffers = x.borrow_mut();
+                if buffers.len() < MAX_POOLED_BUFFERS {
+                    buffers.push(buffer);
+                }
+            });
+        }
+    }
+}
+
+struct ImStringWriter<'a>(&'a mut imgui::ImString);
+
+impl Write for ImStringWriter<'_> {
+    fn write_str(
+        &mut self,
+        s: &str,
+    ) -> std::fmt::Result {
+        self.0.push_str(s);
+        Ok(())
+    }
+}
+
+/// Like im_str!, but writes into a reused buffer instead of allocating
+macro_rules! scratch_im_str {
+    ($($arg:tt)*) => {
+        crate::scratch::ScratchImString::format(format_args!($($arg)*))
+    };
+}
+
+pub(crate) use scratch_im_str;
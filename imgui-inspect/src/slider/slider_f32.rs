@@ -8,11 +8,11 @@ impl InspectRenderSlider<f32> for f32 {
         _args: &InspectArgsSlider,
     ) {
         if data.is_empty() {
-            ui.text(&imgui::im_str!("{}: None", label));
+            ui.text(scratch_im_str!("{}: None", label));
             return;
         }
 
-        ui.text(&imgui::im_str!("{}: {}", label, data[0]));
+        ui.text(scratch_im_str!("{}: {}", label, data[0]));
     }
 
     fn render_mut(
@@ -44,7 +44,7 @@ impl InspectRenderSlider<f32> for f32 {
         }
 
         let mut changed = false;
-        if imgui::Slider::new(&imgui::im_str!("{}", label))
+        if imgui::Slider::new(&scratch_im_str!("{}", label))
             .range(std::ops::RangeInclusive::new(min, max))
             .build(ui, &mut value)
         {
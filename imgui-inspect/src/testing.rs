@@ -0,0 +1,973 @@
+// A headless imgui context for testing InspectRenderDefault impls without a window or GPU.
+//
+// Widgets are found by reading back what imgui drew. Before the first frame, every character of the built-in font is
+// drawn once to learn which part of the font atlas it uses. After each frame, the glyphs in the draw lists are turned
+// back into text, so labels can be found no matter which code drew them (built-in impls, derived structs, or custom
+// impls calling imgui directly). Input boxes are found as the frame-colored rect to the left of a label, which is where
+// imgui puts the label for every built-in widget.
+//
+// Input is applied one step per frame, the same way a platform backend would feed it to imgui.
+use crate::{InspectArgsDefault, InspectRenderDefault};
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Write;
+use std::path::Path;
+use std::rc::Rc;
+use std::sync::{Mutex, MutexGuard};
+
+const DISPLAY_SIZE: [f32; 2] = [800.0, 2000.0];
+
+// Pixels the mouse moves per frame while dragging
+const DRAG_STEP: f32 = 4.0;
+
+// imgui only allows one context at a time, so tests that run in parallel take turns
+static TEST_CONTEXT_LOCK: Mutex<()> = Mutex::new(());
+
+/// If this environment variable is set, assert_snapshot overwrites golden files instead of comparing against them
+pub const UPDATE_SNAPSHOTS_ENV_VAR: &str = "IMGUI_INSPECT_UPDATE_SNAPSHOTS";
+
+/// A line of text drawn during a frame, in screen coordinates. Text drawn side by side with a single space between is
+/// joined into one entry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InspectTestText {
+    pub text: String,
+    pub min: [f32; 2],
+    pub max: [f32; 2],
+    pub color: [f32; 4],
+    /// The draw list the text was drawn in. Each window and popup has its own.
+    pub draw_list: usize,
+}
+
+impl InspectTestText {
+    pub fn center(&self) -> [f32; 2] {
+        [
+            (self.min[0] + self.max[0]) * 0.5,
+            (self.min[1] + self.max[1]) * 0.5,
+        ]
+    }
+}
+
+/// A filled rectangle drawn during a frame (i.e. the background of an input box or button)
+#[derive(Debug, Clone, PartialEq)]
+pub struct InspectTestRect {
+    pub min: [f32; 2],
+    pub max: [f32; 2],
+    pub color: [f32; 4],
+    /// The draw list the rect was drawn in. Each window and popup has its own.
+    pub draw_list: usize,
+}
+
+impl InspectTestRect {
+    pub fn center(&self) -> [f32; 2] {
+        [
+            (self.min[0] + self.max[0]) * 0.5,
+            (self.min[1] + self.max[1]) * 0.5,
+        ]
+    }
+
+    pub fn contains(
+        &self,
+        pos: [f32; 2],
+    ) -> bool {
+        self.min[0] <= pos[0]
+            && pos[0] <= self.max[0]
+            && self.min[1] <= pos[1]
+            && pos[1] <= self.max[1]
+    }
+}
+
+// Style values used to tell widgets apart. Colors are converted the same way as vertex colors so that they compare
+// equal to colors read back from draw lists.
+#[derive(Debug, Default, Clone)]
+struct TestStyle {
+    frame_colors: Vec<[f32; 4]>,
+    button_colors: Vec<[f32; 4]>,
+    header_colors: Vec<[f32; 4]>,
+    text_color: [f32; 4],
+    check_mark_color: [f32; 4],
+    window_padding: f32,
+    indent_spacing: f32,
+}
+
+impl TestStyle {
+    fn new(style: &imgui::Style) -> Self {
+        let colors = |colors: &[imgui::StyleColor]| -> Vec<[f32; 4]> {
+            colors
+                .iter()
+                .map(|x| from_vertex_color(to_vertex_color(style[*x])))
+                .collect()
+        };
+
+        TestStyle {
+            frame_colors: colors(&[
+                imgui::StyleColor::FrameBg,
+                imgui::StyleColor::FrameBgHovered,
+                imgui::StyleColor::FrameBgActive,
+            ]),
+            button_colors: colors(&[
+                imgui::StyleColor::Button,
+                imgui::StyleColor::ButtonHovered,
+                imgui::StyleColor::ButtonActive,
+            ]),
+            header_colors: colors(&[
+                imgui::StyleColor::Header,
+                imgui::StyleColor::HeaderHovered,
+                imgui::StyleColor::HeaderActive,
+            ]),
+            text_color: colors(&[imgui::StyleColor::Text])[0],
+            check_mark_color: colors(&[imgui::StyleColor::CheckMark])[0],
+            window_padding: style.window_padding[0],
+            indent_spacing: style.indent_spacing,
+        }
+    }
+}
+
+/// Everything drawn during the last frame, in draw order
+#[derive(Debug, Default, Clone)]
+pub struct InspectTestFrame {
+    pub texts: Vec<InspectTestText>,
+    pub rects: Vec<InspectTestRect>,
+
+    // Positions of vertices drawn in the check mark color, used to tell if a checkbox is checked
+    check_marks: Vec<[f32; 2]>,
+    style: TestStyle,
+}
+
+impl InspectTestFrame {
+    /// All text drawn during the frame
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.texts.iter().map(|x| x.text.as_str())
+    }
+
+    /// The first text that exactly matches the given text
+    pub fn find_text(
+        &self,
+        text: &str,
+    ) -> Option<&InspectTestText> {
+        self.texts.iter().find(|x| x.text == text)
+    }
+
+    /// Returns true if any text exactly matches the given text
+    pub fn contains_text(
+        &self,
+        text: &str,
+    ) -> bool {
+        self.find_text(text).is_some()
+    }
+
+    /// The input box (or checkbox) for the given label. This is the closest frame-colored rect to the left of the label
+    /// on the same line, skipping buttons (i.e. the step buttons of an integer input).
+    pub fn find_input(
+        &self,
+        label: &str,
+    ) -> Option<&InspectTestRect> {
+        self.input_for(self.find_text(label)?)
+    }
+
+    /// A stable text description of the widgets that were drawn, one per line, for comparing against golden files
+    /// with assert_snapshot. Each line has the widget's kind, label, and value (for input boxes and checkboxes), and
+    /// the text color if it isn't the default (i.e. yellow for values that differ across the selection). Lines are
+    /// indented to match the widget's indentation. Positions and sizes aren't included, so the snapshot doesn't change
+    /// if the font or style does.
+    ///
+    /// ```text
+    /// header "Entity"
+    ///   input "name" = "Entity 0"
+    ///   checkbox "visible" = true
+    ///   input "health" = "100" color=(1.00, 1.00, 0.00, 1.00)
+    /// ```
+    pub fn snapshot(&self) -> String {
+        let mut snapshot = String::new();
+        for text in &self.texts {
+            let center = text.center();
+
+            // Values drawn inside input boxes are written on the same line as the input box's label
+            if self.input_rects().any(|r| r.contains(center)) {
+                continue;
+            }
+
+            let header = self
+                .rects_with_colors(&self.style.header_colors)
+                .find(|r| r.contains(center));
+            let button = self
+                .rects_with_colors(&self.style.button_colors)
+                .find(|r| r.contains(center));
+            let (kind, x, value) = if let Some(header) = header {
+                ("header", header.min[0], None)
+            } else if let Some(button) = button {
+                ("button", button.min[0], None)
+            } else if let Some(input) = self.input_for(text) {
+                let values: Vec<&str> = self
+                    .texts
+                    .iter()
+                    .filter(|x| input.contains(x.center()))
+                    .map(|x| x.text.as_str())
+                    .collect();
+
+                let is_square =
+                    (input.max[0] - input.min[0] - (input.max[1] - input.min[1])).abs() < 1.0;
+                if is_square && values.is_empty() {
+                    let is_checked = self.check_marks.iter().any(|x| input.contains(*x));
+                    ("checkbox", input.min[0], Some(is_checked.to_string()))
+                } else {
+                    (
+                        "input",
+                        input.min[0],
+                        Some(format!("{:?}", values.join(" "))),
+                    )
+                }
+            } else {
+                ("text", text.min[0], None)
+            };
+
+            let indent = ((x - self.style.window_padding) / self.style.indent_spacing)
+                .round()
+                .max(0.0) as usize;
+            let _ = write!(
+                snapshot,
+                "{:width$}{} {:?}",
+                "",
+                kind,
+                text.text,
+                width = indent * 2
+            );
+            if let Some(value) = value {
+                let _ = write!(snapshot, " = {}", value);
+            }
+            if text.color != self.style.text_color {
+                let _ = write!(
+                    snapshot,
+                    " color=({:.2}, {:.2}, {:.2}, {:.2})",
+                    text.color[0], text.color[1], text.color[2], text.color[3]
+                );
+            }
+            snapshot.push('\n');
+        }
+
+        snapshot
+    }
+
+    fn rects_with_colors<'a>(
+        &'a self,
+        colors: &'a [[f32; 4]],
+    ) -> impl Iterator<Item = &'a InspectTestRect> {
+        self.rects.iter().filter(move |r| colors.contains(&r.color))
+    }
+
+    // Frame-colored rects that aren't buttons. A hovered input box can have the same color as a button (i.e. the step
+    // buttons next to an integer input), but buttons center their text while input boxes draw it from the left edge.
+    fn input_rects(&self) -> impl Iterator<Item = &InspectTestRect> {
+        self.rects_with_colors(&self.style.frame_colors)
+            .filter(move |r| {
+                !self.style.button_colors.contains(&r.color)
+                    || !self.texts.iter().any(|t| {
+                        r.contains(t.center()) && (t.center()[0] - r.center()[0]).abs() < 1.0
+                    })
+            })
+    }
+
+    // The closest input box to the left of the label on the same line, in the same window. A popup's items can line up
+    // with an input box in the window behind it.
+    fn input_for(
+        &self,
+        label: &InspectTestText,
+    ) -> Option<&InspectTestRect> {
+        let center_y = label.center()[1];
+        self.input_rects()
+            .filter(|r| {
+                r.draw_list == label.draw_list
+                    && r.max[0] <= label.min[0] + 0.5
+                    && r.min[1] <= center_y
+                    && center_y <= r.max[1]
+            })
+            .max_by(|a, b| a.max[0].partial_cmp(&b.max[0]).unwrap())
+    }
+}
+
+/// Compares a snapshot (see `InspectTestFrame::snapshot`) against the golden file at path, and panics if they differ
+/// or the file doesn't exist. If the IMGUI_INSPECT_UPDATE_SNAPSHOTS environment variable is set, the file is written
+/// instead.
+pub fn assert_snapshot<P: AsRef<Path>>(
+    path: P,
+    snapshot: &str,
+) {
+    let path = path.as_ref();
+    if std::env::var_os(UPDATE_SNAPSHOTS_ENV_VAR).is_some() {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).unwrap();
+        }
+        std::fs::write(path, snapshot)
+            .unwrap_or_else(|e| panic!("Failed to write {}: {}", path.display(), e));
+        return;
+    }
+
+    // A missing golden file fails rather than being written, so that a renamed test or a golden file that was never
+    // committed doesn't pass without comparing anything
+    if !path.exists() {
+        panic!(
+            "Snapshot {} doesn't exist. Set {} to write it.\n\nactual:\n{}",
+            path.display(),
+            UPDATE_SNAPSHOTS_ENV_VAR,
+            snapshot
+        );
+    }
+
+    let expected = std::fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {}", path.display(), e));
+
+    // Golden files may have been checked out with windows line endings
+    let expected = expected.replace("\r\n", "\n");
+    if expected == snapshot {
+        return;
+    }
+
+    let line = expected
+        .lines()
+        .zip(snapshot.lines())
+        .position(|(a, b)| a != b)
+        .unwrap_or_else(|| expected.lines().count().min(snapshot.lines().count()));
+
+    panic!(
+        "Snapshot doesn't match {} (first difference on line {}). Set {} to update it.\n\nexpected:\n{}\nactual:\n{}",
+        path.display(),
+        line + 1,
+        UPDATE_SNAPSHOTS_ENV_VAR,
+        expected,
+        snapshot
+    );
+}
+
+/// The reason a simulated input couldn't be queued
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InspectTestError {
+    /// No text matching the label was drawn in the last frame
+    LabelNotFound(String),
+
+    /// The label was found, but there's no input box next to it
+    InputNotFound(String),
+}
+
+impl std::fmt::Display for InspectTestError {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            InspectTestError::LabelNotFound(label) => {
+                write!(f, "no text \"{}\" in the last frame", label)
+            }
+            InspectTestError::InputNotFound(label) => {
+                write!(f, "no input box next to \"{}\" in the last frame", label)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InspectTestError {}
+
+// The input for a single frame
+#[derive(Debug, Default, Clone)]
+struct InputStep {
+    mouse_pos: Option<[f32; 2]>,
+    mouse_down: [bool; 2],
+    key_ctrl: bool,
+    keys: Vec<imgui::Key>,
+    chars: Vec<char>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Glyph {
+    ch: char,
+    // Distance from where the glyph is drawn (the top left of the line) to the top left of its quad
+    offset: [f32; 2],
+    advance: f32,
+}
+
+// Maps the top-left UV of each glyph in the font atlas back to the character it draws
+#[derive(Default)]
+struct GlyphTable {
+    glyphs: HashMap<(u32, u32), Glyph>,
+    space_advance: f32,
+    line_height: f32,
+}
+
+impl GlyphTable {
+    fn get(
+        &self,
+        uv: [f32; 2],
+    ) -> Option<&Glyph> {
+        self.glyphs.get(&(uv[0].to_bits(), uv[1].to_bits()))
+    }
+}
+
+struct TestClipboard(Rc<RefCell<Option<String>>>);
+
+impl imgui::ClipboardBackend for TestClipboard {
+    fn get(&mut self) -> Option<imgui::ImString> {
+        self.0.borrow().as_ref().map(imgui::ImString::new)
+    }
+
+    fn set(
+        &mut self,
+        value: &imgui::ImStr,
+    ) {
+        *self.0.borrow_mut() = Some(value.to_str().to_string());
+    }
+}
+
+/// A headless imgui context for testing inspectors. Each frame draws into a window covering the whole display, and
+/// everything that was drawn is available from `last_frame`. Simulated input is queued with `click`, `type_text`,
+/// `drag`, etc. and applied by the next call to `run`, `render`, or `render_mut`.
+///
+/// ```ignore
+/// let mut context = InspectTestContext::new();
+/// let mut value = MyStruct::default();
+/// context.render_mut(&mut value, "value");
+///
+/// context.type_text("health", "42").unwrap();
+/// assert!(context.render_mut(&mut value, "value"));
+/// assert_eq!(value.health, 42);
+/// ```
+///
+/// imgui only allows one context at a time, so creating an InspectTestContext blocks until any other one is dropped.
+/// Tests can still run in parallel, but they take turns drawing.
+pub struct InspectTestContext {
+    // Declared before the lock guard so that it's dropped first
+    context: imgui::Context,
+    glyphs: GlyphTable,
+    style: TestStyle,
+    input: VecDeque<InputStep>,
+    mouse_pos: [f32; 2],
+    clipboard: Rc<RefCell<Option<String>>>,
+    last_frame: InspectTestFrame,
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl Default for InspectTestContext {
+    fn default() -> Self {
+        InspectTestContext::new()
+    }
+}
+
+impl InspectTestContext {
+    pub fn new() -> Self {
+        // A test that panicked while holding the lock doesn't leave anything behind, so a poisoned lock is fine
+        let lock = TEST_CONTEXT_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+        let mut context = imgui::Context::create();
+        context.set_ini_filename(None);
+        context
+            .fonts()
+            .add_font(&[imgui::FontSource::DefaultFontData { config: None }]);
+        context.fonts().build_rgba32_texture();
+
+        let clipboard = Rc::new(RefCell::new(None));
+        context.set_clipboard_backend(Box::new(TestClipboard(clipboard.clone())));
+
+        {
+            let io = context.io_mut();
+            io.display_size = DISPLAY_SIZE;
+            io.mouse_pos = [-f32::MAX, -f32::MAX];
+            // Shortcuts use ctrl on every platform
+            io.config_mac_os_behaviors = false;
+        }
+
+        let style = TestStyle::new(context.style());
+
+        let mut test_context = InspectTestContext {
+            context,
+            glyphs: GlyphTable::default(),
+            style,
+            input: VecDeque::new(),
+            mouse_pos: [-f32::MAX, -f32::MAX],
+            clipboard,
+            last_frame: InspectTestFrame::default(),
+            _lock: lock,
+        };
+
+        test_context.glyphs = test_context.build_glyph_table();
+        test_context
+    }
+
+    /// Everything drawn during the last frame
+    pub fn last_frame(&self) -> &InspectTestFrame {
+        &self.last_frame
+    }
+
+    /// The text last copied to the clipboard
+    pub fn clipboard_text(&self) -> Option<String> {
+        self.clipboard.borrow().clone()
+    }
+
+    pub fn set_clipboard_text(
+        &mut self,
+        text: &str,
+    ) {
+        *self.clipboard.borrow_mut() = Some(text.to_string());
+    }
+
+    /// Draws frames with f until all queued input has been applied. At least one frame is always drawn.
+    pub fn run<F: FnMut(&imgui::Ui)>(
+        &mut self,
+        mut f: F,
+    ) -> &InspectTestFrame {
+        loop {
+            let step = self.input.pop_front().unwrap_or_default();
+            self.run_frame(&step, &mut f);
+            if self.input.is_empty() {
+                break;
+            }
+        }
+
+        &self.last_frame
+    }
+
+    /// Draws the value read-only until all queued input has been applied
+    pub fn render<T: InspectRenderDefault<T>>(
+        &mut self,
+        data: &T,
+        label: &'static str,
+    ) -> &InspectTestFrame {
+        self.render_with_args(data, label, &InspectArgsDefault::default())
+    }
+
+    pub fn render_with_args<T: InspectRenderDefault<T>>(
+        &mut self,
+        data: &T,
+        label: &'static str,
+        args: &InspectArgsDefault,
+    ) -> &InspectTestFrame {
+        self.run(|ui| {
+            <T as InspectRenderDefault<T>>::render(&[data], label, ui, args);
+        })
+    }
+
+    /// Draws the value with widgets for editing it until all queued input has been applied. Returns true if any frame
+    /// reported a change.
+    pub fn render_mut<T: InspectRenderDefault<T>>(
+        &mut self,
+        data: &mut T,
+        label: &'static str,
+    ) -> bool {
+        self.render_mut_with_args(data, label, &InspectArgsDefault::default())
+    }
+
+    pub fn render_mut_with_args<T: InspectRenderDefault<T>>(
+        &mut self,
+        data: &mut T,
+        label: &'static str,
+        args: &InspectArgsDefault,
+    ) -> bool {
+        let mut changed = false;
+        self.run(|ui| {
+            changed |=
+                <T as InspectRenderDefault<T>>::render_mut(&mut [&mut *data], label, ui, args);
+        });
+        changed
+    }
+
+    /// Clicks the input box for the label, or the label itself if it doesn't have one (i.e. a button or header)
+    pub fn click(
+        &mut self,
+        label: &str,
+    ) -> Result<(), InspectTestError> {
+        let pos = self.find_click_target(label)?;
+        self.queue_click(pos, 0, false);
+        Ok(())
+    }
+
+    /// Right-clicks the input box for the label, or the label itself if it doesn't have one. This opens context menus.
+    pub fn right_click(
+        &mut self,
+        label: &str,
+    ) -> Result<(), InspectTestError> {
+        let pos = self.find_click_target(label)?;
+        self.queue_click(pos, 1, false);
+        Ok(())
+    }
+
+    /// Replaces the contents of the input box for the label with the text, then presses enter. Drag and slider widgets
+    /// are ctrl+clicked so that they accept text.
+    pub fn type_text(
+        &mut self,
+        label: &str,
+        text: &str,
+    ) -> Result<(), InspectTestError> {
+        let pos = self.find_input_center(label)?;
+        self.queue_click(pos, 0, true);
+        self.input.push_back(InputStep {
+            key_ctrl: true,
+            keys: vec![imgui::Key::A],
+            ..Default::default()
+        });
+        self.input.push_back(InputStep {
+            chars: text.chars().collect(),
+            ..Default::default()
+        });
+        self.press_key(imgui::Key::Enter);
+        Ok(())
+    }
+
+    /// Drags the input box for the label horizontally by dx pixels. imgui ignores the first few pixels of a drag, so
+    /// the value changes by a little less than dx times the widget's speed.
+    pub fn drag(
+        &mut self,
+        label: &str,
+        dx: f32,
+    ) -> Result<(), InspectTestError> {
+        let start = self.find_input_center(label)?;
+        self.move_mouse(start);
+        self.input.push_back(InputStep {
+            mouse_down: [true, false],
+            ..Default::default()
+        });
+
+        let step_count = (dx.abs() / DRAG_STEP).ceil().max(1.0) as usize;
+        for i in 1..=step_count {
+            self.input.push_back(InputStep {
+                mouse_pos: Some([start[0] + dx * i as f32 / step_count as f32, start[1]]),
+                mouse_down: [true, false],
+                ..Default::default()
+            });
+        }
+
+        self.input.push_back(InputStep::default());
+        Ok(())
+    }
+
+    /// Presses and releases a key
+    pub fn press_key(
+        &mut self,
+        key: imgui::Key,
+    ) {
+        self.input.push_back(InputStep {
+            keys: vec![key],
+            ..Default::default()
+        });
+        self.input.push_back(InputStep::default());
+    }
+
+    /// Moves the mouse without clicking (i.e. to show a tooltip)
+    pub fn move_mouse(
+        &mut self,
+        pos: [f32; 2],
+    ) {
+        self.input.push_back(InputStep {
+            mouse_pos: Some(pos),
+            ..Default::default()
+        });
+    }
+
+    fn find_input_center(
+        &self,
+        label: &str,
+    ) -> Result<[f32; 2], InspectTestError> {
+        if !self.last_frame.contains_text(label) {
+            return Err(InspectTestError::LabelNotFound(label.to_string()));
+        }
+
+        self.last_frame
+            .find_input(label)
+            .map(|x| x.center())
+            .ok_or_else(|| InspectTestError::InputNotFound(label.to_string()))
+    }
+
+    fn find_click_target(
+        &self,
+        label: &str,
+    ) -> Result<[f32; 2], InspectTestError> {
+        let text = self
+            .last_frame
+            .find_text(label)
+            .ok_or_else(|| InspectTestError::LabelNotFound(label.to_string()))?;
+
+        Ok(self
+            .last_frame
+            .find_input(label)
+            .map(|x| x.center())
+            .unwrap_or_else(|| text.center()))
+    }
+
+    // The mouse is moved first so that imgui sees it hovering the widget before the button goes down
+    fn queue_click(
+        &mut self,
+        pos: [f32; 2],
+        button: usize,
+        key_ctrl: bool,
+    ) {
+        let mut mouse_down = [false; 2];
+        mouse_down[button] = true;
+
+        self.move_mouse(pos);
+        self.input.push_back(InputStep {
+            mouse_down,
+            key_ctrl,
+            ..Default::default()
+        });
+        self.input.push_back(InputStep {
+            key_ctrl,
+            ..Default::default()
+        });
+    }
+
+    fn run_frame<F: FnMut(&imgui::Ui)>(
+        &mut self,
+        step: &InputStep,
+        f: &mut F,
+    ) {
+        if let Some(mouse_pos) = step.mouse_pos {
+            self.mouse_pos = mouse_pos;
+        }
+
+        {
+            let io = self.context.io_mut();
+            io.delta_time = 1.0 / 60.0;
+            io.mouse_pos = self.mouse_pos;
+            io.mouse_down = [false; 5];
+            io.mouse_down[..2].copy_from_slice(&step.mouse_down);
+            io.key_ctrl = step.key_ctrl;
+            io.keys_down = [false; 512];
+            for key in &step.keys {
+                io[*key] = *key as u32;
+                io.keys_down[*key as usize] = true;
+            }
+            for c in &step.chars {
+                io.add_input_character(*c);
+            }
+        }
+
+        let ui = self.context.frame();
+        imgui::Window::new(imgui::im_str!("##inspect_test"))
+            .position([0.0, 0.0], imgui::Condition::Always)
+            .size(DISPLAY_SIZE, imgui::Condition::Always)
+            .title_bar(false)
+            .resizable(false)
+            .movable(false)
+            .build(&ui, || f(&ui));
+
+        let draw_data = ui.render();
+        self.last_frame = read_frame(draw_data, &self.glyphs, &self.style);
+    }
+
+    // Draws every character of the font in a grid, and records where each one lands in the font atlas
+    fn build_glyph_table(&mut self) -> GlyphTable {
+        const COLUMNS: usize = 16;
+        const CELL_SIZE: [f32; 2] = [32.0, 24.0];
+
+        let chars: Vec<char> = (0x21u8..=0x7e).chain(0xa1..=0xff).map(char::from).collect();
+        let mut pens = Vec::with_capacity(chars.len());
+        let mut advances = Vec::with_capacity(chars.len());
+        let mut space_advance = 0.0;
+        let mut line_height = 0.0;
+
+        let ui = self.context.frame();
+        imgui::Window::new(imgui::im_str!("##inspect_test"))
+            .position([0.0, 0.0], imgui::Condition::Always)
+            .size(DISPLAY_SIZE, imgui::Condition::Always)
+            .title_bar(false)
+            .resizable(false)
+            .movable(false)
+            .build(&ui, || {
+                let origin = ui.cursor_screen_pos();
+                let space_size = ui.calc_text_size(imgui::im_str!(" "), false, -1.0);
+                space_advance = space_size[0];
+                line_height = space_size[1];
+                for (i, ch) in chars.iter().enumerate() {
+                    let pen = [
+                        origin[0] + (i % COLUMNS) as f32 * CELL_SIZE[0],
+                        origin[1] + (i / COLUMNS) as f32 * CELL_SIZE[1],
+                    ];
+                    let text = imgui::ImString::new(ch.to_string());
+                    ui.set_cursor_screen_pos(pen);
+                    ui.text(&text);
+                    pens.push(pen);
+                    advances.push(ui.calc_text_size(&text, false, -1.0)[0]);
+                }
+            });
+
+        let draw_data = ui.render();
+
+        let mut table = GlyphTable {
+            glyphs: HashMap::new(),
+            space_advance,
+            line_height,
+        };
+
+        for draw_list in draw_data.draw_lists() {
+            let vtx = draw_list.vtx_buffer();
+            for quad in vtx.windows(4) {
+                if !is_quad(quad) || quad[0].uv == quad[2].uv {
+                    continue;
+                }
+
+                // Each character was drawn in its own cell, so the cell tells which character this is
+                let pos = quad[0].pos;
+                let index = pens.iter().position(|pen| {
+                    pos[0] >= pen[0] - 1.0
+                        && pos[0] < pen[0] + CELL_SIZE[0] - 1.0
+                        && pos[1] >= pen[1] - 1.0
+                        && pos[1] < pen[1] + CELL_SIZE[1] - 1.0
+                });
+
+                if let Some(index) = index {
+                    table
+                        .glyphs
+                        .entry((quad[0].uv[0].to_bits(), quad[0].uv[1].to_bits()))
+                        .or_insert(Glyph {
+                            ch: chars[index],
+                            offset: [pos[0] - pens[index][0], pos[1] - pens[index][1]],
+                            advance: advances[index],
+                        });
+                }
+            }
+        }
+
+        table
+    }
+}
+
+// True if the four vertices are an axis-aligned rectangle in the order imgui writes them (top left, top right, bottom
+// right, bottom left)
+fn is_quad(v: &[imgui::DrawVert]) -> bool {
+    v[0].pos[1] == v[1].pos[1]
+        && v[1].pos[0] == v[2].pos[0]
+        && v[2].pos[1] == v[3].pos[1]
+        && v[3].pos[0] == v[0].pos[0]
+        && v[0].pos[0] < v[1].pos[0]
+        && v[0].pos[1] < v[3].pos[1]
+        && v[0].uv[1] == v[1].uv[1]
+        && v[1].uv[0] == v[2].uv[0]
+        && v[2].uv[1] == v[3].uv[1]
+        && v[3].uv[0] == v[0].uv[0]
+        && v[0].col == v[1].col
+        && v[0].col == v[2].col
+        && v[0].col == v[3].col
+}
+
+fn to_vertex_color(color: [f32; 4]) -> [u8; 4] {
+    let mut result = [0; 4];
+    for (channel, value) in result.iter_mut().zip(color.iter()) {
+        *channel = (value.clamp(0.0, 1.0) * 255.0 + 0.5) as u8;
+    }
+    result
+}
+
+fn from_vertex_color(color: [u8; 4]) -> [f32; 4] {
+    [
+        color[0] as f32 / 255.0,
+        color[1] as f32 / 255.0,
+        color[2] as f32 / 255.0,
+        color[3] as f32 / 255.0,
+    ]
+}
+
+// True if the left or right edge of a rect on the text's line is between the end of the text and x
+fn has_edge_between(
+    rects: &[InspectTestRect],
+    text: &InspectTestText,
+    x: f32,
+) -> bool {
+    let center_y = text.center()[1];
+    rects.iter().any(|r| {
+        r.min[1] <= center_y
+            && center_y <= r.max[1]
+            && [r.min[0], r.max[0]]
+                .iter()
+                .any(|edge| text.max[0] - 0.5 <= *edge && *edge <= x + 0.5)
+    })
+}
+
+// Turns the glyphs in the draw lists back into text, and collects filled rects and check marks
+fn read_frame(
+    draw_data: &imgui::DrawData,
+    glyphs: &GlyphTable,
+    style: &TestStyle,
+) -> InspectTestFrame {
+    let mut frame = InspectTestFrame {
+        style: style.clone(),
+        ..Default::default()
+    };
+
+    for (draw_list_index, draw_list) in draw_data.draw_lists().enumerate() {
+        let vtx = draw_list.vtx_buffer();
+        let first_rect = frame.rects.len();
+        let mut current: Option<InspectTestText> = None;
+
+        frame.check_marks.extend(
+            vtx.iter()
+                .filter(|x| from_vertex_color(x.col) == style.check_mark_color)
+                .map(|x| x.pos),
+        );
+
+        let mut i = 0;
+        while i + 4 <= vtx.len() {
+            let quad = &vtx[i..i + 4];
+            if !is_quad(quad) {
+                i += 1;
+                continue;
+            }
+
+            let color = from_vertex_color(quad[0].col);
+            if quad[0].uv == quad[2].uv {
+                frame.rects.push(InspectTestRect {
+                    min: quad[0].pos,
+                    max: quad[2].pos,
+                    color,
+                    draw_list: draw_list_index,
+                });
+            } else if let Some(glyph) = glyphs.get(quad[0].uv) {
+                let pen = [
+                    quad[0].pos[0] - glyph.offset[0],
+                    quad[0].pos[1] - glyph.offset[1],
+                ];
+
+                // Glyphs continue the current text if they're on the same line with at most one space between them.
+                // Spaces aren't drawn, so they're inferred from the gap. Text on either side of the edge of a rect
+                // (i.e. a step button's "+" and the label next to it) belongs to different widgets.
+                let continues = current.as_ref().and_then(|text| {
+                    let gap = pen[0] - text.max[0];
+                    let spaces = (gap / glyphs.space_advance).round();
+                    if text.color == color
+                        && (text.min[1] - pen[1]).abs() < 0.5
+                        && gap > -0.5
+                        && spaces <= 1.0
+                        && !has_edge_between(&frame.rects[first_rect..], text, pen[0])
+                    {
+                        Some(spaces > 0.0)
+                    } else {
+                        None
+                    }
+                });
+
+                match (continues, current.as_mut()) {
+                    (Some(add_space), Some(text)) => {
+                        if add_space {
+                            text.text.push(' ');
+                        }
+                        text.text.push(glyph.ch);
+                        text.max[0] = pen[0] + glyph.advance;
+                    }
+                    _ => {
+                        frame.texts.extend(current.take());
+                        current = Some(InspectTestText {
+                            text: glyph.ch.to_string(),
+                            min: pen,
+                            max: [pen[0] + glyph.advance, pen[1] + glyphs.line_height],
+                            color,
+                            draw_list: draw_list_index,
+                        });
+                    }
+                }
+            } else {
+                i += 1;
+                continue;
+            }
+
+            i += 4;
+        }
+
+        frame.texts.extend(current.take());
+    }
+
+    frame
+}
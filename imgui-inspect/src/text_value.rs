@@ -0,0 +1,207 @@
+// Shared widgets for values that are edited as text, such as addresses and paths. The impls for these types are thin
+// wrappers that provide conversions to and from a string.
+//
+// Text that doesn't parse isn't written to the value. It's kept as widget state so that it can be corrected, and the
+// parse error is drawn under the widget.
+use crate::scratch::scratch_im_str;
+use crate::widget_state::{widget_state_owner, with_widget_state};
+
+const PENDING_TEXT_STATE: &str = "inspect_text_value_pending";
+
+// Returns the text for the values if they are all the same
+fn get_same_text_or_none<'a, T: 'a, F: Fn(&T) -> String>(
+    mut values: impl Iterator<Item = &'a T>,
+    to_text: F,
+) -> Option<String> {
+    let first = to_text(values.next()?);
+    if values.all(|x| to_text(x) == first) {
+        Some(first)
+    } else {
+        None
+    }
+}
+
+/// Draws values as static text
+pub(crate) fn render_text_value<T, F: Fn(&T) -> String>(
+    data: &[&T],
+    label: &str,
+    ui: &imgui::Ui,
+    to_text: F,
+) {
+    if data.is_empty() {
+        // Values are inconsistent
+        let style_token = ui.push_style_color(imgui::StyleColor::Text, [1.0, 0.0, 0.0, 1.0]);
+        ui.text(scratch_im_str!("{}: ", label));
+        style_token.pop(ui);
+        return;
+    }
+
+    match get_same_text_or_none(data.iter().copied(), to_text) {
+        Some(text) => {
+            // Values are consistent
+            ui.text(scratch_im_str!("{}: {}", label, text))
+        }
+        None => {
+            // Values are inconsistent
+            let style_token = ui.push_style_color(imgui::StyleColor::Text, [1.0, 1.0, 0.0, 1.0]);
+            ui.text(scratch_im_str!("{}: ", label));
+            style_token.pop(ui);
+        }
+    }
+}
+
+/// Draws a text input for the values. parse is called when the text is edited, and the result is written to all
+/// values if it succeeds. Returns true if the values changed.
+pub(crate) fn render_text_value_mut<T, F, P>(
+    data: &mut [&mut T],
+    label: &str,
+    ui: &imgui::Ui,
+    to_text: F,
+    parse: P,
+) -> bool
+where
+    T: Clone,
+    F: Fn(&T) -> String,
+    P: Fn(&str) -> Result<T, String>,
+{
+    if data.is_empty() {
+        ui.text(scratch_im_str!("{}: None", label));
+        return false;
+    }
+
+    let owner = widget_state_owner(&*data[0], ui);
+    let same_or_none_text = get_same_text_or_none(data.iter().map(|x| &**x), &to_text);
+
+    with_widget_state(owner, PENDING_TEXT_STATE, |pending: &mut Option<String>| {
+        let style_token = if same_or_none_text.is_none() && pending.is_none() {
+            // If values are inconsistent, push a style
+            Some(ui.push_style_color(imgui::StyleColor::Text, [1.0, 1.0, 0.0, 1.0]))
+        } else {
+            None
+        };
+
+        let text = pending
+            .clone()
+            .or_else(|| same_or_none_text.clone())
+            .unwrap_or_default();
+
+        let mut changed = false;
+        let mut value = scratch_im_str!("{}", text);
+        if ui
+            .input_text(&scratch_im_str!("{}", label), value.as_im_string_mut())
+            .resize_buffer(true)
+            .build()
+        {
+            match parse(value.to_str()) {
+                Ok(parsed) => {
+                    for d in data.iter_mut() {
+                        **d = parsed.clone();
+                    }
+                    *pending = None;
+                    changed = true;
+                }
+                Err(_) => *pending = Some(value.to_str().to_string()),
+            }
+        }
+
+        if let Some(style_token) = style_token {
+            style_token.pop(ui);
+        }
+
+        if let Some(Err(error)) = pending.as_deref().map(&parse) {
+            ui.text_colored([1.0, 0.0, 0.0, 1.0], scratch_im_str!("{}", error));
+        }
+
+        changed
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_text_is_shared() {
+        let values = [1, 1, 1];
+        assert_eq!(
+            get_same_text_or_none(values.iter(), |x| x.to_string()),
+            Some("1".to_string())
+        );
+    }
+
+    #[test]
+    fn different_text_is_none() {
+        let values = [1, 2];
+        assert_eq!(
+            get_same_text_or_none(values.iter(), |x| x.to_string()),
+            None
+        );
+    }
+
+    #[test]
+    fn values_are_compared_by_text() {
+        // Different values that convert to the same text are treated as the same
+        let values = [3, 13];
+        assert_eq!(
+            get_same_text_or_none(values.iter(), |x| (x % 10).to_string()),
+            Some("3".to_string())
+        );
+    }
+
+    #[cfg(feature = "testing")]
+    mod widget {
+        use crate::testing::InspectTestContext;
+        use std::net::{IpAddr, Ipv4Addr};
+
+        #[test]
+        fn parsed_text_is_written() {
+            let mut context = InspectTestContext::new();
+            let mut value = IpAddr::V4(Ipv4Addr::LOCALHOST);
+            context.render_mut(&mut value, "address");
+
+            context.type_text("address", "10.0.0.1").unwrap();
+            assert!(context.render_mut(&mut value, "address"));
+            assert_eq!(value, IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)));
+        }
+
+        #[test]
+        fn invalid_text_is_kept_until_corrected() {
+            let mut context = InspectTestContext::new();
+            let mut value = IpAddr::V4(Ipv4Addr::LOCALHOST);
+            context.render_mut(&mut value, "address");
+
+            context.type_text("address", "10.0.0").unwrap();
+            assert!(!context.render_mut(&mut value, "address"));
+            assert_eq!(value, IpAddr::V4(Ipv4Addr::LOCALHOST));
+            assert!(context.last_frame().contains_text("10.0.0"));
+            assert!(context
+                .last_frame()
+                .contains_text("invalid IP address syntax"));
+
+            context.type_text("address", "10.0.0.2").unwrap();
+            assert!(context.render_mut(&mut value, "address"));
+            assert_eq!(value, IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2)));
+            assert!(!context
+                .last_frame()
+                .contains_text("invalid IP address syntax"));
+        }
+
+        #[test]
+        fn char_accepts_a_single_character() {
+            let mut context = InspectTestContext::new();
+            let mut value = 'a';
+            context.render_mut(&mut value, "key");
+
+            context.type_text("key", "bc").unwrap();
+            assert!(!context.render_mut(&mut value, "key"));
+            assert_eq!(value, 'a');
+            assert!(context
+                .last_frame()
+                .contains_text("Enter a single character"));
+
+            context.type_text("key", "b").unwrap();
+            assert!(context.render_mut(&mut value, "key"));
+            assert_eq!(value, 'b');
+        }
+    }
+}
@@ -0,0 +1,422 @@
+// A backend-agnostic way to walk the fields of a value. Structs that opt in with #[inspect(visitor)] implement
+// InspectVisit, which hands each field (with the args parsed from its attributes) to an InspectVisitor. A visitor can
+// draw the fields with any UI library, send them over a network, etc. ImguiInspectVisitor draws them with the same
+// widgets as InspectRenderDefault.
+//
+// Like InspectRenderDefault, every call receives all of the selected values so that visitors can show values that
+// differ across the selection. on_set callbacks run when a visitor reports a change.
+use crate::list::{begin_list_item, LIST_ITEM_LABEL};
+use crate::scratch::scratch_im_str;
+use crate::{
+    check_inspect_id, layout_record_header, layout_restore_next_header, push_layout_path,
+    InspectArgsDefault, InspectArgsSlider, InspectArgsStruct, InspectRenderDefault,
+    InspectRenderSlider,
+};
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+/// Receives the fields of a value. Methods return true if any of the values were changed.
+///
+/// There's a method for each type that implements InspectVisit. Fields of any other type (maps, sets, char, Duration,
+/// math types, ...) are passed to visit_unsupported.
+pub trait InspectVisitor {
+    fn visit_bool(
+        &mut self,
+        label: &'static str,
+        values: &mut [&mut bool],
+        args: &InspectArgsDefault,
+    ) -> bool;
+
+    fn visit_f32(
+        &mut self,
+        label: &'static str,
+        values: &mut [&mut f32],
+        args: &InspectArgsDefault,
+    ) -> bool;
+
+    fn visit_u32(
+        &mut self,
+        label: &'static str,
+        values: &mut [&mut u32],
+        args: &InspectArgsDefault,
+    ) -> bool;
+
+    fn visit_usize(
+        &mut self,
+        label: &'static str,
+        values: &mut [&mut usize],
+        args: &InspectArgsDefault,
+    ) -> bool;
+
+    fn visit_string(
+        &mut self,
+        label: &'static str,
+        values: &mut [&mut String],
+        args: &InspectArgsDefault,
+    ) -> bool;
+
+    /// A field marked with `#[inspect_slider]`
+    fn visit_slider_f32(
+        &mut self,
+        label: &'static str,
+        values: &mut [&mut f32],
+        args: &InspectArgsSlider,
+    ) -> bool;
+
+    /// A struct that derives Inspect. visit_fields passes each of its fields to the given visitor, and can be skipped
+    /// (i.e. if the struct's header is collapsed).
+    fn visit_struct(
+        &mut self,
+        label: &'static str,
+        type_name: &'static str,
+        args: &InspectArgsStruct,
+        visit_fields: &mut dyn FnMut(&mut dyn InspectVisitor) -> bool,
+    ) -> bool;
+
+    /// An Option. is_some has an entry for each selected value. visit_some visits the values that are Some.
+    fn visit_option(
+        &mut self,
+        label: &'static str,
+        is_some: &[bool],
+        visit_some: &mut dyn FnMut(&mut dyn InspectVisitor) -> bool,
+    ) -> bool;
+
+    /// A list. lens has the length of each selected list. visit_item visits the element at an index in every list
+    /// that's long enough to have one. Elements are visited with an empty label, so visitors should label them by
+    /// their index.
+    fn visit_list(
+        &mut self,
+        label: &'static str,
+        lens: &[usize],
+        visit_item: &mut dyn FnMut(&mut dyn InspectVisitor, usize) -> bool,
+    ) -> bool;
+
+    /// A field whose type doesn't implement InspectVisit, or that's drawn with a custom render_trait
+    fn visit_unsupported(
+        &mut self,
+        _label: &'static str,
+        _type_name: &'static str,
+    ) -> bool {
+        false
+    }
+}
+
+/// Passes a value to an InspectVisitor. This is normally generated by putting `#[derive(Inspect)]` and
+/// `#[inspect(visitor)]` on a struct. Like InspectRenderDefault, it can be implemented on a proxy type and used with
+/// `proxy_type`.
+pub trait InspectVisit<T> {
+    fn visit(
+        data: &mut [&mut T],
+        label: &'static str,
+        visitor: &mut dyn InspectVisitor,
+        args: &InspectArgsDefault,
+    ) -> bool;
+}
+
+/// Passes a value marked with `#[inspect_slider]` to an InspectVisitor
+pub trait InspectVisitSlider<T> {
+    fn visit_slider(
+        data: &mut [&mut T],
+        label: &'static str,
+        visitor: &mut dyn InspectVisitor,
+        args: &InspectArgsSlider,
+    ) -> bool;
+}
+
+macro_rules! impl_inspect_visit {
+    ($t:ty, $visit_fn:ident) => {
+        impl InspectVisit<$t> for $t {
+            fn visit(
+                data: &mut [&mut $t],
+                label: &'static str,
+                visitor: &mut dyn InspectVisitor,
+                args: &InspectArgsDefault,
+            ) -> bool {
+                visitor.$visit_fn(label, data, args)
+            }
+        }
+    };
+}
+
+impl_inspect_visit!(bool, visit_bool);
+impl_inspect_visit!(f32, visit_f32);
+impl_inspect_visit!(u32, visit_u32);
+impl_inspect_visit!(usize, visit_usize);
+impl_inspect_visit!(String, visit_string);
+
+impl InspectVisitSlider<f32> for f32 {
+    fn visit_slider(
+        data: &mut [&mut f32],
+        label: &'static str,
+        visitor: &mut dyn InspectVisitor,
+        args: &InspectArgsSlider,
+    ) -> bool {
+        visitor.visit_slider_f32(label, data, args)
+    }
+}
+
+impl<T: InspectVisit<T>> InspectVisit<Option<T>> for Option<T> {
+    fn visit(
+        data: &mut [&mut Option<T>],
+        label: &'static str,
+        visitor: &mut dyn InspectVisitor,
+        args: &InspectArgsDefault,
+    ) -> bool {
+        let is_some: Vec<bool> = data.iter().map(|x| x.is_some()).collect();
+        visitor.visit_option(label, &is_some, &mut |visitor| {
+            let mut values: Vec<&mut T> = data.iter_mut().filter_map(|x| x.as_mut()).collect();
+            <T as InspectVisit<T>>::visit(&mut values, label, visitor, args)
+        })
+    }
+}
+
+macro_rules! impl_inspect_visit_list {
+    ($list:ident) => {
+        impl<T: InspectVisit<T>> InspectVisit<$list<T>> for $list<T> {
+            fn visit(
+                data: &mut [&mut $list<T>],
+                label: &'static str,
+                visitor: &mut dyn InspectVisitor,
+                args: &InspectArgsDefault,
+            ) -> bool {
+                let lens: Vec<usize> = data.iter().map(|x| x.len()).collect();
+                visitor.visit_list(label, &lens, &mut |visitor, index| {
+                    let mut values: Vec<&mut T> =
+                        data.iter_mut().filter_map(|x| x.get_mut(index)).collect();
+                    <T as InspectVisit<T>>::visit(&mut values, LIST_ITEM_LABEL, visitor, args)
+                })
+            }
+        }
+    };
+}
+
+impl_inspect_visit_list!(Vec);
+impl_inspect_visit_list!(VecDeque);
+
+/// Used by the derive to visit fields whose type might not implement InspectVisit. See `InspectVisitSupported`
+pub struct InspectVisitTag<S, T>(PhantomData<fn() -> (S, T)>);
+
+impl<S, T> InspectVisitTag<S, T> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        InspectVisitTag(PhantomData)
+    }
+}
+
+/// Visits a field whose type (or proxy type) implements InspectVisit. The derive calls
+/// `(&&InspectVisitTag::<S, T>::new()).visit_field(...)`, which resolves to this impl if the bound is met and to
+/// `InspectVisitUnsupported` otherwise. This works the same way as the clipboard impls.
+pub trait InspectVisitSupported<T> {
+    fn visit_field(
+        &self,
+        data: &mut [&mut T],
+        label: &'static str,
+        visitor: &mut dyn InspectVisitor,
+        args: &InspectArgsDefault,
+    ) -> bool;
+}
+
+impl<S: InspectVisit<T>, T> InspectVisitSupported<T> for &InspectVisitTag<S, T> {
+    fn visit_field(
+        &self,
+        data: &mut [&mut T],
+        label: &'static str,
+        visitor: &mut dyn InspectVisitor,
+        args: &InspectArgsDefault,
+    ) -> bool {
+        S::visit(data, label, visitor, args)
+    }
+}
+
+/// Fallback for fields that can't be visited
+pub trait InspectVisitUnsupported<T> {
+    fn visit_field(
+        &self,
+        data: &mut [&mut T],
+        label: &'static str,
+        visitor: &mut dyn InspectVisitor,
+        args: &InspectArgsDefault,
+    ) -> bool;
+}
+
+impl<S, T> InspectVisitUnsupported<T> for InspectVisitTag<S, T> {
+    fn visit_field(
+        &self,
+        _data: &mut [&mut T],
+        label: &'static str,
+        visitor: &mut dyn InspectVisitor,
+        _args: &InspectArgsDefault,
+    ) -> bool {
+        visitor.visit_unsupported(label, std::any::type_name::<T>())
+    }
+}
+
+/// Draws visited values with imgui, using the same widgets as InspectRenderDefault
+pub struct ImguiInspectVisitor<'a, 'ui> {
+    ui: &'a imgui::Ui<'ui>,
+}
+
+impl<'a, 'ui> ImguiInspectVisitor<'a, 'ui> {
+    pub fn new(ui: &'a imgui::Ui<'ui>) -> Self {
+        ImguiInspectVisitor { ui }
+    }
+}
+
+impl InspectVisitor for ImguiInspectVisitor<'_, '_> {
+    fn visit_bool(
+        &mut self,
+        label: &'static str,
+        values: &mut [&mut bool],
+        args: &InspectArgsDefault,
+    ) -> bool {
+        <bool as InspectRenderDefault<bool>>::render_mut(values, label, self.ui, args)
+    }
+
+    fn visit_f32(
+        &mut self,
+        label: &'static str,
+        values: &mut [&mut f32],
+        args: &InspectArgsDefault,
+    ) -> bool {
+        <f32 as InspectRenderDefault<f32>>::render_mut(values, label, self.ui, args)
+    }
+
+    fn visit_u32(
+        &mut self,
+        label: &'static str,
+        values: &mut [&mut u32],
+        args: &InspectArgsDefault,
+    ) -> bool {
+        <u32 as InspectRenderDefault<u32>>::render_mut(values, label, self.ui, args)
+    }
+
+    fn visit_usize(
+        &mut self,
+        label: &'static str,
+        values: &mut [&mut usize],
+        args: &InspectArgsDefault,
+    ) -> bool {
+        <usize as InspectRenderDefault<usize>>::render_mut(values, label, self.ui, args)
+    }
+
+    fn visit_string(
+        &mut self,
+        label: &'static str,
+        values: &mut [&mut String],
+        args: &InspectArgsDefault,
+    ) -> bool {
+        <String as InspectRenderDefault<String>>::render_mut(values, label, self.ui, args)
+    }
+
+    fn visit_slider_f32(
+        &mut self,
+        label: &'static str,
+        values: &mut [&mut f32],
+        args: &InspectArgsSlider,
+    ) -> bool {
+        <f32 as InspectRenderSlider<f32>>::render_mut(values, label, self.ui, args)
+    }
+
+    fn visit_struct(
+        &mut self,
+        label: &'static str,
+        type_name: &'static str,
+        args: &InspectArgsStruct,
+        visit_fields: &mut dyn FnMut(&mut dyn InspectVisitor) -> bool,
+    ) -> bool {
+        let ui = self.ui;
+        let header = args.header.unwrap_or(true);
+        let indent_children = args.indent_children.unwrap_or(true);
+
+        // Like derived structs, the header is inside the label's ID scope and layout path
+        check_inspect_id(label, ui);
+        let id_token = ui.push_id(label);
+        let layout_token = push_layout_path(label);
+        if header {
+            layout_restore_next_header(ui);
+            let is_open = imgui::CollapsingHeader::new(&scratch_im_str!("{}", type_name))
+                .default_open(args.default_open.unwrap_or(true))
+                .build(ui);
+            layout_record_header(is_open);
+
+            if !is_open {
+                layout_token.end();
+                id_token.pop(ui);
+                return false;
+            }
+        }
+
+        if indent_children {
+            ui.indent();
+        }
+
+        let changed = visit_fields(self);
+
+        if indent_children {
+            ui.unindent();
+        }
+        layout_token.end();
+        id_token.pop(ui);
+        changed
+    }
+
+    fn visit_option(
+        &mut self,
+        label: &'static str,
+        is_some: &[bool],
+        visit_some: &mut dyn FnMut(&mut dyn InspectVisitor) -> bool,
+    ) -> bool {
+        if is_some.iter().any(|x| *x) {
+            visit_some(self)
+        } else {
+            self.ui.text(scratch_im_str!("{}: None", label));
+            false
+        }
+    }
+
+    fn visit_list(
+        &mut self,
+        label: &'static str,
+        lens: &[usize],
+        visit_item: &mut dyn FnMut(&mut dyn InspectVisitor, usize) -> bool,
+    ) -> bool {
+        let ui = self.ui;
+        let len = lens.iter().copied().max().unwrap_or(0);
+
+        // The ### keeps the ID stable when the length changes so that the node doesn't close
+        let node_label = scratch_im_str!("{} ({})###{}", label, len, label);
+        let layout_token = push_layout_path(label);
+        layout_restore_next_header(ui);
+        let node_token = imgui::TreeNode::new(&node_label).push(ui);
+        layout_record_header(node_token.is_some());
+        let node_token = match node_token {
+            Some(node_token) => node_token,
+            None => {
+                layout_token.end();
+                return false;
+            }
+        };
+
+        let mut changed = false;
+        for index in 0..len {
+            let id_token = ui.push_id(index as i32);
+            let item_layout_token = begin_list_item(index, ui);
+            changed |= visit_item(self, index);
+            item_layout_token.end();
+            id_token.pop(ui);
+        }
+
+        node_token.pop(ui);
+        layout_token.end();
+        changed
+    }
+
+    fn visit_unsupported(
+        &mut self,
+        label: &'static str,
+        type_name: &'static str,
+    ) -> bool {
+        self.ui.text(scratch_im_str!("{}: <{}>", label, type_name));
+        false
+    }
+}
@@ -0,0 +1,129 @@
+// imgui identifies widgets by hashing their label with the IDs pushed before them, so two widgets drawn with the same
+// label in the same scope share state (i.e. one header opens both). The code generated by #[derive(Inspect)] keeps IDs
+// unique by stacking them:
+//
+// - A derived struct pushes its label around everything it draws, including its header
+// - #[inspect(id = "...")] on the struct pushes a per-object ID (i.e. "self.guid") inside the label
+// - #[inspect(id = "...")] on a field pushes an extra ID around that field
+// - Collections push the index of each element
+//
+// InspectIdCheck is a debug aid for finding the cases this doesn't cover, like drawing two values with the same label
+// in one window. Like InspectFilter, it's installed for the current thread so that it reaches nested structs.
+use crate::scratch::scratch_im_str;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+/// Text color used for duplicate ID warnings
+pub const DUPLICATE_ID_TEXT_COLOR: [f32; 4] = [1.0, 0.3, 0.3, 1.0];
+
+/// A label that was checked more than once in the same ID scope
+#[derive(Debug, Clone, PartialEq)]
+pub struct InspectDuplicateId {
+    pub id: u32,
+    pub label: String,
+}
+
+#[derive(Default)]
+struct ActiveIdCheck {
+    seen: HashMap<u32, usize>,
+    last_id: Option<u32>,
+    duplicates: Vec<InspectDuplicateId>,
+}
+
+thread_local! {
+    static ACTIVE_ID_CHECK: RefCell<Option<ActiveIdCheck>> = const { RefCell::new(None) };
+}
+
+/// Finds values drawn with the same ID. While the check is active, derived structs and their fields check their labels
+/// with `check_inspect_id`, and a warning is drawn above any that were already used in the scope.
+///
+/// ```ignore
+/// id_check.scope(|| {
+///     for entity in &mut entities {
+///         <Entity as InspectRenderDefault<Entity>>::render_mut(&mut [entity], "entity", ui, &args);
+///     }
+/// });
+/// assert!(id_check.duplicates().is_empty());
+/// ```
+#[derive(Debug, Default)]
+pub struct InspectIdCheck {
+    duplicates: Vec<InspectDuplicateId>,
+}
+
+impl InspectIdCheck {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Calls f with the check active on this thread. Usually this wraps everything drawn in a frame. Duplicates found
+    /// by previous calls are cleared.
+    pub fn scope<R, F: FnOnce() -> R>(
+        &mut self,
+        f: F,
+    ) -> R {
+        let previous = ACTIVE_ID_CHECK.with(|x| x.replace(Some(ActiveIdCheck::default())));
+        let result = f();
+        let active = ACTIVE_ID_CHECK.with(|x| x.replace(previous));
+        self.duplicates = active.map(|x| x.duplicates).unwrap_or_default();
+        result
+    }
+
+    /// The duplicate IDs found by the last call to scope
+    pub fn duplicates(&self) -> &[InspectDuplicateId] {
+        &self.duplicates
+    }
+}
+
+/// Records the ID that a widget with the given label would have if an InspectIdCheck is active, and draws a warning if
+/// it was already used. Custom impls that draw several children can call this for each of them.
+///
+/// Checking the same ID twice in a row is allowed, since wrappers like Option and Box pass their label through to
+/// the value they contain.
+pub fn check_inspect_id(
+    label: &str,
+    ui: &imgui::Ui,
+) {
+    ACTIVE_ID_CHECK.with(|x| {
+        let mut active = x.borrow_mut();
+        let active = match active.as_mut() {
+            Some(active) => active,
+            None => return,
+        };
+
+        let id = unsafe {
+            let begin = label.as_ptr() as *const std::os::raw::c_char;
+            imgui::sys::igGetIDStrStr(begin, begin.add(label.len()))
+        };
+
+        if active.last_id == Some(id) {
+            return;
+        }
+        active.last_id = Some(id);
+
+        let count = active.seen.entry(id).or_insert(0);
+        *count += 1;
+        if *count > 1 {
+            active.duplicates.push(InspectDuplicateId {
+                id,
+                label: label.to_string(),
+            });
+
+            let style_token = ui.push_style_color(imgui::StyleColor::Text, DUPLICATE_ID_TEXT_COLOR);
+            ui.text(scratch_im_str!("Duplicate ID: \"{}\"", label));
+            style_token.pop(ui);
+        }
+    })
+}
+
+/// Pushes an ID made from any hashable value. This is used by the derive for `#[inspect(id = "...")]`, and can be used
+/// by custom impls to scope values by something other than their label or index.
+pub fn push_inspect_id<T: Hash + ?Sized>(
+    id: &T,
+    ui: &imgui::Ui,
+) -> imgui::IdStackToken {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    id.hash(&mut hasher);
+    let hash = hasher.finish();
+    ui.push_id((hash ^ (hash >> 32)) as i32)
+}
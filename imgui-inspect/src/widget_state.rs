@@ -0,0 +1,105 @@
+// Some widgets need state that persists across frames that isn't part of the value being inspected. For example, the
+// text of a key that is being typed in but hasn't been added to a map yet. imgui keeps this kind of state for its own
+// widgets, but there's no way to store arbitrary rust values in it, so it's kept here instead.
+//
+// State is identified by the address of the value that owns it (normally the first selected value), the imgui ID scope
+// it's drawn in, and a name. Addresses get reused once a value is dropped, so state that wasn't used during the
+// previous frame is dropped. Widgets that stop being drawn (i.e. a collapsed header, or a different object selected)
+// lose their state, the same as if their value had been dropped.
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+type WidgetStateKey = (usize, u32, &'static str);
+
+struct WidgetStateEntry {
+    state: Box<dyn Any>,
+    last_used_frame: i32,
+}
+
+#[derive(Default)]
+struct WidgetStates {
+    states: HashMap<WidgetStateKey, WidgetStateEntry>,
+    // The frame that unused state was last dropped in
+    collected_frame: Option<i32>,
+}
+
+impl WidgetStates {
+    // Drops state that wasn't used in the previous frame. This runs once per frame, the first time any state is used.
+    fn collect(
+        &mut self,
+        frame: i32,
+    ) {
+        if self.collected_frame == Some(frame) {
+            return;
+        }
+
+        self.collected_frame = Some(frame);
+        self.states
+            .retain(|_, entry| entry.last_used_frame >= frame - 1);
+    }
+}
+
+thread_local! {
+    static WIDGET_STATE: RefCell<WidgetStates> = RefCell::new(WidgetStates::default());
+}
+
+/// Identifies the value that owns some state. See `widget_state_owner`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct WidgetStateOwner {
+    address: usize,
+    id: u32,
+    frame: i32,
+}
+
+/// Returns a key for state owned by the given value when it's drawn in the current ID scope
+pub(crate) fn widget_state_owner<T>(
+    owner: &T,
+    ui: &imgui::Ui,
+) -> WidgetStateOwner {
+    const ID_LABEL: &str = "##inspect_widget_state";
+    let id = unsafe {
+        let begin = ID_LABEL.as_ptr() as *const std::os::raw::c_char;
+        imgui::sys::igGetIDStrStr(begin, begin.add(ID_LABEL.len()))
+    };
+
+    WidgetStateOwner {
+        address: owner as *const T as usize,
+        id,
+        frame: ui.frame_count(),
+    }
+}
+
+/// Calls f with the state for the given owner/name. The state is created with Default if it doesn't exist yet.
+pub(crate) fn with_widget_state<S: Default + 'static, R, F: FnOnce(&mut S) -> R>(
+    owner: WidgetStateOwner,
+    name: &'static str,
+    f: F,
+) -> R {
+    let key = (owner.address, owner.id, name);
+
+    // The state is removed while f runs so that f can access other state (i.e. for nested widgets) without a double
+    // borrow of the RefCell
+    let mut state = WIDGET_STATE
+        .with(|x| {
+            let mut states = x.borrow_mut();
+            states.collect(owner.frame);
+            states.states.remove(&key)
+        })
+        .and_then(|x| x.state.downcast::<S>().ok())
+        .map(|x| *x)
+        .unwrap_or_default();
+
+    let result = f(&mut state);
+
+    WIDGET_STATE.with(|x| {
+        x.borrow_mut().states.insert(
+            key,
+            WidgetStateEntry {
+                state: Box::new(state),
+                last_used_frame: owner.frame,
+            },
+        )
+    });
+    result
+}
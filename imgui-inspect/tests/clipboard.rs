@@ -0,0 +1,47 @@
+// Pastes into fields of derived structs through their context menus
+#![cfg(feature = "testing")]
+
+use imgui_inspect::testing::InspectTestContext;
+use imgui_inspect_derive::Inspect;
+
+#[derive(Inspect, Debug, Default, PartialEq)]
+struct Label {
+    text: String,
+}
+
+#[derive(Inspect, Debug, Default, PartialEq)]
+struct Health {
+    health: f32,
+}
+
+#[test]
+fn paste_keeps_string_whitespace() {
+    let mut context = InspectTestContext::new();
+    let mut value = Label::default();
+    context.render_mut(&mut value, "label");
+
+    context.set_clipboard_text("  padded  ");
+    context.right_click("text").unwrap();
+    context.render_mut(&mut value, "label");
+    context.click("Paste").unwrap();
+    assert!(context.render_mut(&mut value, "label"));
+    assert_eq!(value.text, "  padded  ");
+}
+
+#[test]
+fn paste_that_does_not_parse_is_disabled() {
+    let mut context = InspectTestContext::new();
+    let mut value = Health::default();
+    context.render_mut(&mut value, "health");
+
+    context.set_clipboard_text("fast");
+    context.right_click("health").unwrap();
+    context.render_mut(&mut value, "health");
+    assert!(context
+        .last_frame()
+        .contains_text("Can't paste: invalid float literal"));
+
+    context.click("Paste").unwrap();
+    assert!(!context.render_mut(&mut value, "health"));
+    assert_eq!(value, Health::default());
+}
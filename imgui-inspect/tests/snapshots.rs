@@ -0,0 +1,95 @@
+// Golden-file tests for how derived structs lay out their header and children. Set IMGUI_INSPECT_UPDATE_SNAPSHOTS to
+// rewrite the files in tests/snapshots after an intended change.
+#![cfg(feature = "testing")]
+
+use imgui_inspect::testing::{assert_snapshot, InspectTestContext, UPDATE_SNAPSHOTS_ENV_VAR};
+use imgui_inspect::InspectArgsDefault;
+use imgui_inspect_derive::Inspect;
+
+#[derive(Inspect, Default)]
+struct Transform {
+    position: f32,
+    scale: f32,
+}
+
+#[derive(Inspect)]
+struct Entity {
+    name: String,
+    visible: bool,
+    transform: Transform,
+}
+
+impl Default for Entity {
+    fn default() -> Self {
+        Entity {
+            name: "player".to_string(),
+            visible: true,
+            transform: Transform::default(),
+        }
+    }
+}
+
+fn snapshot_path(name: &str) -> String {
+    format!(
+        "{}/tests/snapshots/{}.txt",
+        env!("CARGO_MANIFEST_DIR"),
+        name
+    )
+}
+
+fn check_snapshots(
+    name: &str,
+    args: InspectArgsDefault,
+) {
+    let mut context = InspectTestContext::new();
+    let mut value = Entity::default();
+
+    let frame = context.render_with_args(&value, "entity", &args);
+    assert_snapshot(snapshot_path(name), &frame.snapshot());
+
+    context.render_mut_with_args(&mut value, "entity", &args);
+    assert_snapshot(
+        snapshot_path(&format!("{}_mut", name)),
+        &context.last_frame().snapshot(),
+    );
+}
+
+#[test]
+fn default_args() {
+    check_snapshots("default", InspectArgsDefault::default());
+}
+
+#[test]
+fn without_indent_children() {
+    check_snapshots(
+        "indent_children_false",
+        InspectArgsDefault {
+            indent_children: Some(false),
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+fn without_header() {
+    check_snapshots(
+        "header_false",
+        InspectArgsDefault {
+            header: Some(false),
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+fn missing_snapshot_fails() {
+    // Missing files are written instead when updating snapshots
+    if std::env::var_os(UPDATE_SNAPSHOTS_ENV_VAR).is_some() {
+        return;
+    }
+
+    let path = snapshot_path("missing");
+    let result = std::panic::catch_unwind(|| assert_snapshot(&path, "text \"entity\"\n"));
+    assert!(result.is_err());
+    assert!(!std::path::Path::new(&path).exists());
+}
@@ -0,0 +1,193 @@
+// Drives built-in impls and a derived struct through InspectTestContext, to check that the harness itself can find,
+// click, type into, and drag widgets drawn by a real imgui context.
+#![cfg(feature = "testing")]
+
+use imgui_inspect::testing::{InspectTestContext, InspectTestError};
+use imgui_inspect_derive::Inspect;
+
+#[derive(Inspect, Debug, Default, Clone, PartialEq)]
+struct Entity {
+    name: String,
+    visible: bool,
+    health: f32,
+    #[inspect_slider(min_value = 0.0, max_value = 100.0)]
+    speed: f32,
+}
+
+#[test]
+fn type_text_edits_f32() {
+    let mut context = InspectTestContext::new();
+    let mut value = 1.0f32;
+    context.render_mut(&mut value, "health");
+
+    context.type_text("health", "42.5").unwrap();
+    assert!(context.render_mut(&mut value, "health"));
+    assert_eq!(value, 42.5);
+}
+
+#[test]
+fn type_text_edits_u32_with_step_buttons() {
+    let mut context = InspectTestContext::new();
+    let mut value = 3u32;
+    context.render_mut(&mut value, "lives");
+
+    // The "+" step button is drawn right before the label, with the same color as a hovered input box
+    let snapshot = context.last_frame().snapshot();
+    assert!(snapshot.contains("button \"+\"\n"), "{}", snapshot);
+    assert!(
+        snapshot.ends_with("input \"lives\" = \"3\"\n"),
+        "{}",
+        snapshot
+    );
+
+    context.type_text("lives", "7").unwrap();
+    assert!(context.render_mut(&mut value, "lives"));
+    assert_eq!(value, 7);
+
+    // The mouse is left hovering the input box
+    let snapshot = context.last_frame().snapshot();
+    assert!(
+        snapshot.ends_with("input \"lives\" = \"7\"\n"),
+        "{}",
+        snapshot
+    );
+}
+
+#[test]
+fn click_toggles_bool() {
+    let mut context = InspectTestContext::new();
+    let mut value = false;
+    context.render_mut(&mut value, "visible");
+
+    context.click("visible").unwrap();
+    assert!(context.render_mut(&mut value, "visible"));
+    assert!(value);
+
+    context.click("visible").unwrap();
+    assert!(context.render_mut(&mut value, "visible"));
+    assert!(!value);
+}
+
+#[test]
+fn type_text_replaces_string() {
+    let mut context = InspectTestContext::new();
+    let mut value = "old name".to_string();
+    context.render_mut(&mut value, "name");
+    assert!(context.last_frame().contains_text("old name"));
+
+    context.type_text("name", "new name").unwrap();
+    assert!(context.render_mut(&mut value, "name"));
+    assert_eq!(value, "new name");
+    assert!(context.last_frame().contains_text("new name"));
+}
+
+#[test]
+fn drag_moves_slider() {
+    let mut context = InspectTestContext::new();
+    let mut value = Entity::default();
+    context.render_mut(&mut value, "entity");
+
+    // Pressing the slider jumps to the value under the mouse (the middle of the range), then dragging right raises it
+    context.drag("speed", 40.0).unwrap();
+    assert!(context.render_mut(&mut value, "entity"));
+    assert!(value.speed > 50.0 && value.speed < 100.0, "{}", value.speed);
+}
+
+#[test]
+fn edits_derived_struct_fields() {
+    let mut context = InspectTestContext::new();
+    let mut value = Entity::default();
+    context.render_mut(&mut value, "entity");
+    for label in &["name", "visible", "health", "speed"] {
+        assert!(context.last_frame().contains_text(label), "{}", label);
+    }
+
+    context.type_text("name", "player").unwrap();
+    context.click("visible").unwrap();
+    context.type_text("health", "75").unwrap();
+    assert!(context.render_mut(&mut value, "entity"));
+
+    assert_eq!(
+        value,
+        Entity {
+            name: "player".to_string(),
+            visible: true,
+            health: 75.0,
+            speed: 0.0,
+        }
+    );
+}
+
+#[test]
+fn edits_apply_to_every_selected_value() {
+    let mut context = InspectTestContext::new();
+    let mut a = Entity::default();
+    let mut b = Entity {
+        health: 10.0,
+        ..Default::default()
+    };
+
+    let render = |context: &mut InspectTestContext, a: &mut Entity, b: &mut Entity| {
+        let mut changed = false;
+        context.run(|ui| {
+            changed |= <Entity as imgui_inspect::InspectRenderDefault<Entity>>::render_mut(
+                &mut [&mut *a, &mut *b],
+                "entities",
+                ui,
+                &Default::default(),
+            );
+        });
+        changed
+    };
+
+    render(&mut context, &mut a, &mut b);
+    context.type_text("health", "20").unwrap();
+    assert!(render(&mut context, &mut a, &mut b));
+    assert_eq!(a.health, 20.0);
+    assert_eq!(b.health, 20.0);
+}
+
+#[test]
+fn read_only_render_draws_values() {
+    let mut context = InspectTestContext::new();
+    let value = Entity {
+        name: "player".to_string(),
+        ..Default::default()
+    };
+
+    let frame = context.render(&value, "entity");
+    assert!(frame.contains_text("name: player"));
+}
+
+#[test]
+fn missing_labels_are_errors() {
+    let mut context = InspectTestContext::new();
+    let mut value = Entity::default();
+    context.render_mut(&mut value, "entity");
+
+    assert_eq!(
+        context.click("missing"),
+        Err(InspectTestError::LabelNotFound("missing".to_string()))
+    );
+    assert_eq!(
+        context.type_text("Entity", "text"),
+        Err(InspectTestError::InputNotFound("Entity".to_string()))
+    );
+}
+
+#[test]
+fn popup_items_are_clicked_instead_of_inputs_behind_them() {
+    let mut context = InspectTestContext::new();
+    let mut value = Entity {
+        name: "player".to_string(),
+        ..Default::default()
+    };
+    context.render_mut(&mut value, "entity");
+
+    // The context menu opens at the mouse, so its items line up with the input boxes of the fields below it
+    context.right_click("name").unwrap();
+    context.render_mut(&mut value, "entity");
+    context.click("Copy").unwrap();
+    context.render_mut(&mut value, "entity");
+    assert_eq!(context.clipboard_text(), Some("player".to_string()));
+}